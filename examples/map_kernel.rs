@@ -0,0 +1,18 @@
+//squares a vec on the gpu with CudaDevice::map_kernel. square.ptx is square.cu compiled with
+//nvcc -ptx, the kernel takes (inp, out, n) in the order map_kernel passes them.
+use cudas::cuda::borrow::CudaDevice;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut dev = CudaDevice::new(0)?;
+    dev.load_ptx("square", include_str!("square.ptx"), &["square"])?;
+    let square = dev.get_module("square").and_then(|m| m.get_fn("square")).unwrap();
+
+    let input: Vec<f32> = (0..1000).map(|i| i as f32).collect();
+    let output = dev.map_kernel(square, &input)?;
+
+    for (x, y) in input.iter().zip(&output) {
+        assert_eq!(*y, x * x);
+    }
+    println!("squared {} floats, the last is {}", output.len(), output[output.len() - 1]);
+    Ok(())
+}
@@ -0,0 +1,6 @@
+extern "C" __global__ void square(const float *inp, float *out, unsigned int n) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i < n) {
+        out[i] = inp[i] * inp[i];
+    }
+}
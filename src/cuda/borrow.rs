@@ -1,170 +1,4243 @@
 use super::result;
 use super::sys;
-use std::alloc::{alloc_zeroed, Layout};
+use std::cell::Cell;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
-use std::hash::Hash;
+use std::ffi::{c_void, CStr, CString};
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::mem::size_of;
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex, Once, OnceLock, RwLock};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
 
 pub mod prelude {
-    pub use super::result::CudaError;
+    pub use super::result::{AllocError, CudaError, LaunchError};
     pub use super::*;
 }
 
+//types that can be copied to the device byte for byte and read back: plain data, no pointers or
+//references, no Drop. Every bit pattern must be a valid value, since whatever a kernel (or a zeroing
+//memset) leaves in the buffer gets reinterpreted as T on the way back. That's why bool and char
+//aren't here. Implementing it for your own #[repr(C)] struct means promising the same.
+/// # Safety
+/// every bit pattern of the type must be a valid value and it must hold no pointers or references
+pub unsafe trait DeviceRepr: Copy {}
+
+unsafe impl DeviceRepr for u8 {}
+unsafe impl DeviceRepr for u16 {}
+unsafe impl DeviceRepr for u32 {}
+unsafe impl DeviceRepr for u64 {}
+unsafe impl DeviceRepr for usize {}
+unsafe impl DeviceRepr for i8 {}
+unsafe impl DeviceRepr for i16 {}
+unsafe impl DeviceRepr for i32 {}
+unsafe impl DeviceRepr for i64 {}
+unsafe impl DeviceRepr for isize {}
+unsafe impl DeviceRepr for f32 {}
+unsafe impl DeviceRepr for f64 {}
+
+//the byte oriented, non generic part of CudaDevice, so code that only moves bytes around can hold
+//a Box<dyn DynDevice> / &dyn DynDevice and stay object safe. The generic methods (typed
+//allocations, launches) aren't part of it. Other implementations (a mock for tests) make their
+//buffers with InCudaMemory::from_raw_parts.
+pub trait DynDevice: std::fmt::Debug + Send + Sync {
+    fn alloc_bytes(&self, n: usize) -> Result<InCudaMemory<'_, [u8]>, result::AllocError>;
+    //frees what alloc_bytes handed out, InCudaMemory has no Drop
+    fn discard_bytes(&self, mem: InCudaMemory<'_, [u8]>) -> Result<(), result::CudaError>;
+    //src.len() must equal dst.len(), ordered behind the work already queued on the device and
+    //finished when this returns
+    fn copy_bytes_to_device(&self, dst: &mut InCudaMemory<[u8]>, src: &[u8]) -> Result<(), result::CudaError>;
+    //dst.len() must equal src.len(), finished when this returns
+    fn copy_bytes_to_host(&self, src: &InCudaMemory<[u8]>, dst: &mut [u8]) -> Result<(), result::CudaError>;
+    fn synchronize(&self) -> Result<(), result::CudaError>;
+}
+
+//one kernel argument for launch_args. cuLaunchKernel wants a pointer to each argument's value, so
+//scalars hand out their own address and pass by value (no device allocation for a loop count or a
+//scale factor), device memory hands out the address of its device pointer and the kernel sees a
+//T*. The pointer only has to live as long as self, the driver copies the values at launch.
+/// # Safety
+/// as_kernel_param must point at param_size bytes holding the value the kernel expects, valid as long as self
+pub unsafe trait KernelArg {
+    fn as_kernel_param(&self) -> *mut c_void;
+    //bytes the kernel reads through that pointer, checked against CudaFunction::param_info
+    fn param_size(&self) -> usize;
+}
+
+unsafe impl<T: DeviceRepr> KernelArg for T {
+    fn as_kernel_param(&self) -> *mut c_void {
+        self as *const T as *mut c_void
+    }
+
+    fn param_size(&self) -> usize {
+        size_of::<T>()
+    }
+}
+
+unsafe impl<T: ?Sized> KernelArg for &InCudaMemory<'_, T> {
+    fn as_kernel_param(&self) -> *mut c_void {
+        &self.cu_device_ptr as *const sys::CUdeviceptr as *mut c_void
+    }
+
+    fn param_size(&self) -> usize {
+        size_of::<sys::CUdeviceptr>()
+    }
+}
+
+//output buffers are usually held as &mut, this saves reborrowing them as & at every launch
+unsafe impl<T: ?Sized> KernelArg for &mut InCudaMemory<'_, T> {
+    fn as_kernel_param(&self) -> *mut c_void {
+        &self.cu_device_ptr as *const sys::CUdeviceptr as *mut c_void
+    }
+
+    fn param_size(&self) -> usize {
+        size_of::<sys::CUdeviceptr>()
+    }
+}
+
+//&n passes n by value too, same as n. Only the built in scalars, a blanket impl for &T would
+//overlap with the one for T, so your own DeviceRepr structs go in the tuple by value.
+macro_rules! scalar_ref_kernel_arg {
+    ($($t:ty),+) => {
+        $(
+            unsafe impl KernelArg for &$t {
+                fn as_kernel_param(&self) -> *mut c_void {
+                    *self as *const $t as *mut c_void
+                }
+
+                fn param_size(&self) -> usize {
+                    size_of::<$t>()
+                }
+            }
+        )+
+    };
+}
+
+scalar_ref_kernel_arg!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
+unsafe impl<T: ?Sized> KernelArg for &DeviceView<'_, T> {
+    fn as_kernel_param(&self) -> *mut c_void {
+        &self.cu_device_ptr as *const sys::CUdeviceptr as *mut c_void
+    }
+
+    fn param_size(&self) -> usize {
+        size_of::<sys::CUdeviceptr>()
+    }
+}
+
+//a tuple of KernelArgs in kernel parameter order, e.g. (&buf, n, alpha) for
+//`__global__ void scale(float *buf, unsigned int n, float alpha)`. The pointers from kernel_params
+//point into the tuple itself, launch_args owns it until cuLaunchKernel has copied the values.
+/// # Safety
+/// kernel_params must hold one valid KernelArg pointer per argument, in kernel parameter order
+pub unsafe trait LaunchArgs {
+    fn kernel_params(&self) -> Vec<*mut c_void>;
+    fn param_sizes(&self) -> Vec<usize>;
+}
+
+macro_rules! tuple_launch_args {
+    ($($name:ident),+) => {
+        unsafe impl<$($name: KernelArg),+> LaunchArgs for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn kernel_params(&self) -> Vec<*mut c_void> {
+                let ($($name,)+) = self;
+                vec![$($name.as_kernel_param()),+]
+            }
+
+            #[allow(non_snake_case)]
+            fn param_sizes(&self) -> Vec<usize> {
+                let ($($name,)+) = self;
+                vec![$($name.param_size()),+]
+            }
+        }
+    };
+}
+
+tuple_launch_args!(A);
+tuple_launch_args!(A, B);
+tuple_launch_args!(A, B, C);
+tuple_launch_args!(A, B, C, D);
+tuple_launch_args!(A, B, C, D, E);
+tuple_launch_args!(A, B, C, D, E, F);
+tuple_launch_args!(A, B, C, D, E, F, G);
+tuple_launch_args!(A, B, C, D, E, F, G, H);
+tuple_launch_args!(A, B, C, D, E, F, G, H, I);
+tuple_launch_args!(A, B, C, D, E, F, G, H, I, J);
+tuple_launch_args!(A, B, C, D, E, F, G, H, I, J, K);
+tuple_launch_args!(A, B, C, D, E, F, G, H, I, J, K, L);
+
 #[derive(Debug)]
 pub struct CudaDevice{
+    //the index that was passed to new/builder, cu_device is the driver's handle for it
+    pub(crate) ordinal: usize,
     pub(crate) cu_device: sys::CUdevice, 
     pub(crate) cu_primary_ctx: sys::CUcontext,
+    //false for a context adopted with from_context, which the application that made it releases
+    pub(crate) owns_ctx: bool,
     pub(crate) cu_stream: sys::CUstream,
     pub(crate) loaded_modules: HashMap<&'static str, CudaModule>,
+    //modules from load_ptx_cached, keyed by a hash of their PTX text. Behind a Mutex so the
+    //cache works through &self
+    pub(crate) ptx_cache: Mutex<HashMap<u64, Vec<CachedPtx>>>,
+    //MAX_GRID_DIM_X/Y/Z, read once in new so launch doesn't query the driver every time
+    pub(crate) max_grid_dim: (u32, u32, u32),
+    //MAX_SHARED_MEMORY_PER_BLOCK and its _OPTIN version, checked against launch shared_mem_bytes
+    pub(crate) max_shared_mem: u32,
+    pub(crate) max_shared_mem_optin: u32,
+    pub(crate) stream_flags: result::stream::CUstream_flags,
+    //cuMemAllocAsync/cuMemFreeAsync when supports_async_alloc and the driver has them, picks
+    //between the stream ordered and the plain allocator
+    pub(crate) async_alloc: Option<result::async_alloc::AsyncAllocFns>,
+    //bumped by every reset, memory from an older generation was freed by the driver
+    pub(crate) generation: u64,
+    //rust side accounting of the bytes allocated through this device, off until enable_tracking
+    pub(crate) tracking: AtomicBool,
+    pub(crate) current_bytes: AtomicUsize,
+    pub(crate) peak_bytes: AtomicUsize,
+    //device pointers freed on the device stream, kept by size for the next allocation of exactly
+    //that size. Only used after enable_freelist.
+    pub(crate) freelist_enabled: AtomicBool,
+    pub(crate) freelist: Mutex<HashMap<usize, Vec<sys::CUdeviceptr>>>,
+    //memory whose owner was dropped on a thread without the context current, freed by the next
+    //allocation or synchronize on a thread that has it. has_deferred saves them the lock.
+    pub(crate) deferred_frees: Mutex<Vec<DeferredFree>>,
+    pub(crate) has_deferred: AtomicBool,
+    //synchronize after every launch so a failing kernel reports its own error
+    pub(crate) eager_error_check: bool,
+    //page locked host buffer that small downloads go through, see CudaDeviceBuilder::staging_bytes
+    pub(crate) staging: Option<Mutex<HostStaging>>,
+    //gets the JIT info/error log of every module loaded from memory, see set_diagnostic_callback
+    pub(crate) diagnostics: Option<DiagnosticCallback>,
+}
+
+pub(crate) struct DiagnosticCallback(Box<dyn Fn(&str) + Send + Sync>);
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DeferredFree {
+    pub(crate) dptr: sys::CUdeviceptr,
+    pub(crate) num_bytes: usize,
+    //from cuMemAllocManaged, which only cuMemFree can free
+    pub(crate) managed: bool,
+}
+
+#[derive(Debug)]
+pub(crate) struct HostStaging {
+    pub(crate) ptr: *mut c_void,
+    pub(crate) len: usize,
+}
+
+//every CudaDevice constructor ends up here. new(ordinal) is builder().ordinal(ordinal).build().
+#[derive(Debug, Clone, Copy)]
+pub struct CudaDeviceBuilder {
+    ordinal: usize,
+    ctx_flags: Option<sys::CUctx_flags>,
+    stream_flags: result::stream::CUstream_flags,
+    eager_error_check: bool,
+    staging_bytes: usize,
+}
+
+//one PTX text in load_ptx_cached's cache: the module, JIT compiled once, and a CudaModule per
+//set of funcs that was asked for. The boxes are never changed or dropped before reset/drop,
+//which both take &mut self, so load_ptx_cached can hand out references to them through &self
+#[derive(Debug)]
+pub(crate) struct CachedPtx {
+    ptx: String,
+    cu_module: sys::CUmodule,
+    //boxed so pushing a view doesn't move the ones already handed out
+    #[allow(clippy::vec_box)]
+    views: Vec<Box<CudaModule>>,
 }
 
 #[derive(Debug)]
 pub struct CudaModule {
     pub(crate) cu_module: sys::CUmodule,
     pub(crate) functions: HashMap<&'static str, CudaFunction>,
+    //what get_or_load_fn resolved through &self. Boxed and only dropped with the module, so the
+    //references it hands out stay valid while the module is borrowed
+    pub(crate) lazy_functions: Mutex<HashMap<&'static str, Box<CudaFunction>>>,
+    //source text when the module was loaded from PTX in memory, None for files
+    pub(crate) ptx: Option<String>,
 }
 
 #[derive(Debug)]
 pub struct CudaFunction {
     pub(crate) cu_function: sys::CUfunction,
+    //.param count of the entry in the module's PTX, None when there's no PTX to read it from
+    pub(crate) param_count: Option<usize>,
+    //cuFuncGetParamInfo's answer, asked for the first time param_info or launch_args needs it
+    pub(crate) param_info: OnceLock<Result<Vec<ParamInfo>, result::CudaError>>,
+}
+
+//where one kernel parameter sits in the parameter buffer, both in bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParamInfo {
+    pub offset: usize,
+    pub size: usize,
+}
+
+//what CudaDevice::theoretical_occupancy worked out for one launch shape
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Occupancy {
+    pub blocks_per_sm: u32,
+    pub warps_per_sm: u32,
+    //warps_per_sm over the most warps an SM can hold, 0.0..=1.0
+    pub ratio: f32,
+    pub limited_by: OccupancyLimit,
+}
+
+//the resource that ran out first. Warps also covers a block that can't launch at all (0 or more
+//threads than the device allows), Registers one that needs more registers than a block may use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OccupancyLimit {
+    Warps,
+    Blocks,
+    Registers,
+    SharedMemory,
+}
+
+//the attributes people usually want from the runtime api's cudaDeviceProp, see CudaDevice::properties
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceProperties {
+    pub name: String,
+    pub compute_capability: (u32, u32),
+    pub total_mem_bytes: usize,
+    pub multiprocessor_count: u32,
+    pub max_threads_per_block: u32,
+    pub warp_size: u32,
+    pub max_block_dim: (u32, u32, u32),
+    pub max_grid_dim: (u32, u32, u32),
+    pub clock_rate_khz: u32,
+    pub memory_clock_khz: u32,
+    pub memory_bus_width_bits: u32,
+    pub l2_cache_bytes: usize,
+    pub ecc_enabled: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LaunchConfig {
+    pub grid_dim: (u32, u32, u32),
+    pub block_dim: (u32, u32, u32),
+    pub shared_mem_bytes: u32,
+}
+
+//LaunchConfig plus what only cuLaunchKernelEx can express. cluster_dim groups blocks into thread
+//block clusters and needs compute capability 9.0, the grid has to be a multiple of it.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LaunchConfigEx {
+    pub grid_dim: (u32, u32, u32),
+    pub block_dim: (u32, u32, u32),
+    pub shared_mem_bytes: u32,
+    pub cluster_dim: Option<(u32, u32, u32)>,
+    pub attributes: Vec<LaunchAttribute>,
+}
+
+//the CUlaunchAttribute values that are plain numbers/flags. Anything else the driver rejects or
+//checks itself (e.g. programmatic serialization before sm_90).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LaunchAttribute {
+    //all blocks are resident at once so they can sync grid wide
+    Cooperative(bool),
+    //lets this kernel start before the previous one on the stream has finished (programmatic
+    //dependent launch), the kernels sync with griddepcontrol themselves
+    ProgrammaticStreamSerialization(bool),
+    //overrides the stream priority for this one launch
+    Priority(i32),
+}
+
+#[derive(Debug)]
+pub struct CudaStream<'device> {
+    pub(crate) cu_stream: sys::CUstream,
+    device: PhantomData<&'device CudaDevice>,
+}
+
+#[derive(Debug)]
+pub struct CudaEvent<'device> {
+    pub(crate) cu_event: sys::CUevent,
+    device: PhantomData<&'device CudaDevice>,
+}
+
+//sequential writes into a device buffer, see InCudaMemory::writer
+#[derive(Debug)]
+pub struct DeviceWriter<'a> {
+    device: &'a CudaDevice,
+    cu_device_ptr: sys::CUdeviceptr,
+    len_bytes: usize,
+    offset: usize,
+    mem: PhantomData<&'a mut ()>,
+}
+
+//sequential reads out of a device buffer, see InCudaMemory::reader
+#[derive(Debug)]
+pub struct DeviceReader<'a> {
+    device: &'a CudaDevice,
+    cu_device_ptr: sys::CUdeviceptr,
+    len_bytes: usize,
+    offset: usize,
+    mem: PhantomData<&'a ()>,
+}
+
+//n timing events made up front and handed out in turns, see CudaDevice::event_pool
+#[derive(Debug)]
+pub struct EventPool<'device> {
+    events: Vec<CudaEvent<'device>>,
+    next: Cell<usize>,
+}
+
+//what CudaDevice::synchronize_async returns. Nothing is enqueued until the first poll.
+#[derive(Debug)]
+pub struct SynchronizeFuture<'a> {
+    device: &'a CudaDevice,
+    state: Option<Arc<Mutex<SyncState>>>,
+    //recorded right behind the host function, what the watcher thread queries
+    event: Option<CudaEvent<'a>>,
+}
+
+//shared between a SynchronizeFuture, the host function that completes it and the watcher thread
+#[derive(Debug, Default)]
+struct SyncState {
+    done: bool,
+    //what the watcher got from querying the event, for when the host function never runs
+    error: Option<result::CudaError>,
+    waker: Option<Waker>,
+}
+
+//a pending SynchronizeFuture as the watcher thread sees it. The future takes itself out of
+//WATCHED before its event is destroyed, and the device (so the context) outlives the future.
+struct Watched {
+    cu_ctx: sys::CUcontext,
+    cu_event: sys::CUevent,
+    state: Arc<Mutex<SyncState>>,
+}
+
+//plain handles, and the driver api is thread safe
+unsafe impl Send for Watched {}
+
+static WATCHED: Mutex<Vec<Watched>> = Mutex::new(Vec::new());
+static WATCHED_ADDED: Condvar = Condvar::new();
+static WATCHER: Once = Once::new();
+//how often the watcher queries the events of futures whose host function hasn't run yet
+const WATCH_INTERVAL: Duration = Duration::from_millis(10);
+
+//how synchronize_with_cancel ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncOutcome {
+    Completed,
+    Cancelled,
+}
+
+//where CudaDevice::copy_any reads from and writes to, for code that only finds out at runtime
+#[derive(Debug)]
+pub enum CopySrc<'a, T> {
+    Host(&'a [T]),
+    Device(DeviceView<'a, [T]>),
+}
+
+#[derive(Debug)]
+pub enum CopyDst<'a, 'device, T> {
+    Host(&'a mut [T]),
+    Device(&'a mut InCudaMemory<'device, [T]>),
+}
+
+//how CudaDevice::copy runs: on the device stream, or on the given stream. Either way it's ordered
+//behind what's already queued there and finished when copy returns
+#[derive(Debug, Clone, Copy)]
+pub enum CopyMode<'a> {
+    Sync,
+    Async(&'a CudaStream<'a>),
+}
+
+//borrows both so neither the device nor the stream can go away while work is being enqueued
+#[derive(Debug, Clone, Copy)]
+pub struct StreamScoped<'a> {
+    device: &'a CudaDevice,
+    stream: &'a CudaStream<'a>,
+}
+
+//iterator from CudaDevice::download_chunks. The pinned chunk buffer is allocated on the first
+//next and freed on drop.
+#[derive(Debug)]
+pub struct DownloadChunks<'a, T> {
+    device: &'a CudaDevice,
+    mem: DeviceView<'a, [T]>,
+    chunk_elems: usize,
+    offset: usize,
+    pinned: *mut c_void,
+}
+
+//two device buffers used in turns so uploading the next batch overlaps computing on the previous one.
+//each buffer has its own upload stream, the compute closure enqueues on the device stream. Events
+//order it: the compute on a buffer waits for that buffer's upload, and the next upload into the
+//buffer waits for the compute that last read it. The overlap is a copy next to a kernel, which
+//needs a free copy engine (CU_DEVICE_ATTRIBUTE_ASYNC_ENGINE_COUNT) but not concurrent kernels.
+#[derive(Debug)]
+pub struct DoubleBuffer<'device, T> {
+    device: &'device CudaDevice,
+    buffers: [InCudaMemory<'device, [T]>; 2],
+    streams: [CudaStream<'device>; 2],
+    uploaded: [CudaEvent<'device>; 2],
+    computed: [CudaEvent<'device>; 2],
+    next: usize,
+}
+
+//launches and copies recorded once by CudaDevice::record and replayed with run. The graph holds
+//the raw device pointers it was recorded with, so the pipeline borrows every buffer the recording
+//closure used ('a) and they can't be freed or moved out while it can still run.
+#[derive(Debug)]
+pub struct CapturedPipeline<'a> {
+    device: &'a CudaDevice,
+    cu_graph: sys::CUgraph,
+    cu_graph_exec: sys::CUgraphExec,
+}
+
+//a graph put together node by node instead of captured, see CudaDevice::graph_builder. Like
+//CapturedPipeline it holds raw device pointers, so buffers given to the memcpy/memset nodes stay
+//borrowed for 'a.
+#[derive(Debug)]
+pub struct GraphBuilder<'a> {
+    device: &'a CudaDevice,
+    cu_graph: sys::CUgraph,
+    //from NEXT_GRAPH_BUILDER_ID, never reused unlike the CUgraph address
+    id: u64,
+    buffers: PhantomData<&'a ()>,
+}
+
+static NEXT_GRAPH_BUILDER_ID: AtomicU64 = AtomicU64::new(0);
+
+//a node of a GraphBuilder, for listing as a dependency of later nodes. builder_id ties it to the
+//builder that made it, so it can't be handed to a later builder that got the same CUgraph address
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NodeHandle {
+    builder_id: u64,
+    cu_node: sys::CUgraphNode,
 }
 
+//profiling is on while this lives, see CudaDevice::profiler_scope
 #[derive(Debug)]
-pub struct InCudaMemory<'device, T> {
+pub struct ProfilerScope<'a> {
+    device: &'a CudaDevice,
+}
+
+//T can be unsized so that InCudaMemory<[T]> can describe a run of elements.
+//len_bytes is the size of the device allocation, for sized T it is always size_of::<T>().
+//generation is the device's reset count when the memory was allocated, see CudaDevice::reset.
+pub struct InCudaMemory<'device, T: ?Sized> {
     pub(crate) cu_device_ptr: sys::CUdeviceptr,
+    pub(crate) len_bytes: usize,
+    //element count, 1 for a sized T. Kept apart from len_bytes because for zero sized elements
+    //len_bytes is 0 and can't give it back
+    pub(crate) len: usize,
+    pub(crate) generation: u64,
     pub(crate) host_data: Option<Box<T>>,
+    //false for from_raw_borrowed memory, release/discard then leave the pointer alone
+    pub(crate) owned: bool,
     device: PhantomData<&'device CudaDevice>
 }
 
+//a device slice plus an N dimensional row major shape, for tensor code. The elements are an
+//ordinary InCudaMemory<[T]> of numel() elements (as_slice), everything that works on slices
+//works on the tensor's data.
+#[derive(Debug)]
+pub struct DeviceTensor<'device, T, const N: usize> {
+    data: InCudaMemory<'device, [T]>,
+    shape: [usize; N],
+}
+
+//len elements of managed (unified) memory from CudaDevice::alloc_managed. The same pointer works
+//in kernels and, through host_slice, on the host; the driver moves pages to whoever touches them.
+//Freed on drop.
+#[derive(Debug)]
+pub struct ManagedMemory<'device, T> {
+    cu_device_ptr: sys::CUdeviceptr,
+    len: usize,
+    device: &'device CudaDevice,
+    elems: PhantomData<T>,
+}
+
+//a 2D array of height rows of width elements, where row r starts at byte r * pitch. The driver
+//pads rows so that each starts aligned, kernels index it as (T*)((char*)ptr + row * pitch)[col].
+//Like InCudaMemory it's freed explicitly, with CudaDevice::discard_pitched.
+#[derive(Debug)]
+pub struct Pitched2D<'device, T> {
+    cu_device_ptr: sys::CUdeviceptr,
+    pitch: usize,
+    width: usize,
+    height: usize,
+    generation: u64,
+    device: PhantomData<&'device CudaDevice>,
+    elems: PhantomData<T>,
+}
+
+//read only handle to an InCudaMemory: a pointer and a length, nothing that writes or frees. Kernel
+//inputs can be taken as DeviceView and outputs as &mut InCudaMemory so the signature says which is
+//which. It borrows the owner, so the memory can't be released or discarded while a view exists.
+#[derive(Debug)]
+pub struct DeviceView<'a, T: ?Sized> {
+    cu_device_ptr: sys::CUdeviceptr,
+    len_bytes: usize,
+    //element count, see InCudaMemory::len
+    len: usize,
+    generation: u64,
+    owner: PhantomData<&'a T>,
+}
+
+//the driver api is thread safe, the handles are only raw pointers. Whatever thread uses the
+//device needs the context current though, see bind_to_thread.
+unsafe impl Send for CudaDevice {}
+unsafe impl Sync for CudaDevice {}
+
+static DEFAULT_DEVICE: OnceLock<CudaDevice> = OnceLock::new();
+
+static VISIBLE_DEVICES: RwLock<Option<Vec<usize>>> = RwLock::new(None);
+
+//CUDA_VISIBLE_DEVICES for this crate only, for code that can't touch the process environment
+//(a plugin inside someone else's process): from now on ordinal i given to CudaDevice::new, the
+//builder or device_total_mem means the device at ordinals[i] of the ones the driver sees.
+//Every entry is checked against the driver's device count first, an unknown one is
+//CUDA_ERROR_INVALID_DEVICE and leaves the mapping as it was. Devices that already exist keep
+//the device they were made with, CudaDevice::ordinal stays the ordinal they were asked for.
+pub fn set_visible_devices(ordinals: &[usize]) -> Result<(), result::CudaError> {
+    result::ensure_init()?;
+    let count = result::device::get_count()? as usize;
+    if ordinals.iter().any(|&ordinal| ordinal >= count) {
+        return Err(result::CudaError::Driver(sys::CUresult::CUDA_ERROR_INVALID_DEVICE));
+    }
+    *VISIBLE_DEVICES.write().unwrap_or_else(|e| e.into_inner()) = Some(ordinals.to_vec());
+    Ok(())
+}
+
+//ordinal through the set_visible_devices table, unchanged when there is none
+fn physical_ordinal(ordinal: usize) -> Result<i32, result::CudaError> {
+    let visible = VISIBLE_DEVICES.read().unwrap_or_else(|e| e.into_inner());
+    match visible.as_deref() {
+        None => Ok(ordinal as i32),
+        Some(table) => table
+            .get(ordinal)
+            .map(|&physical| physical as i32)
+            .ok_or(result::CudaError::Driver(sys::CUresult::CUDA_ERROR_INVALID_DEVICE)),
+    }
+}
+
+//the inverse of physical_ordinal, for a device handle that didn't come from an ordinal here
+fn logical_ordinal(physical: i32) -> usize {
+    let visible = VISIBLE_DEVICES.read().unwrap_or_else(|e| e.into_inner());
+    visible
+        .as_deref()
+        .and_then(|table| table.iter().position(|&p| p as i32 == physical))
+        .unwrap_or(physical as usize)
+}
+
+//device 0, created on first use. OnceLock makes sure only one thread ever creates it,
+//every call binds its context to the calling thread since the context is per-thread state.
+pub fn default_device() -> &'static CudaDevice {
+    let dev = DEFAULT_DEVICE.get_or_init(|| {
+        CudaDevice::new(0).unwrap_or_else(|e| panic!("cudas: could not create default device 0: {}", e))
+    });
+    dev.bind_to_thread()
+        .unwrap_or_else(|e| panic!("cudas: could not bind default device context: {}", e));
+    dev
+}
+
+//every rust closure the driver calls back into goes through this. Unwinding out of an extern "C"
+//function into the driver is UB, so a panic is reported and the process aborted instead.
+fn abort_on_panic<R>(what: &str, f: impl FnOnce() -> R) -> R {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(r) => r,
+        Err(_) => {
+            eprintln!("cudas: panic in {}, aborting instead of unwinding into the CUDA driver", what);
+            std::process::abort()
+        }
+    }
+}
+
+//Drop can't return an error, so like everywhere else it panics. Except while the thread is already
+//unwinding: a second panic would abort without ever showing the first one, so just report it.
+fn check_in_drop(res: Result<(), result::CudaError>) {
+    if let Err(e) = res {
+        if std::thread::panicking() {
+            eprintln!("cudas: {} during drop while unwinding", e);
+        } else {
+            panic!("cudas: {} during drop", e);
+        }
+    }
+}
+
 impl Drop for CudaDevice {
+    //order matters: kernels still queued on the stream may be running code from the modules and
+    //reading the staging buffer, so wait for them before unloading or freeing anything, then
+    //destroy the stream, then let go of the context. The drop can happen on a thread that never
+    //bound the context, hence binding first.
     fn drop(&mut self) {
+        if !self.cu_primary_ctx.is_null() {
+            check_in_drop(self.bind_to_thread());
+        }
+        if !self.cu_stream.is_null() {
+            check_in_drop(unsafe { result::stream::synchronize(self.cu_stream) });
+        }
+        check_in_drop(self.drain_deferred_frees());
+        check_in_drop(self.drain_freelist());
+
         for(_, module) in self.loaded_modules.drain(){
-            unsafe { result::module::unload(module.cu_module) }.unwrap();
+            check_in_drop(unsafe { result::module::unload(module.cu_module) });
+        }
+        let ptx_cache = self.ptx_cache.get_mut().unwrap_or_else(|e| e.into_inner());
+        for cached in ptx_cache.drain().flat_map(|(_, bucket)| bucket) {
+            check_in_drop(unsafe { result::module::unload(cached.cu_module) });
         }
 
         let stream = std::mem::replace(&mut self.cu_stream, std::ptr::null_mut());
         if !stream.is_null() {
-            unsafe { result::stream::destroy(stream) }.unwrap();
+            check_in_drop(unsafe { result::stream::destroy(stream) });
+        }
+
+        if let Some(staging) = self.staging.take() {
+            //a poisoned lock still holds a valid pointer
+            let staging = staging.into_inner().unwrap_or_else(|e| e.into_inner());
+            check_in_drop(unsafe { result::free_host(staging.ptr) });
         }
 
         let ctx = std::mem::replace(&mut self.cu_primary_ctx, std::ptr::null_mut());
-        if !ctx.is_null() {
-            unsafe { result::device::primary_ctx_release(self.cu_device) }.unwrap();
+        if !ctx.is_null() && self.owns_ctx {
+            check_in_drop(unsafe { result::device::primary_ctx_release(self.cu_device) });
         }
     }
 }
 
 impl CudaDevice {
+    //the device stream is created with CU_STREAM_NON_BLOCKING, so it runs independently of the
+    //legacy NULL stream. Use new_with_stream_flags if you mix this crate with libraries that work on the
+    //default stream.
     pub fn new(ordinal: usize) -> Result<Self, result::CudaError> {
-        result::init()?;
-        let cu_device = result::device::get(ordinal as i32)?;
-        let cu_primary_ctx = unsafe { result::device::primary_ctx_retain(cu_device)}?;
-        unsafe { result::ctx::set_current(cu_primary_ctx) }?;
-        let cu_stream = result::stream::create(result::stream::CUstream_flags::CU_STREAM_NON_BLOCKING)?;
-        Ok(Self{
-            cu_device,
-            cu_primary_ctx,
-            cu_stream,
-            loaded_modules: HashMap::new(),
-        })
+        Self::builder().ordinal(ordinal).build()
+    }
+
+    //CU_STREAM_DEFAULT makes the device stream synchronize with the NULL stream: work enqueued here
+    //waits for prior NULL stream work and the other way around. That's what you want when mixing with
+    //cuBLAS/cuDNN handles left on the default stream. CU_STREAM_NON_BLOCKING skips that implicit
+    //synchronization and the ordering against the NULL stream has to be handled yourself.
+    pub fn new_with_stream_flags(
+        ordinal: usize,
+        stream_flags: result::stream::CUstream_flags,
+    ) -> Result<Self, result::CudaError> {
+        Self::builder().ordinal(ordinal).stream_flags(stream_flags).build()
+    }
+
+    //a device on a context some other part of the application created (a renderer, another
+    //library), instead of retaining the primary context. It gets its own stream like new, and drop
+    //leaves the context alone: the modules, stream and memory made through this device are cleaned
+    //up, the context stays with its owner. reset is refused with CUDA_ERROR_NOT_SUPPORTED since it
+    //would tear down the primary context under the owner.
+    //unsafe because ctx has to be a live context on dev and stay alive until this device is dropped.
+    /// # Safety
+    /// ctx must be a live context on dev that outlives the device
+    pub unsafe fn from_context(ctx: sys::CUcontext, dev: sys::CUdevice) -> Result<Self, result::CudaError> {
+        result::ensure_init()?;
+        result::ctx::set_current(ctx)?;
+        if result::ctx::get_device()? != dev {
+            return Err(result::CudaError::Driver(sys::CUresult::CUDA_ERROR_INVALID_DEVICE));
+        }
+        let builder = Self::builder().ordinal(logical_ordinal(dev));
+        builder.with_context(dev, ctx, false)
+    }
+
+    pub fn builder() -> CudaDeviceBuilder {
+        CudaDeviceBuilder::default()
+    }
+
+    //total memory of a device without creating a CudaDevice or touching its context, for picking
+    //a device by capacity
+    pub fn device_total_mem(ordinal: usize) -> Result<usize, result::CudaError> {
+        result::ensure_init()?;
+        let cu_device = result::device::get(physical_ordinal(ordinal)?)?;
+        result::device::total_mem(cu_device)
     }
 
     //unsafe because it memsets all allocated memory to 0, and T may not be valid.
-    pub unsafe fn alloc<T>(&self) -> Result<InCudaMemory<T>, result::CudaError> {
-        let cu_device_ptr = unsafe {
-            result::malloc_async::<T>(self.cu_stream)
-        }?;
-        unsafe {
-            result::memset_d8_async::<T>(cu_device_ptr, 0, self.cu_stream)
-        }?;
-        Ok(InCudaMemory {
-            cu_device_ptr,
-            host_data: None,
-            device: PhantomData,
-        })
+    /// # Safety
+    /// T must be valid when all zeroes
+    pub unsafe fn alloc<T>(&self) -> Result<InCudaMemory<'_, T>, result::AllocError> {
+        Ok(self.alloc_on(self.cu_stream)?)
     }
 
     //the net effect is: data starts on the CPU heap, gets copied to GPU memory, then the CPU copy is freed. The GPU now has the only copy. 
-    pub fn take<T>(&self, host_data: Box<T>) -> Result<InCudaMemory<T>, result::CudaError> {
-        let cu_device_ptr = unsafe {
-            result::malloc_async::<T>(self.cu_stream)
+    pub fn take<T>(&self, host_data: Box<T>) -> Result<InCudaMemory<'_, T>, result::CudaError> {
+        self.take_on(host_data, self.cu_stream)
+    }
+
+    //unsafe because of the same reason with T not being valid.
+    pub fn release<T>(&self, t: InCudaMemory<T>) -> Result<Box<T>, result::CudaError> {
+        self.release_on(t, self.cu_stream)
+    }
+
+    //release with the take_slice_checked stride check. On LayoutMismatch t is discarded, since
+    //its bytes can't be read as a T anyway.
+    pub fn release_checked<T: DeviceRepr>(
+        &self,
+        t: InCudaMemory<T>,
+        expected_stride: usize,
+    ) -> Result<Box<T>, result::CudaError> {
+        if let Err(e) = check_stride::<T>(expected_stride) {
+            self.discard(t)?;
+            return Err(e);
+        }
+        self.release(t)
+    }
+
+    //count zeroed elements. Safe unlike alloc, zero bytes are a valid DeviceRepr.
+    pub fn alloc_n<T: DeviceRepr>(&self, count: usize) -> Result<InCudaMemory<'_, [T]>, result::AllocError> {
+        Ok(unsafe { self.alloc_zeroed_on(checked_bytes::<T>(count)?, count, self.cu_stream) }?)
+    }
+
+    pub fn alloc_slice<T: DeviceRepr>(&self, len: usize) -> Result<InCudaMemory<'_, [T]>, result::AllocError> {
+        self.alloc_n(len)
+    }
+
+    //take for a whole vec. The vec is kept as the host copy just like take keeps its box.
+    pub fn take_slice<T: DeviceRepr>(&self, host_data: Vec<T>) -> Result<InCudaMemory<'_, [T]>, result::CudaError> {
+        let host_data = host_data.into_boxed_slice();
+        let len_bytes = host_data.len() * size_of::<T>();
+        let cu_device_ptr = self.malloc_on(len_bytes, self.cu_stream)?;
+        unsafe {
+            result::memcpy_htod_async_raw(cu_device_ptr, host_data.as_ptr() as *const _, len_bytes, self.cu_stream)
         }?;
-        unsafe { result::memcpy_htod_async(cu_device_ptr, host_data.as_ref(), self.cu_stream) }?;
         Ok(InCudaMemory {
             cu_device_ptr,
+            len_bytes,
+            len: host_data.len(),
+            generation: self.generation,
             host_data: Some(host_data),
+            owned: true,
             device: PhantomData,
         })
     }
 
-    //unsafe because of the same reason with T not being valid.
-    pub fn release<T>(&self, t: InCudaMemory<T>) -> Result<Box<T>, result::CudaError> {
-        let mut host_data = t.host_data.unwrap_or_else(|| {
-            let layout = Layout::new::<T>();
-            unsafe {
-                let ptr = alloc_zeroed(layout) as *mut T;
-                Box::from_raw(ptr)
-            }
-        });
-        unsafe { result::memcpy_dtoh_async(host_data.as_mut(), t.cu_device_ptr, self.cu_stream) }?;
-        self.synchronize()?;
-        unsafe { result::free_async(t.cu_device_ptr, self.cu_stream) }?;
-        Ok(host_data)
+    //take_slice for arrays of your own #[repr(C)] structs: expected_stride is sizeof() of the
+    //matching struct on the CUDA side, and a different size_of::<T>() (a missing #[repr(C)],
+    //different padding) is LayoutMismatch before anything is allocated.
+    pub fn take_slice_checked<T: DeviceRepr>(
+        &self,
+        host_data: Vec<T>,
+        expected_stride: usize,
+    ) -> Result<InCudaMemory<'_, [T]>, result::CudaError> {
+        check_stride::<T>(expected_stride)?;
+        self.take_slice(host_data)
     }
 
-    pub fn synchronize(&self) -> Result<(), result::CudaError> {
-        unsafe { result::stream::synchronize(self.cu_stream) }
+    //n zeroed bytes for data whose type doesn't matter on the rust side, e.g. a serialized blob.
+    //from_bytes turns the result into typed memory later.
+    pub fn alloc_bytes(&self, n: usize) -> Result<InCudaMemory<'_, [u8]>, result::AllocError> {
+        self.alloc_slice(n)
     }
 
-    pub fn has_module(&self, key: &'static str) -> bool {
-        self.loaded_modules.contains_key(key)
+    pub fn take_bytes(&self, data: Vec<u8>) -> Result<InCudaMemory<'_, [u8]>, result::CudaError> {
+        self.take_slice(data)
     }
 
-    pub fn load_module_from_ptx_file(
-        &mut self,
-        key: &'static str,
-        fname: &'static str,
-    ) -> Result<&mut CudaModule, result::CudaError> {
-        let cu_module = result::module::load(fname)?;
-        self.insert_module(key, cu_module);
-        Ok(self.loaded_modules.get_mut(key).unwrap())
+    //height rows of width elements with the pitch the driver picks, zeroed (padding included)
+    pub fn alloc_pitched<T: DeviceRepr>(&self, width: usize, height: usize) -> Result<Pitched2D<'_, T>, result::AllocError> {
+        //cuMemAllocPitch only takes 4, 8 or 16 byte accesses
+        let elem_size = match size_of::<T>() {
+            8 => 8,
+            16 => 16,
+            _ => 4,
+        };
+        let (cu_device_ptr, pitch) = unsafe { result::malloc_pitch(checked_bytes::<T>(width)?, height, elem_size) }?;
+        let num_bytes = match pitch.checked_mul(height) {
+            Some(num_bytes) => num_bytes,
+            None => {
+                unsafe { result::free(cu_device_ptr) }?;
+                return Err(result::CudaError::SizeOverflow { count: height, elem_size: pitch }.into());
+            }
+        };
+        self.track_alloc(num_bytes);
+        unsafe { result::memset_d8_async_raw(cu_device_ptr, 0, num_bytes, self.cu_stream) }?;
+        Ok(Pitched2D {
+            cu_device_ptr,
+            pitch,
+            width,
+            height,
+            generation: self.generation,
+            device: PhantomData,
+            elems: PhantomData,
+        })
     }
 
-    pub unsafe fn load_module_from_ptx_nvrtc(
-        &mut self,
-        key: &'static str,
-        image: *const std::ffi::c_char,
-    ) -> Result<&mut CudaModule, result::CudaError> {
-        let cu_module = result::module::load_data(image as *const _)?;
-        self.insert_module(key, cu_module);
-        Ok(self.loaded_modules.get_mut(key).unwrap())
+    //frees a Pitched2D, memory from before a reset is just forgotten like in discard
+    pub fn discard_pitched<T>(&self, mem: Pitched2D<T>) -> Result<(), result::CudaError> {
+        if mem.generation != self.generation {
+            return Ok(());
+        }
+        self.free_on(mem.cu_device_ptr, mem.pitch * mem.height, self.cu_stream)
     }
 
-    fn insert_module(&mut self, key: &'static str, cu_module: sys::CUmodule) {
-        self.loaded_modules.insert(
-            key,
-            CudaModule {
-                cu_module,
-                functions: HashMap::with_capacity(1),
-            },
-        );
+    //len elements of managed memory, with undefined contents, fine since any bytes are a valid
+    //DeviceRepr. Devices without CU_DEVICE_ATTRIBUTE_MANAGED_MEMORY get CUDA_ERROR_NOT_SUPPORTED.
+    pub fn alloc_managed<T: DeviceRepr>(&self, len: usize) -> Result<ManagedMemory<'_, T>, result::AllocError> {
+        if self.attribute(sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_MANAGED_MEMORY)? == 0 {
+            return Err(result::CudaError::Driver(sys::CUresult::CUDA_ERROR_NOT_SUPPORTED).into());
+        }
+        let num_bytes = checked_bytes::<T>(len)?;
+        let flags = sys::CUmemAttach_flags::CU_MEM_ATTACH_GLOBAL;
+        let cu_device_ptr = unsafe { result::malloc_managed(num_bytes, flags) }?;
+        self.track_alloc(num_bytes);
+        Ok(ManagedMemory {
+            cu_device_ptr,
+            len,
+            device: self,
+            elems: PhantomData,
+        })
     }
 
-    pub fn get_module(&self, fname: &str) -> Option<&CudaModule> {
-        self.loaded_modules.get(fname)
+    //uploads a borrowed slice into an existing allocation, no vec needed. src.len() must equal
+    //dst.len(). The copy is queued on the device stream behind any pending work, in order with
+    //it, but it is not async: src is only borrowed for this call, so it waits for the copy before
+    //returning and nothing overlaps with it.
+    pub fn copy_slice_ordered<T: DeviceRepr>(&self, dst: &mut InCudaMemory<[T]>, src: &[T]) -> Result<(), result::CudaError> {
+        self.upload_slice(dst, src, self.cu_stream)
     }
 
+    //host to device copy of src into dst (same length), on the device stream or one of your choice:
+    //CopyMode::Sync queues it behind the kernels already on the device stream, CopyMode::Async(stream)
+    //behind the work already on stream, and both wait for that stream since src is only borrowed.
+    //A plain cuMemcpyHtoD would go on the legacy stream, which the non blocking device stream
+    //doesn't wait for, so it could race a kernel still using dst.
+    pub fn copy<T: DeviceRepr>(
+        &self,
+        dst: &mut InCudaMemory<[T]>,
+        src: &[T],
+        mode: CopyMode,
+    ) -> Result<(), result::CudaError> {
+        match mode {
+            CopyMode::Sync => self.upload_slice(dst, src, self.cu_stream),
+            CopyMode::Async(stream) => {
+                debug_warn_pageable(src.as_ptr() as *const c_void);
+                self.upload_slice(dst, src, stream.cu_stream)
+            }
+        }
+    }
 
-}
+    //every borrowed slice upload, ordered on cu_stream and waited for
+    fn upload_slice<T: DeviceRepr>(
+        &self,
+        dst: &mut InCudaMemory<[T]>,
+        src: &[T],
+        cu_stream: sys::CUstream,
+    ) -> Result<(), result::CudaError> {
+        self.check_generation(dst.generation)?;
+        if src.len() != dst.len() {
+            return Err(result::CudaError::LengthMismatch {
+                expected: dst.len(),
+                actual: src.len(),
+            });
+        }
+        let src_ptr = src.as_ptr() as *const c_void;
+        unsafe { result::memcpy_htod_async_raw(dst.cu_device_ptr, src_ptr, dst.len_bytes, cu_stream) }?;
+        unsafe { result::stream::synchronize(cu_stream) }
+    }
 
-impl CudaModule {
-    pub fn load_fn(&mut self, name: &'static str) -> Result<(), result::CudaError> {
-        let cu_function = unsafe { result::module::get_function(self.cu_module, name) }?;
-        self.functions.insert(name, CudaFunction { cu_function });
-        Ok(())
+    //zeroes the elements in range, queued on the device stream like any other work so it doesn't
+    //wait. Handy for clearing part of a scratch buffer between iterations.
+    pub fn zero_slice<T: DeviceRepr>(
+        &self,
+        mem: &mut InCudaMemory<[T]>,
+        range: Range<usize>,
+    ) -> Result<(), result::CudaError> {
+        self.check_generation(mem.generation)?;
+        check_range(&range, mem.len())?;
+        let dptr = mem.cu_device_ptr + (range.start * size_of::<T>()) as sys::CUdeviceptr;
+        let num_bytes = range.len() * size_of::<T>();
+        unsafe { result::memset_d8_async_raw(dptr, 0, num_bytes, self.cu_stream) }
     }
 
-    pub fn get_fn(&self, name: &str) -> Option<&CudaFunction> {
-        self.functions.get(name)
+    //reads the whole buffer back into a new vec and waits for it. The buffer stays on the device.
+    //mem is anything that gives a DeviceView: &InCudaMemory<[T]>, a tensor's as_slice(), a view.
+    pub fn copy_to_host<'a, T: DeviceRepr + 'a>(
+        &self,
+        mem: impl Into<DeviceView<'a, [T]>>,
+    ) -> Result<Vec<T>, result::CudaError> {
+        let mem = mem.into();
+        self.check_generation(mem.generation)?;
+        let mut host = Vec::with_capacity(mem.len());
+        unsafe {
+            self.download_raw(host.as_mut_ptr() as *mut _, mem.cu_device_ptr, mem.len_bytes)?;
+            //every element was written by the copy and any bytes are a valid DeviceRepr
+            host.set_len(mem.len());
+        }
+        Ok(host)
+    }
+
+    //reads mem back and panics unless it equals expected, naming the first index that differs
+    #[cfg(feature = "test-util")]
+    #[track_caller]
+    pub fn assert_eq_host<T: DeviceRepr + PartialEq + std::fmt::Debug>(&self, mem: &InCudaMemory<[T]>, expected: &[T]) {
+        let actual = match self.copy_to_host(mem) {
+            Ok(actual) => actual,
+            Err(e) => panic!("assert_eq_host: reading the buffer back failed: {}", e),
+        };
+        assert_eq!(
+            actual.len(),
+            expected.len(),
+            "assert_eq_host: device buffer has {} elements, expected {}",
+            actual.len(),
+            expected.len()
+        );
+        if let Some(i) = actual.iter().zip(expected).position(|(a, e)| a != e) {
+            panic!(
+                "assert_eq_host: first difference at index {}: device has {:?}, expected {:?}",
+                i, actual[i], expected[i]
+            );
+        }
     }
-}
\ No newline at end of file
+
+    //copy_to_host of just the elements in range, for when only part of the output means anything
+    pub fn copy_range_to_host<'a, T: DeviceRepr + 'a>(
+        &self,
+        mem: impl Into<DeviceView<'a, [T]>>,
+        range: Range<usize>,
+    ) -> Result<Vec<T>, result::CudaError> {
+        let mem = mem.into();
+        self.check_generation(mem.generation)?;
+        check_range(&range, mem.len())?;
+        let src = mem.cu_device_ptr + (range.start * size_of::<T>()) as sys::CUdeviceptr;
+        let mut host = Vec::with_capacity(range.len());
+        unsafe {
+            self.download_raw(host.as_mut_ptr() as *mut _, src, range.len() * size_of::<T>())?;
+            host.set_len(range.len());
+        }
+        Ok(host)
+    }
+
+    //mem chunk_elems elements at a time, each next downloading one chunk into a new vec, the last
+    //chunk may be shorter. The copies go through one page locked buffer of chunk_elems that's
+    //reused for every chunk, so results can be written out as they come without a host copy of
+    //the whole buffer. An error ends the iteration. Panics if chunk_elems is 0.
+    pub fn download_chunks<'a, T: DeviceRepr + 'a>(
+        &'a self,
+        mem: impl Into<DeviceView<'a, [T]>>,
+        chunk_elems: usize,
+    ) -> DownloadChunks<'a, T> {
+        assert!(chunk_elems != 0, "download_chunks: chunk_elems must not be 0");
+        DownloadChunks {
+            device: self,
+            mem: mem.into(),
+            chunk_elems,
+            offset: 0,
+            pinned: std::ptr::null_mut(),
+        }
+    }
+
+    //copy_to_host into a slice you already have, dst.len() must equal mem.len()
+    pub fn copy_into<'a, T: DeviceRepr + 'a>(
+        &self,
+        mem: impl Into<DeviceView<'a, [T]>>,
+        dst: &mut [T],
+    ) -> Result<(), result::CudaError> {
+        let mem = mem.into();
+        self.check_generation(mem.generation)?;
+        if dst.len() != mem.len() {
+            return Err(result::CudaError::LengthMismatch {
+                expected: mem.len(),
+                actual: dst.len(),
+            });
+        }
+        unsafe { self.download_raw(dst.as_mut_ptr() as *mut _, mem.cu_device_ptr, mem.len_bytes) }
+    }
+
+    //the raw bytes of mem for a checkpoint, in host byte order, which is little-endian on every
+    //platform CUDA runs on, same as the device. import_bytes reads them back.
+    pub fn export_bytes<T: DeviceRepr>(&self, mem: &InCudaMemory<[T]>) -> Result<Vec<u8>, result::CudaError> {
+        self.check_generation(mem.generation)?;
+        let mut bytes = vec![0u8; mem.len_bytes];
+        unsafe { self.download_raw(bytes.as_mut_ptr() as *mut _, mem.cu_device_ptr, mem.len_bytes) }?;
+        Ok(bytes)
+    }
+
+    //a new buffer holding bytes as T elements, finished when this returns. bytes.len() must be a
+    //multiple of size_of::<T>() (InvalidByteLength otherwise), bytes needn't be aligned for T.
+    pub fn import_bytes<T: DeviceRepr>(&self, bytes: &[u8]) -> Result<InCudaMemory<'_, [T]>, result::CudaError> {
+        let elem_size = size_of::<T>();
+        if elem_size == 0 || !bytes.len().is_multiple_of(elem_size) {
+            return Err(result::CudaError::InvalidByteLength {
+                len_bytes: bytes.len(),
+                elem_size,
+            });
+        }
+        let cu_device_ptr = self.malloc_on(bytes.len(), self.cu_stream)?;
+        let mem = InCudaMemory {
+            cu_device_ptr,
+            len_bytes: bytes.len(),
+            len: bytes.len() / elem_size,
+            generation: self.generation,
+            host_data: None,
+            owned: true,
+            device: PhantomData,
+        };
+        let uploaded = unsafe {
+            result::memcpy_htod_async_raw(cu_device_ptr, bytes.as_ptr() as *const _, bytes.len(), self.cu_stream)
+        }
+        .and_then(|()| self.synchronize());
+        if let Err(e) = uploaded {
+            self.discard(mem)?;
+            return Err(e);
+        }
+        Ok(mem)
+    }
+
+    //one copy for every combination of host and device, the driver works out the direction (see
+    //result::memcpy_auto). dst and src must be the same length, host sides must be page locked
+    //(PageableHostMemory otherwise) and device sides from this device. Finished when this
+    //returns, in order with the work on the device stream.
+    pub fn copy_any<T: DeviceRepr>(&self, dst: CopyDst<'_, '_, T>, src: CopySrc<'_, T>) -> Result<(), result::CudaError> {
+        let (src_ptr, src_len) = match &src {
+            CopySrc::Host(host) => (host.as_ptr() as sys::CUdeviceptr, host.len()),
+            CopySrc::Device(view) => {
+                self.check_generation(view.generation)?;
+                (view.cu_device_ptr, view.len())
+            }
+        };
+        let (dst_ptr, dst_len) = match &dst {
+            CopyDst::Host(host) => (host.as_ptr() as sys::CUdeviceptr, host.len()),
+            CopyDst::Device(mem) => {
+                self.check_generation(mem.generation)?;
+                (mem.cu_device_ptr, mem.len())
+            }
+        };
+        if dst_len != src_len {
+            return Err(result::CudaError::LengthMismatch {
+                expected: dst_len,
+                actual: src_len,
+            });
+        }
+        unsafe { result::memcpy_auto(dst_ptr, src_ptr, src_len * size_of::<T>(), self.cu_stream) }?;
+        //host sides are only borrowed, the copy can't outlive this call
+        self.synchronize()
+    }
+
+    //copy_into that copies the first min(mem.len(), dst.len()) elements instead of failing on a
+    //length mismatch, and returns how many that was. For reading the head of a buffer into a
+    //fixed size host one.
+    pub fn copy_to_host_truncated<'a, T: DeviceRepr + 'a>(
+        &self,
+        mem: impl Into<DeviceView<'a, [T]>>,
+        dst: &mut [T],
+    ) -> Result<usize, result::CudaError> {
+        let mem = mem.into();
+        self.check_generation(mem.generation)?;
+        let count = mem.len().min(dst.len());
+        unsafe { self.download_raw(dst.as_mut_ptr() as *mut _, mem.cu_device_ptr, count * size_of::<T>()) }?;
+        Ok(count)
+    }
+
+    //device -> host copy of num_bytes that has finished when this returns. Goes through the
+    //staging buffer when there is one big enough.
+    pub(crate) unsafe fn download_raw(
+        &self,
+        dst: *mut c_void,
+        src: sys::CUdeviceptr,
+        num_bytes: usize,
+    ) -> Result<(), result::CudaError> {
+        if let Some(staging) = &self.staging {
+            let staging = staging.lock().unwrap_or_else(|e| e.into_inner());
+            if num_bytes <= staging.len {
+                result::memcpy_dtoh_async_raw(staging.ptr, src, num_bytes, self.cu_stream)?;
+                self.synchronize()?;
+                std::ptr::copy_nonoverlapping(staging.ptr as *const u8, dst as *mut u8, num_bytes);
+                return Ok(());
+            }
+        }
+        result::memcpy_dtoh_async_raw(dst, src, num_bytes, self.cu_stream)?;
+        self.synchronize()
+    }
+
+    //release, but the data lands in a box the caller already owns instead of a new allocation.
+    //for hot loops that keep recycling the same host buffer. The retained host copy in t is dropped.
+    pub fn release_into<T: DeviceRepr>(&self, t: InCudaMemory<T>, dst: &mut Box<T>) -> Result<(), result::CudaError> {
+        self.check_generation(t.generation)?;
+        unsafe { result::memcpy_dtoh_async(dst.as_mut(), t.cu_device_ptr, self.cu_stream) }?;
+        self.synchronize()?;
+        self.free_mem(&t, self.cu_stream)?;
+        Ok(())
+    }
+
+    //zeroed tensor
+    pub fn alloc_tensor<T: DeviceRepr, const N: usize>(
+        &self,
+        shape: [usize; N],
+    ) -> Result<DeviceTensor<'_, T, N>, result::AllocError> {
+        let data = self.alloc_slice(checked_numel::<T, N>(&shape)?)?;
+        Ok(DeviceTensor { data, shape })
+    }
+
+    //host_data is the tensor in row major order and must have exactly numel elements
+    pub fn take_tensor<T: DeviceRepr, const N: usize>(
+        &self,
+        shape: [usize; N],
+        host_data: Vec<T>,
+    ) -> Result<DeviceTensor<'_, T, N>, result::AllocError> {
+        let numel = checked_numel::<T, N>(&shape)?;
+        if host_data.len() != numel {
+            return Err(result::CudaError::LengthMismatch {
+                expected: numel,
+                actual: host_data.len(),
+            }
+            .into());
+        }
+        let data = self.take_slice(host_data)?;
+        Ok(DeviceTensor { data, shape })
+    }
+
+    //frees the device memory without reading it back, the host copy is dropped.
+    //memory from before a reset was already freed by the driver, so that just gets forgotten.
+    pub fn discard<T: ?Sized>(&self, mem: InCudaMemory<T>) -> Result<(), result::CudaError> {
+        if mem.generation != self.generation {
+            return Ok(());
+        }
+        self.free_mem(&mem, self.cu_stream)?;
+        Ok(())
+    }
+
+    //len zeroed elements of scratch that only live for f, freed after it returns whether f failed
+    //or not (f's error wins over a failed free). The free is in stream order, so kernels f queued
+    //on the device stream that use the scratch finish first. With enable_freelist, calls with the
+    //same len keep reusing one buffer instead of allocating each time.
+    pub fn with_scratch<T: DeviceRepr, R>(
+        &self,
+        len: usize,
+        f: impl FnOnce(&InCudaMemory<[T]>) -> Result<R, result::CudaError>,
+    ) -> Result<R, result::CudaError> {
+        let scratch = self.alloc_n::<T>(len)?;
+        let res = f(&scratch);
+        let freed = self.discard(scratch);
+        let out = res?;
+        freed?;
+        Ok(out)
+    }
+
+    //the common elementwise pattern in one call: uploads input, allocates an output of the same
+    //length, launches func over it with LaunchConfig::for_num_elems and downloads the output.
+    //func must take its parameters in this order:
+    //
+    //  extern "C" __global__ void square(const float *inp, float *out, unsigned int n) {
+    //      unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    //      if (i < n) { out[i] = inp[i] * inp[i]; }
+    //  }
+    //
+    //a full program is examples/map_kernel.rs with examples/square.cu. Note examples/sin.cu takes
+    //out before inp and doesn't fit. An input longer than u32::MAX is LengthTooLarge.
+    pub fn map_kernel<T: DeviceRepr>(&self, func: &CudaFunction, input: &[T]) -> Result<Vec<T>, result::LaunchError> {
+        if input.is_empty() {
+            return Ok(Vec::new());
+        }
+        let n = kernel_len(input.len())?;
+        self.run(func, input, input.len(), LaunchConfig::for_num_elems(n))
+    }
+
+    //map_kernel with the output type, output length and launch config up to you. Uploads input,
+    //allocates a zeroed output of output_len, launches func with (in, out, input.len() as u32),
+    //waits and downloads the output. Same parameter order as map_kernel:
+    //
+    //  extern "C" __global__ void histogram(const float *inp, unsigned int *bins, unsigned int n)
+    //
+    //anything else (more arguments, several outputs, keeping buffers on the device between
+    //launches) goes through take_slice/alloc_slice, launch and copy_to_host directly.
+    //An input longer than u32::MAX is LengthTooLarge. Both buffers are freed whether the launch
+    //and the copy back worked or not, the first error is the one returned.
+    pub fn run<T: DeviceRepr, U: DeviceRepr>(
+        &self,
+        func: &CudaFunction,
+        input: &[T],
+        output_len: usize,
+        launch: LaunchConfig,
+    ) -> Result<Vec<U>, result::LaunchError> {
+        let n = kernel_len(input.len())?;
+        let inp = self.take_slice(input.to_vec())?;
+        let out = match self.alloc_slice::<U>(output_len) {
+            Ok(out) => out,
+            Err(e) => {
+                self.discard(inp)?;
+                return Err(result::CudaError::from(e).into());
+            }
+        };
+        let output = match unsafe { self.launch_args(func, launch, (&inp, &out, n)) } {
+            Ok(()) => self.copy_to_host(&out).map_err(result::LaunchError::from),
+            Err(e) => Err(e),
+        };
+        let freed_inp = self.discard(inp);
+        let freed_out = self.discard(out);
+        let output = output?;
+        freed_inp?;
+        freed_out?;
+        Ok(output)
+    }
+
+    //the *_on helpers are the actual implementations, shared by the methods above (device stream)
+    //and StreamScoped (any other stream).
+    unsafe fn alloc_on<T>(&self, cu_stream: sys::CUstream) -> Result<InCudaMemory<'_, T>, result::CudaError> {
+        self.alloc_zeroed_on(size_of::<T>(), 1, cu_stream)
+    }
+
+    //the one zeroed allocation path, alloc is len_bytes = size_of::<T>() and alloc_n is count of them.
+    //unsafe for the same reason as alloc, T (or its elements) may not be valid as zeroes.
+    unsafe fn alloc_zeroed_on<T: ?Sized>(
+        &self,
+        len_bytes: usize,
+        len: usize,
+        cu_stream: sys::CUstream,
+    ) -> Result<InCudaMemory<'_, T>, result::CudaError> {
+        let cu_device_ptr = self.malloc_on(len_bytes, cu_stream)?;
+        result::memset_d8_async_raw(cu_device_ptr, 0, len_bytes, cu_stream)?;
+        Ok(InCudaMemory {
+            cu_device_ptr,
+            len_bytes,
+            len,
+            generation: self.generation,
+            host_data: None,
+            owned: true,
+            device: PhantomData,
+        })
+    }
+
+    fn take_on<T>(&self, host_data: Box<T>, cu_stream: sys::CUstream) -> Result<InCudaMemory<'_, T>, result::CudaError> {
+        let cu_device_ptr = self.malloc_on(size_of::<T>(), cu_stream)?;
+        unsafe { result::memcpy_htod_async(cu_device_ptr, host_data.as_ref(), cu_stream) }?;
+        Ok(InCudaMemory {
+            cu_device_ptr,
+            len_bytes: size_of::<T>(),
+            len: 1,
+            generation: self.generation,
+            host_data: Some(host_data),
+            owned: true,
+            device: PhantomData,
+        })
+    }
+
+    fn release_on<T>(&self, mut t: InCudaMemory<T>, cu_stream: sys::CUstream) -> Result<Box<T>, result::CudaError> {
+        self.check_generation(t.generation)?;
+        let host_data = match t.host_data.take() {
+            Some(mut host_data) => {
+                unsafe { result::memcpy_dtoh_async(host_data.as_mut(), t.cu_device_ptr, cu_stream) }?;
+                unsafe { result::stream::synchronize(cu_stream) }?;
+                host_data
+            }
+            //no host copy to overwrite, so the bytes go straight into fresh uninitialized memory
+            None => {
+                let mut host_data = Box::<T>::new_uninit();
+                unsafe { result::memcpy_dtoh_async_uninit(host_data.as_mut(), t.cu_device_ptr, cu_stream) }?;
+                unsafe { result::stream::synchronize(cu_stream) }?;
+                //the copy wrote all size_of::<T>() bytes, same promise about their validity as alloc
+                unsafe { host_data.assume_init() }
+            }
+        };
+        self.free_mem(&t, cu_stream)?;
+        Ok(host_data)
+    }
+
+    //destroys every allocation, module and stream in the primary context and starts over with a
+    //fresh stream. Taking &mut self means no borrowed InCudaMemory can be alive at this point, the
+    //generation check covers the buffers that got around the borrow (unsafe code, raw handles):
+    //they fail with StaleBuffer instead of touching freed memory.
+    pub fn reset(&mut self) -> Result<(), result::CudaError> {
+        if !self.owns_ctx {
+            return Err(result::CudaError::Driver(sys::CUresult::CUDA_ERROR_NOT_SUPPORTED));
+        }
+        self.synchronize()?;
+        self.drain_freelist()?;
+        //the reset frees these with everything else
+        self.deferred_frees.get_mut().unwrap_or_else(|e| e.into_inner()).clear();
+        self.has_deferred.store(false, Ordering::SeqCst);
+        for (_, module) in self.loaded_modules.drain() {
+            unsafe { result::module::unload(module.cu_module) }?;
+        }
+        let ptx_cache = self.ptx_cache.get_mut().unwrap_or_else(|e| e.into_inner());
+        for cached in ptx_cache.drain().flat_map(|(_, bucket)| bucket) {
+            unsafe { result::module::unload(cached.cu_module) }?;
+        }
+        let stream = std::mem::replace(&mut self.cu_stream, std::ptr::null_mut());
+        unsafe { result::stream::destroy(stream) }?;
+        //the reset frees the staging buffer too
+        let had_staging = self
+            .staging
+            .take()
+            .map(|staging| staging.into_inner().unwrap_or_else(|e| e.into_inner()).len);
+        unsafe { result::device::primary_ctx_reset(self.cu_device) }?;
+        self.generation += 1;
+        self.current_bytes.store(0, Ordering::SeqCst);
+        unsafe { result::ctx::set_current(self.cu_primary_ctx) }?;
+        self.cu_stream = result::stream::create(self.stream_flags)?;
+        self.staging = alloc_staging(had_staging.unwrap_or(0))?;
+        Ok(())
+    }
+
+    //starts counting allocations made from now on. cuMemGetInfo reports the whole gpu including
+    //other processes, these numbers only cover buffers created by this device. Memory allocated
+    //before tracking was enabled isn't counted, freeing it saturates at 0 instead of wrapping.
+    pub fn enable_tracking(&self) {
+        self.tracking.store(true, Ordering::SeqCst);
+    }
+
+    pub fn current_bytes(&self) -> usize {
+        self.current_bytes.load(Ordering::SeqCst)
+    }
+
+    pub fn peak_bytes(&self) -> usize {
+        self.peak_bytes.load(Ordering::SeqCst)
+    }
+
+    //by default the async allocator's pool holds on to everything that was freed, so the process
+    //looks like it leaks to monitoring tools. With a threshold the pool gives back whatever it has
+    //reserved above num_bytes when a stream/event/device synchronizes. 0 returns memory to the os
+    //as aggressively as possible: lowest footprint, but later allocations have to go back to the driver.
+    pub fn set_mempool_release_threshold(&self, num_bytes: u64) -> Result<(), result::CudaError> {
+        let pool = result::mem_pool::get_default(self.cu_device)?;
+        unsafe { result::mem_pool::set_release_threshold(pool, num_bytes) }
+    }
+
+    pub fn uuid(&self) -> Result<[u8; 16], result::CudaError> {
+        result::device::get_uuid(self.cu_device)
+    }
+
+    //the uuid the way nvidia-smi -L prints it, GPU-xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx
+    pub fn uuid_string(&self) -> Result<String, result::CudaError> {
+        let uuid = self.uuid()?;
+        let mut out = String::from("GPU");
+        for (i, byte) in uuid.iter().enumerate() {
+            if matches!(i, 0 | 4 | 6 | 8 | 10) {
+                out.push('-');
+            }
+            out.push_str(&format!("{:02x}", byte));
+        }
+        Ok(out)
+    }
+
+    pub fn context_api_version(&self) -> Result<u32, result::CudaError> {
+        unsafe { result::ctx::api_version(self.cu_primary_ctx) }
+    }
+
+    pub fn ordinal(&self) -> usize {
+        self.ordinal
+    }
+
+    //relative speed of the link from this device to other, lower is faster. For a scheduler picking
+    //which pairs to put peer copies on. Comparing a device with itself is CUDA_ERROR_INVALID_DEVICE.
+    pub fn p2p_performance_rank(&self, other: &CudaDevice) -> Result<i32, result::CudaError> {
+        use sys::CUdevice_P2PAttribute::CU_DEVICE_P2P_ATTRIBUTE_PERFORMANCE_RANK;
+        result::device::get_p2p_attribute(CU_DEVICE_P2P_ATTRIBUTE_PERFORMANCE_RANK, self.cu_device, other.cu_device)
+    }
+
+    //whether this device can read and write other's memory directly
+    pub fn p2p_access_supported(&self, other: &CudaDevice) -> Result<bool, result::CudaError> {
+        use sys::CUdevice_P2PAttribute::CU_DEVICE_P2P_ATTRIBUTE_ACCESS_SUPPORTED;
+        let supported = result::device::get_p2p_attribute(CU_DEVICE_P2P_ATTRIBUTE_ACCESS_SUPPORTED, self.cu_device, other.cu_device)?;
+        Ok(supported != 0)
+    }
+
+    //the CUdevice for driver calls this crate doesn't wrap (migration targets, peer access...)
+    pub fn device_handle(&self) -> sys::CUdevice {
+        self.cu_device
+    }
+
+    //whether cuMemAllocAsync/cuMemFreeAsync work here (CU_DEVICE_ATTRIBUTE_MEMORY_POOLS_SUPPORTED).
+    //when they don't every allocation falls back to cuMemAlloc/cuMemFree.
+    pub fn supports_async_alloc(&self) -> Result<bool, result::CudaError> {
+        let supported = self.attribute(sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_MEMORY_POOLS_SUPPORTED)?;
+        Ok(supported != 0)
+    }
+
+    //whether allocations actually use the stream ordered allocator: supports_async_alloc, and the
+    //driver is new enough to have cuMemAllocAsync/cuMemFreeAsync. Looked up at runtime, so an old
+    //driver gets the cuMemAlloc/cuMemFree fallback instead of failing to load.
+    pub fn async_alloc_available(&self) -> bool {
+        self.async_alloc.is_some()
+    }
+
+    //every allocation and free goes through these two so the async/sync choice and the tracking
+    //live in one place. 0 bytes (a zero sized T, an empty slice) never reaches the driver, what
+    //cuMemAlloc does with 0 is up to the driver. Those buffers get ZST_DEVICE_PTR, the copies and
+    //memsets in result skip 0 bytes and free_on skips freeing it, so release of a ZST just hands
+    //back the Box.
+    pub(crate) fn malloc_on(&self, num_bytes: usize, cu_stream: sys::CUstream) -> Result<sys::CUdeviceptr, result::CudaError> {
+        if num_bytes == 0 {
+            return Ok(ZST_DEVICE_PTR);
+        }
+        self.drain_deferred_frees()?;
+        if let Some(dptr) = self.freelist_pop(num_bytes, cu_stream) {
+            self.track_alloc(num_bytes);
+            return Ok(dptr);
+        }
+        let cu_device_ptr = if let Some(fns) = &self.async_alloc {
+            unsafe { fns.malloc(num_bytes, cu_stream) }?
+        } else {
+            //cuMemAlloc while the stream is captured fails with STREAM_CAPTURE_UNSUPPORTED and
+            //invalidates the capture, which doesn't say much. cuMemAllocAsync is fine, it becomes a
+            //graph node. Debug builds only, and they also refuse under CU_STREAM_CAPTURE_MODE_RELAXED
+            //where the driver would allow it, the capture info doesn't tell the mode.
+            if cfg!(debug_assertions) && unsafe { result::stream::is_capturing(cu_stream) }? {
+                return Err(result::CudaError::AllocDuringCapture);
+            }
+            unsafe { result::malloc_raw(num_bytes) }?
+        };
+        self.track_alloc(num_bytes);
+        Ok(cu_device_ptr)
+    }
+
+    //free_on for a buffer, unless it's borrowed memory some other code owns
+    fn free_mem<T: ?Sized>(&self, mem: &InCudaMemory<T>, cu_stream: sys::CUstream) -> Result<(), result::CudaError> {
+        if !mem.owned {
+            return Ok(());
+        }
+        self.free_on(mem.cu_device_ptr, mem.len_bytes, cu_stream)
+    }
+
+    pub(crate) fn free_on(
+        &self,
+        dptr: sys::CUdeviceptr,
+        num_bytes: usize,
+        cu_stream: sys::CUstream,
+    ) -> Result<(), result::CudaError> {
+        if num_bytes == 0 {
+            return Ok(());
+        }
+        if self.freelist_push(dptr, num_bytes, cu_stream) {
+            self.track_free(num_bytes);
+            return Ok(());
+        }
+        if let Some(fns) = &self.async_alloc {
+            unsafe { fns.free(dptr, cu_stream) }?;
+        } else {
+            //cuMemFree isn't stream ordered, work still queued on cu_stream may be using dptr
+            unsafe { result::stream::synchronize(cu_stream) }?;
+            unsafe { result::free(dptr) }?;
+        }
+        self.track_free(num_bytes);
+        Ok(())
+    }
+
+    fn ctx_is_current(&self) -> bool {
+        matches!(result::ctx::get_current(), Ok(ctx) if ctx == self.cu_primary_ctx)
+    }
+
+    //what the Drop of a type owning device memory calls instead of freeing it: frees right away
+    //when this thread has the context current, otherwise queues it for drain_deferred_frees, so
+    //dropping a buffer on some other thread never fails
+    pub(crate) fn free_in_drop(&self, pending: DeferredFree) {
+        if self.ctx_is_current() {
+            check_in_drop(self.free_deferred(pending));
+            return;
+        }
+        self.deferred_frees.lock().unwrap_or_else(|e| e.into_inner()).push(pending);
+        self.has_deferred.store(true, Ordering::SeqCst);
+    }
+
+    fn free_deferred(&self, pending: DeferredFree) -> Result<(), result::CudaError> {
+        if !pending.managed {
+            return self.free_on(pending.dptr, pending.num_bytes, self.cu_stream);
+        }
+        //cuMemFree isn't stream ordered
+        unsafe { result::stream::synchronize(self.cu_stream) }?;
+        unsafe { result::free(pending.dptr) }?;
+        self.track_free(pending.num_bytes);
+        Ok(())
+    }
+
+    //frees what free_in_drop queued, a no-op on threads without the context current. Everything
+    //queued is attempted, the first error is returned.
+    fn drain_deferred_frees(&self) -> Result<(), result::CudaError> {
+        if !self.has_deferred.load(Ordering::SeqCst) || !self.ctx_is_current() {
+            return Ok(());
+        }
+        let pending = {
+            let mut queue = self.deferred_frees.lock().unwrap_or_else(|e| e.into_inner());
+            self.has_deferred.store(false, Ordering::SeqCst);
+            std::mem::take(&mut *queue)
+        };
+        let mut res = Ok(());
+        for pending in pending {
+            let freed = self.free_deferred(pending);
+            if res.is_ok() {
+                res = freed;
+            }
+        }
+        res
+    }
+
+    //from now on buffers freed through discard/release/drop of a DoubleBuffer aren't given back to
+    //the driver but kept for the next allocation of the same byte size, so churning through
+    //fixed size buffers stops calling cuMemAlloc/cuMemFree at all. Only the device stream uses the
+    //list: work on it runs in order, so a reused pointer can't still be in use by earlier work.
+    //Memory on the list stays allocated until reset or drop.
+    pub fn enable_freelist(&self) {
+        self.freelist_enabled.store(true, Ordering::SeqCst);
+    }
+
+    fn freelist_lock(&self) -> std::sync::MutexGuard<'_, HashMap<usize, Vec<sys::CUdeviceptr>>> {
+        //the map is never left half updated, a poisoned lock is still usable
+        self.freelist.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn freelist_pop(&self, num_bytes: usize, cu_stream: sys::CUstream) -> Option<sys::CUdeviceptr> {
+        if cu_stream != self.cu_stream || !self.freelist_enabled.load(Ordering::Relaxed) {
+            return None;
+        }
+        self.freelist_lock().get_mut(&num_bytes).and_then(Vec::pop)
+    }
+
+    fn freelist_push(&self, dptr: sys::CUdeviceptr, num_bytes: usize, cu_stream: sys::CUstream) -> bool {
+        if cu_stream != self.cu_stream || !self.freelist_enabled.load(Ordering::Relaxed) {
+            return false;
+        }
+        self.freelist_lock().entry(num_bytes).or_default().push(dptr);
+        true
+    }
+
+    //really frees what's on the free list, the device stream has to be idle
+    fn drain_freelist(&self) -> Result<(), result::CudaError> {
+        let lists = std::mem::take(&mut *self.freelist_lock());
+        for dptr in lists.into_values().flatten() {
+            unsafe { result::free(dptr) }?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn track_alloc(&self, num_bytes: usize) {
+        if self.tracking.load(Ordering::Relaxed) {
+            let current = self.current_bytes.fetch_add(num_bytes, Ordering::SeqCst) + num_bytes;
+            self.peak_bytes.fetch_max(current, Ordering::SeqCst);
+        }
+    }
+
+    pub(crate) fn track_free(&self, num_bytes: usize) {
+        if self.tracking.load(Ordering::Relaxed) {
+            let _ = self.current_bytes.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |current| {
+                Some(current.saturating_sub(num_bytes))
+            });
+        }
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub(crate) fn check_generation(&self, generation: u64) -> Result<(), result::CudaError> {
+        if generation != self.generation {
+            return Err(result::CudaError::StaleBuffer {
+                buffer_generation: generation,
+                device_generation: self.generation,
+            });
+        }
+        Ok(())
+    }
+
+    //runs f on a driver thread once everything enqueued on the device stream before it has
+    //finished, without blocking the host. f must not call any CUDA function (the driver forbids it
+    //from host callbacks). A panic in f aborts the process, it can't unwind through the driver.
+    pub fn launch_host_func<F: FnOnce() + Send + 'static>(&self, f: F) -> Result<(), result::CudaError> {
+        unsafe extern "C" fn trampoline<F: FnOnce()>(user_data: *mut c_void) {
+            let f = Box::from_raw(user_data as *mut F);
+            abort_on_panic("a host function", f);
+        }
+        let user_data = Box::into_raw(Box::new(f));
+        let res = unsafe { result::stream::launch_host_func(self.cu_stream, trampoline::<F>, user_data as *mut c_void) };
+        if res.is_err() {
+            //never enqueued, so the trampoline won't free it
+            drop(unsafe { Box::from_raw(user_data) });
+        }
+        res
+    }
+
+    //makes this device's context current on the calling thread. new() only binds the thread
+    //it was called from.
+    pub fn bind_to_thread(&self) -> Result<(), result::CudaError> {
+        unsafe { result::ctx::set_current(self.cu_primary_ctx) }
+    }
+
+    //see CudaStream::set_l2_persisting
+    pub fn reset_persisting_l2_cache(&self) -> Result<(), result::CudaError> {
+        self.bind_to_thread()?;
+        result::ctx::reset_persisting_l2_cache()
+    }
+
+    //per thread stack size in bytes for kernels that recurse or use big local arrays. Set it before
+    //launching the kernels that need it, a launch that runs out of stack just fails. The driver
+    //reserves stack for every resident thread, so large values cost a lot of memory.
+    pub fn set_stack_size(&self, num_bytes: usize) -> Result<(), result::CudaError> {
+        self.bind_to_thread()?;
+        result::ctx::set_limit(result::ctx::CUlimit::CU_LIMIT_STACK_SIZE, num_bytes)
+    }
+
+    //heap that device side malloc/new allocate from, 8MB by default. It can only be changed before
+    //the first launch of a kernel that uses malloc, after that the driver returns an error.
+    pub fn set_malloc_heap_size(&self, num_bytes: usize) -> Result<(), result::CudaError> {
+        self.bind_to_thread()?;
+        result::ctx::set_limit(result::ctx::CUlimit::CU_LIMIT_MALLOC_HEAP_SIZE, num_bytes)
+    }
+
+    pub fn limit(&self, limit: result::ctx::CUlimit) -> Result<usize, result::CudaError> {
+        self.bind_to_thread()?;
+        result::ctx::get_limit(limit)
+    }
+
+    pub fn synchronize(&self) -> Result<(), result::CudaError> {
+        unsafe { result::stream::synchronize(self.cu_stream) }?;
+        self.drain_deferred_frees()
+    }
+
+    //synchronize for async code: the future resolves once everything enqueued on the device stream
+    //before its first poll has finished, without blocking a thread on the wait. A host function
+    //(launch_host_func) queued behind that work wakes the task, and the stream's error state is
+    //checked then, so a failed kernel still comes back as Err. Once the context has hit an error
+    //the driver stops running host functions, so as a fallback one watcher thread shared by all
+    //pending futures queries an event recorded behind the host function every WATCH_INTERVAL and
+    //wakes the task when it completed or failed. Works with any executor, nothing here depends
+    //on one.
+    pub fn synchronize_async(&self) -> SynchronizeFuture<'_> {
+        SynchronizeFuture {
+            device: self,
+            state: None,
+            event: None,
+        }
+    }
+
+    //synchronize that an interactive tool can give up on: polls the device stream every
+    //millisecond and returns Cancelled as soon as should_cancel is set, e.g. from a ctrl-c handler
+    //or a ui thread. Cancelling only stops the waiting, the queued work keeps running on the GPU
+    //(a hung kernel stays hung until the context is reset or the process exits).
+    pub fn synchronize_with_cancel(&self, should_cancel: &AtomicBool) -> Result<SyncOutcome, result::CudaError> {
+        loop {
+            if unsafe { result::stream::query(self.cu_stream) }? {
+                return Ok(SyncOutcome::Completed);
+            }
+            if should_cancel.load(Ordering::Relaxed) {
+                return Ok(SyncOutcome::Cancelled);
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    //a checkpoint: waits for everything queued in the context, the device stream and every
+    //CudaStream, and returns the first failure any of it hit. Launches and async copies only
+    //report errors at the next call that waits, which can be far from the cause, so long async
+    //sequences can flush at their boundaries to fail there instead. synchronize only waits for the
+    //device stream.
+    pub fn flush(&self) -> Result<(), result::CudaError> {
+        self.bind_to_thread()?;
+        self.synchronize()?;
+        result::ctx::synchronize()
+    }
+
+    //post mortem for a failed launch or synchronize: waits for the whole context and describes the
+    //error it is stuck with, None if there is none. Kernel faults (asserts, bad addresses) are
+    //sticky, every later call in the context fails with the same code until reset(), so this is
+    //all the driver can tell after the fact. It's best effort: the code says what kind of fault
+    //happened, not which launch caused it (turn on eager_error_check for that) and not the
+    //assert's text, which the device printf'd to the process's stderr.
+    pub fn last_error_message(&self) -> Option<String> {
+        use sys::CUresult::*;
+        self.bind_to_thread().ok()?;
+        let code = match result::ctx::synchronize() {
+            Ok(()) => return None,
+            Err(result::CudaError::Driver(code)) => code,
+            Err(e) => return Some(e.to_string()),
+        };
+        let name = result::error_name(code).unwrap_or("unknown error");
+        let mut message = match result::error_string(code) {
+            Some(text) => format!("{}: {}", name, text),
+            None => format!("{} ({})", name, code as i32),
+        };
+        let hint = match code {
+            CUDA_ERROR_ASSERT => Some("a device side assert() failed, its message was printed to stderr"),
+            CUDA_ERROR_ILLEGAL_ADDRESS | CUDA_ERROR_MISALIGNED_ADDRESS | CUDA_ERROR_INVALID_ADDRESS_SPACE => {
+                Some("a kernel accessed memory it shouldn't, usually an out of bounds index or a freed buffer")
+            }
+            CUDA_ERROR_HARDWARE_STACK_ERROR => Some("a kernel overflowed its stack, see set_stack_size"),
+            CUDA_ERROR_LAUNCH_TIMEOUT => Some("a kernel ran into the display watchdog timeout"),
+            CUDA_ERROR_ILLEGAL_INSTRUCTION | CUDA_ERROR_INVALID_PC | CUDA_ERROR_LAUNCH_FAILED => {
+                Some("a kernel crashed")
+            }
+            _ => None,
+        };
+        if let Some(hint) = hint {
+            message.push_str(". ");
+            message.push_str(hint);
+            message.push_str(", the context is unusable until reset()");
+        }
+        Some(message)
+    }
+
+    //synchronize, returning how long the host sat blocked in it. Near zero means the stream was
+    //already idle, anything bigger is time the host spent waiting on the GPU.
+    pub fn synchronize_timed(&self) -> Result<Duration, result::CudaError> {
+        let start = Instant::now();
+        self.synchronize()?;
+        Ok(start.elapsed())
+    }
+
+    //starts the profiler now and stops it when the guard drops, so with
+    //`ncu --profile-from-start off` only what runs inside the scope gets captured:
+    //    warmup(&dev)?;
+    //    { let _p = dev.profiler_scope()?; step(&dev)?; }
+    //without a profiler attached it costs nothing
+    pub fn profiler_scope(&self) -> Result<ProfilerScope<'_>, result::CudaError> {
+        self.bind_to_thread()?;
+        result::profiler::start()?;
+        Ok(ProfilerScope { device: self })
+    }
+
+    //unsafe because params has to match the kernel signature, one pointer per kernel argument
+    //pointing at the argument's value. the grid is checked against the device limits first so an
+    //oversized axis gets a useful error instead of CUDA_ERROR_INVALID_VALUE.
+    /// # Safety
+    /// params must hold one pointer per kernel argument, pointing at a value of the type the kernel expects
+    pub unsafe fn launch(
+        &self,
+        func: &CudaFunction,
+        cfg: LaunchConfig,
+        params: &mut [*mut c_void],
+    ) -> Result<(), result::LaunchError> {
+        Ok(self.launch_on(func, cfg, params, self.cu_stream)?)
+    }
+
+    //launch with the arguments as a tuple instead of a hand built pointer array:
+    //    dev.launch_args(&scale, cfg, (&buf, n, 2.0f32))?;
+    //still unsafe: with a 12.3+ driver the count and the size of every argument are checked
+    //against the kernel (ParamCountMismatch / ParamSizeMismatch), but a u32 where the kernel
+    //wants a float has the right size and gets through.
+    /// # Safety
+    /// the argument types must match the kernel's, only the sizes are checked
+    pub unsafe fn launch_args<A: LaunchArgs>(
+        &self,
+        func: &CudaFunction,
+        cfg: LaunchConfig,
+        args: A,
+    ) -> Result<(), result::LaunchError> {
+        func.check_param_sizes(&args.param_sizes())?;
+        let mut params = args.kernel_params();
+        self.launch(func, cfg, &mut params)
+    }
+
+    unsafe fn launch_on(
+        &self,
+        func: &CudaFunction,
+        cfg: LaunchConfig,
+        params: &mut [*mut c_void],
+        cu_stream: sys::CUstream,
+    ) -> Result<(), result::CudaError> {
+        self.check_grid_dim(cfg.grid_dim)?;
+        self.check_shared_mem(func, cfg.shared_mem_bytes)?;
+        func.check_param_count(params.len())?;
+        result::launch_kernel(
+            func.cu_function,
+            cfg.grid_dim,
+            cfg.block_dim,
+            cfg.shared_mem_bytes,
+            cu_stream,
+            params,
+        )?;
+        if self.eager_error_check {
+            result::stream::synchronize(cu_stream)?;
+        }
+        Ok(())
+    }
+
+    //launch through cuLaunchKernelEx so cfg can carry a cluster size and launch attributes.
+    //Same grid check and eager error check as launch.
+    /// # Safety
+    /// same as launch
+    pub unsafe fn launch_ex(
+        &self,
+        func: &CudaFunction,
+        cfg: &LaunchConfigEx,
+        params: &mut [*mut c_void],
+    ) -> Result<(), result::LaunchError> {
+        use sys::CUlaunchAttributeID::*;
+        self.check_grid_dim(cfg.grid_dim)?;
+        self.check_shared_mem(func, cfg.shared_mem_bytes)?;
+        func.check_param_count(params.len())?;
+        let mut attrs = Vec::with_capacity(cfg.attributes.len() + 1);
+        if let Some((x, y, z)) = cfg.cluster_dim {
+            let actual = self.compute_capability()?;
+            if actual < (9, 0) {
+                return Err(result::CudaError::ComputeCapabilityTooLow { required: (9, 0), actual }.into());
+            }
+            let mut value: sys::CUlaunchAttributeValue = std::mem::zeroed();
+            value.clusterDim = sys::CUlaunchAttributeValue_union__bindgen_ty_1 { x, y, z };
+            attrs.push(launch_attribute(CU_LAUNCH_ATTRIBUTE_CLUSTER_DIMENSION, value));
+        }
+        for attr in &cfg.attributes {
+            let mut value: sys::CUlaunchAttributeValue = std::mem::zeroed();
+            let id = match *attr {
+                LaunchAttribute::Cooperative(on) => {
+                    value.cooperative = on as i32;
+                    CU_LAUNCH_ATTRIBUTE_COOPERATIVE
+                }
+                LaunchAttribute::ProgrammaticStreamSerialization(on) => {
+                    value.programmaticStreamSerializationAllowed = on as i32;
+                    CU_LAUNCH_ATTRIBUTE_PROGRAMMATIC_STREAM_SERIALIZATION
+                }
+                LaunchAttribute::Priority(priority) => {
+                    value.priority = priority;
+                    CU_LAUNCH_ATTRIBUTE_PRIORITY
+                }
+            };
+            attrs.push(launch_attribute(id, value));
+        }
+        let config = sys::CUlaunchConfig {
+            gridDimX: cfg.grid_dim.0,
+            gridDimY: cfg.grid_dim.1,
+            gridDimZ: cfg.grid_dim.2,
+            blockDimX: cfg.block_dim.0,
+            blockDimY: cfg.block_dim.1,
+            blockDimZ: cfg.block_dim.2,
+            sharedMemBytes: cfg.shared_mem_bytes,
+            hStream: self.cu_stream,
+            attrs: attrs.as_mut_ptr(),
+            numAttrs: attrs.len() as u32,
+        };
+        result::launch_kernel_ex(&config, func.cu_function, params)?;
+        if self.eager_error_check {
+            self.synchronize()?;
+        }
+        Ok(())
+    }
+
+    //an extra stream on this device, created with the same flags as the device stream. Kernels on
+    //different streams only run at the same time when supports_concurrent_kernels says so,
+    //otherwise they serialize and streams only buy copy/compute overlap.
+    pub fn new_stream(&self) -> Result<CudaStream<'_>, result::CudaError> {
+        let cu_stream = result::stream::create(self.stream_flags)?;
+        Ok(CudaStream {
+            cu_stream,
+            device: PhantomData,
+        })
+    }
+
+    //new_stream with a scheduling priority, lower is more urgent. Out of range values get clamped by
+    //the driver, CudaStream::priority shows the one it picked.
+    pub fn new_stream_with_priority(&self, priority: i32) -> Result<CudaStream<'_>, result::CudaError> {
+        let cu_stream = result::stream::create_with_priority(self.stream_flags, priority)?;
+        Ok(CudaStream {
+            cu_stream,
+            device: PhantomData,
+        })
+    }
+
+    //the regular alloc/take/release/launch api, but everything is enqueued on stream instead of
+    //the device stream. Nothing orders the two streams against each other.
+    pub fn on_stream<'a>(&'a self, stream: &'a CudaStream<'a>) -> StreamScoped<'a> {
+        StreamScoped {
+            device: self,
+            stream,
+        }
+    }
+
+    //events are only used for ordering here, CU_EVENT_DEFAULT if you want to time with them
+    pub fn new_event(&self, flags: result::event::CUevent_flags) -> Result<CudaEvent<'_>, result::CudaError> {
+        let cu_event = result::event::create(flags)?;
+        Ok(CudaEvent {
+            cu_event,
+            device: PhantomData,
+        })
+    }
+
+    //n CU_EVENT_DEFAULT events for pipelines that record several events per iteration (one per
+    //stage, say) and don't want to create and destroy them every time. acquire hands them out
+    //round robin, so an event comes back around after n acquires: n has to cover every event
+    //that is still waited on or timed at once. Panics if n is 0.
+    pub fn event_pool(&self, n: usize) -> Result<EventPool<'_>, result::CudaError> {
+        assert!(n != 0, "event_pool: n must not be 0");
+        let flags = result::event::CUevent_flags::CU_EVENT_DEFAULT;
+        let events = (0..n).map(|_| self.new_event(flags)).collect::<Result<_, _>>()?;
+        Ok(EventPool {
+            events,
+            next: Cell::new(0),
+        })
+    }
+
+    //runs f and returns its result with the GPU time in ms of everything f enqueued on the device
+    //stream: kernels, but also take/release/copies, so transfer bandwidth can be measured with
+    //bandwidth_gbps. Waits for that work to finish.
+    pub fn time<R>(
+        &self,
+        f: impl FnOnce() -> Result<R, result::CudaError>,
+    ) -> Result<(R, f32), result::CudaError> {
+        let flags = result::event::CUevent_flags::CU_EVENT_DEFAULT;
+        let start = self.new_event(flags)?;
+        let end = self.new_event(flags)?;
+        start.record_on_device(self)?;
+        let r = f()?;
+        end.record_on_device(self)?;
+        end.synchronize()?;
+        Ok((r, end.elapsed_ms_since(&start)?))
+    }
+
+    //captures what f enqueues on the device stream into a graph instead of running it. f should
+    //only launch and make async copies: anything that waits on the stream (copy_to_host, release,
+    //synchronize, launch with eager_error_check) invalidates the capture and record returns the
+    //driver's error. Buffers f borrows stay borrowed for as long as the pipeline lives.
+    pub fn record<'a, F>(&'a self, f: F) -> Result<CapturedPipeline<'a>, result::CudaError>
+    where
+        F: FnOnce(&CudaDevice) -> Result<(), result::CudaError> + 'a,
+    {
+        let mode = result::stream::CUstreamCaptureMode::CU_STREAM_CAPTURE_MODE_THREAD_LOCAL;
+        unsafe { result::stream::begin_capture(self.cu_stream, mode) }?;
+        let recorded = f(self);
+        //the capture has to end even if f failed, otherwise the stream stays in capture mode
+        let cu_graph = unsafe { result::stream::end_capture(self.cu_stream) };
+        let cu_graph = match (recorded, cu_graph) {
+            (Ok(()), Ok(cu_graph)) => cu_graph,
+            (Err(e), Ok(cu_graph)) => {
+                unsafe { result::graph::destroy(cu_graph) }?;
+                return Err(e);
+            }
+            (Err(e), Err(_)) | (Ok(()), Err(e)) => return Err(e),
+        };
+        let cu_graph_exec = match unsafe { result::graph::instantiate(cu_graph) } {
+            Ok(exec) => exec,
+            Err(e) => {
+                unsafe { result::graph::destroy(cu_graph) }?;
+                return Err(e);
+            }
+        };
+        Ok(CapturedPipeline {
+            device: self,
+            cu_graph,
+            cu_graph_exec,
+        })
+    }
+
+    //an empty graph to add kernel, memcpy and memset nodes to with explicit dependencies, for when
+    //record's capture of a closure is not enough control. GraphBuilder::instantiate gives the same
+    //CapturedPipeline record does.
+    pub fn graph_builder(&self) -> Result<GraphBuilder<'_>, result::CudaError> {
+        self.bind_to_thread()?;
+        Ok(GraphBuilder {
+            device: self,
+            cu_graph: result::graph::create()?,
+            id: NEXT_GRAPH_BUILDER_ID.fetch_add(1, Ordering::Relaxed),
+            buffers: PhantomData,
+        })
+    }
+
+    //buffers of len elements for DoubleBuffer::submit, every submit has to be exactly len long
+    pub fn double_buffer<T: DeviceRepr>(&self, len: usize) -> Result<DoubleBuffer<'_, T>, result::CudaError> {
+        let no_timing = result::event::CUevent_flags::CU_EVENT_DISABLE_TIMING;
+        Ok(DoubleBuffer {
+            device: self,
+            buffers: [self.alloc_slice(len)?, self.alloc_slice(len)?],
+            streams: [self.new_stream()?, self.new_stream()?],
+            uploaded: [self.new_event(no_timing)?, self.new_event(no_timing)?],
+            computed: [self.new_event(no_timing)?, self.new_event(no_timing)?],
+            next: 0,
+        })
+    }
+
+    fn check_grid_dim(&self, grid_dim: (u32, u32, u32)) -> Result<(), result::CudaError> {
+        let axes = [
+            ('x', grid_dim.0, self.max_grid_dim.0),
+            ('y', grid_dim.1, self.max_grid_dim.1),
+            ('z', grid_dim.2, self.max_grid_dim.2),
+        ];
+        for (axis, requested, limit) in axes {
+            if requested > limit {
+                return Err(result::CudaError::GridDimTooLarge { axis, requested, limit });
+            }
+        }
+        Ok(())
+    }
+
+    //up to MAX_SHARED_MEMORY_PER_BLOCK (48KB) is always fine. Past that only up to what the
+    //kernel opted into, which is asked from the driver since set_attribute may have raised it.
+    fn check_shared_mem(&self, func: &CudaFunction, requested: u32) -> Result<(), result::CudaError> {
+        use sys::CUfunction_attribute::CU_FUNC_ATTRIBUTE_MAX_DYNAMIC_SHARED_SIZE_BYTES;
+        if requested <= self.max_shared_mem {
+            return Ok(());
+        }
+        let limit = if requested > self.max_shared_mem_optin {
+            self.max_shared_mem_optin
+        } else {
+            func.get_attribute(CU_FUNC_ATTRIBUTE_MAX_DYNAMIC_SHARED_SIZE_BYTES)? as u32
+        };
+        if requested > limit {
+            return Err(result::CudaError::SharedMemTooLarge { requested, limit });
+        }
+        Ok(())
+    }
+
+    pub fn has_module(&self, key: &'static str) -> bool {
+        self.loaded_modules.contains_key(key)
+    }
+
+    pub fn load_module_from_ptx_file(
+        &mut self,
+        key: &'static str,
+        fname: &'static str,
+    ) -> Result<&mut CudaModule, result::CudaError> {
+        let cu_module = result::module::load(fname)?;
+        self.insert_module(key, cu_module, None);
+        Ok(self.loaded_modules.get_mut(key).unwrap())
+    }
+
+    //loads PTX text with cuModuleLoadData and resolves funcs right away. The PTX is kept on the
+    //module so it can be inspected later, see CudaModule::ptx. A nul inside ptx is
+    //CUDA_ERROR_INVALID_PTX, the driver would stop reading there.
+    pub fn load_ptx(
+        &mut self,
+        key: &'static str,
+        ptx: &str,
+        funcs: &[&'static str],
+    ) -> Result<&mut CudaModule, result::CudaError> {
+        let ptx_cstr = ptx_cstring(ptx)?;
+        let cu_module = unsafe { self.jit_load(ptx_cstr.as_ptr() as *const _) }?;
+        self.insert_module(key, cu_module, Some(ptx.to_owned()));
+        let module = self.loaded_modules.get_mut(key).unwrap();
+        for &name in funcs {
+            module.load_fn(name)?;
+        }
+        Ok(module)
+    }
+
+    //load_ptx without the funcs: kernels are resolved the first time get_func asks for them and
+    //cached on the module after that. A module with lots of kernels loads even if some names
+    //are wrong, the CUDA_ERROR_NOT_FOUND just shows up when that kernel is actually asked for.
+    pub fn load_ptx_lazy(&mut self, key: &'static str, ptx: &str) -> Result<&mut CudaModule, result::CudaError> {
+        self.load_ptx(key, ptx, &[])
+    }
+
+    //the function from a loaded module, resolving it if this is the first time it's asked for.
+    //An unknown module key is CUDA_ERROR_NOT_FOUND, same as an unknown function name. Takes
+    //&self, so the result can go straight into launch.
+    pub fn get_func(&self, key: &str, name: &'static str) -> Result<&CudaFunction, result::CudaError> {
+        self.loaded_modules
+            .get(key)
+            .ok_or(result::CudaError::Driver(sys::cudaError_enum::CUDA_ERROR_NOT_FOUND))?
+            .get_or_load_fn(name)
+    }
+
+    //a module from an in memory image, typically include_bytes!("kernels.cubin"). The driver
+    //works out whether it's a cubin, fatbin or PTX; a wrong arch or garbage comes back as
+    //CUDA_ERROR_INVALID_IMAGE / CUDA_ERROR_NO_BINARY_FOR_GPU. PTX has to be nul terminated for the
+    //driver, so text that isn't gets a copy with the nul added.
+    pub fn load_module_from_bytes(
+        &mut self,
+        bytes: &[u8],
+        key: &'static str,
+        funcs: &[&'static str],
+    ) -> Result<&mut CudaModule, result::CudaError> {
+        const ELF_MAGIC: &[u8] = b"\x7fELF";
+        const FATBIN_MAGIC: &[u8] = &0xba55ed50u32.to_le_bytes();
+        if bytes.is_empty() {
+            return Err(result::CudaError::EmptyImage);
+        }
+        let binary = bytes.starts_with(ELF_MAGIC) || bytes.starts_with(FATBIN_MAGIC);
+        let terminated;
+        let image = if binary || bytes.ends_with(&[0]) {
+            bytes
+        } else {
+            terminated = [bytes, &[0]].concat();
+            &terminated
+        };
+        let cu_module = unsafe { self.jit_load(image.as_ptr() as *const _) }?;
+        self.insert_module(key, cu_module, None);
+        let module = self.loaded_modules.get_mut(key).unwrap();
+        for &name in funcs {
+            module.load_fn(name)?;
+        }
+        Ok(module)
+    }
+
+    //load_ptx for code that loads the same PTX over and over (a server building its kernels per
+    //request): the module is looked up by a hash of the PTX and only JIT compiled the first time.
+    //Takes &self so threads sharing an Arc<CudaDevice> share the cache, a miss JIT compiles with
+    //the cache locked so two threads asking for the same PTX don't both compile it. Cached
+    //modules live until the device is reset or dropped.
+    pub fn load_ptx_cached(&self, ptx: &str, funcs: &[&'static str]) -> Result<&CudaModule, result::CudaError> {
+        let mut hasher = DefaultHasher::new();
+        ptx.hash(&mut hasher);
+        let key = hasher.finish();
+        let mut cache = self.ptx_cache.lock().unwrap_or_else(|e| e.into_inner());
+        let bucket = cache.entry(key).or_default();
+        //a hash collision is unlikely but would hand back the wrong kernels, so compare the text too
+        let cached = match bucket.iter().position(|cached| cached.ptx == ptx) {
+            Some(i) => &mut bucket[i],
+            None => {
+                let ptx_cstr = ptx_cstring(ptx)?;
+                let cu_module = unsafe { self.jit_load(ptx_cstr.as_ptr() as *const _) }?;
+                bucket.push(CachedPtx { ptx: ptx.to_owned(), cu_module, views: Vec::new() });
+                bucket.last_mut().unwrap()
+            }
+        };
+        let has_funcs = |module: &CudaModule| funcs.iter().all(|name| module.functions.contains_key(name));
+        if !cached.views.iter().any(|module| has_funcs(module)) {
+            //the newest view already has every func asked for before, so the new one is a superset
+            //of all of them and later calls with any earlier funcs hit it too
+            let mut module = CudaModule {
+                cu_module: cached.cu_module,
+                functions: HashMap::new(),
+                lazy_functions: Mutex::new(HashMap::new()),
+                ptx: Some(cached.ptx.clone()),
+            };
+            let earlier = cached.views.last().into_iter().flat_map(|module| module.functions.keys());
+            for &name in earlier.chain(funcs) {
+                if !module.functions.contains_key(name) {
+                    module.load_fn(name)?;
+                }
+            }
+            cached.views.push(Box::new(module));
+        }
+        let module: &CudaModule = cached.views.iter().find(|module| has_funcs(module)).unwrap();
+        //the box stays where it is and unchanged until reset or drop, see CachedPtx
+        Ok(unsafe { &*(module as *const CudaModule) })
+    }
+
+    /// # Safety
+    /// image must point at a nul terminated PTX string that stays valid for the call
+    pub unsafe fn load_module_from_ptx_nvrtc(
+        &mut self,
+        key: &'static str,
+        image: *const std::ffi::c_char,
+    ) -> Result<&mut CudaModule, result::CudaError> {
+        let cu_module = self.jit_load(image as *const _)?;
+        let ptx = CStr::from_ptr(image).to_string_lossy().into_owned();
+        self.insert_module(key, cu_module, Some(ptx));
+        Ok(self.loaded_modules.get_mut(key).unwrap())
+    }
+
+    //f is called with the JIT compiler's info log after every load from memory (load_ptx,
+    //load_ptx_cached, load_module_from_bytes, load_module_from_ptx_nvrtc), so warnings show up
+    //even when the load works, and with the error log when it doesn't. Empty logs are skipped.
+    //File loads go through cuModuleLoad, which can't return a log.
+    pub fn set_diagnostic_callback(&mut self, f: impl Fn(&str) + Send + Sync + 'static) {
+        self.diagnostics = Some(DiagnosticCallback(Box::new(f)));
+    }
+
+    //cuModuleLoadData, or cuModuleLoadDataEx with log buffers when someone wants the logs
+    unsafe fn jit_load(&self, image: *const c_void) -> Result<sys::CUmodule, result::CudaError> {
+        use sys::CUjit_option::*;
+        const LOG_BYTES: usize = 16 * 1024;
+        let Some(callback) = &self.diagnostics else {
+            return result::module::load_data(image);
+        };
+        let mut info_log = vec![0u8; LOG_BYTES];
+        let mut error_log = vec![0u8; LOG_BYTES];
+        let mut options = [
+            CU_JIT_INFO_LOG_BUFFER,
+            CU_JIT_INFO_LOG_BUFFER_SIZE_BYTES,
+            CU_JIT_ERROR_LOG_BUFFER,
+            CU_JIT_ERROR_LOG_BUFFER_SIZE_BYTES,
+        ];
+        let mut values = [
+            info_log.as_mut_ptr() as *mut c_void,
+            LOG_BYTES as *mut c_void,
+            error_log.as_mut_ptr() as *mut c_void,
+            LOG_BYTES as *mut c_void,
+        ];
+        let res = result::module::load_data_ex(image, &mut options, &mut values);
+        for log in [&info_log, &error_log] {
+            let end = log.iter().position(|&b| b == 0).unwrap_or(log.len());
+            if end > 0 {
+                (callback.0)(&String::from_utf8_lossy(&log[..end]));
+            }
+        }
+        res
+    }
+
+    fn insert_module(&mut self, key: &'static str, cu_module: sys::CUmodule, ptx: Option<String>) {
+        self.loaded_modules.insert(
+            key,
+            CudaModule {
+                cu_module,
+                functions: HashMap::with_capacity(1),
+                lazy_functions: Mutex::new(HashMap::new()),
+                ptx,
+            },
+        );
+    }
+
+    pub fn get_module(&self, fname: &str) -> Option<&CudaModule> {
+        self.loaded_modules.get(fname)
+    }
+
+    fn attribute(&self, attrib: result::device::CUdevice_attribute) -> Result<i32, result::CudaError> {
+        result::device::get_attribute(self.cu_device, attrib)
+    }
+
+    pub fn memory_clock_khz(&self) -> Result<u32, result::CudaError> {
+        let khz = self.attribute(sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_MEMORY_CLOCK_RATE)?;
+        Ok(khz as u32)
+    }
+
+    pub fn memory_bus_width_bits(&self) -> Result<u32, result::CudaError> {
+        let bits = self.attribute(sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_GLOBAL_MEMORY_BUS_WIDTH)?;
+        Ok(bits as u32)
+    }
+
+    pub fn l2_cache_size(&self) -> Result<usize, result::CudaError> {
+        let bytes = self.attribute(sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_L2_CACHE_SIZE)?;
+        Ok(bytes as usize)
+    }
+
+    //the most L2 CU_LIMIT_PERSISTING_L2_CACHE_SIZE can set aside for CudaStream::set_l2_persisting,
+    //0 on devices without persisting L2
+    pub fn max_persisting_l2_cache_size(&self) -> Result<usize, result::CudaError> {
+        let bytes = self.attribute(sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_MAX_PERSISTING_L2_CACHE_SIZE)?;
+        Ok(bytes as usize)
+    }
+
+    pub fn max_threads_per_multiprocessor(&self) -> Result<u32, result::CudaError> {
+        let threads = self.attribute(sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_MAX_THREADS_PER_MULTIPROCESSOR)?;
+        Ok(threads as u32)
+    }
+
+    pub fn max_blocks_per_multiprocessor(&self) -> Result<u32, result::CudaError> {
+        let blocks = self.attribute(sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_MAX_BLOCKS_PER_MULTIPROCESSOR)?;
+        Ok(blocks as u32)
+    }
+
+    //how many blocks of block_size threads fit on one SM at once, worked out the way the occupancy
+    //spreadsheet does it: the block count allowed by each of warps, the block limit, registers and
+    //shared memory, and the smallest one wins. Registers are given out per warp in units of 256,
+    //shared memory in units of 128 bytes plus what the driver reserves per block (1KB from 8.0). It
+    //assumes the L1/shared carveout gives shared memory its maximum, the driver may pick less. For
+    //a kernel you already have, regs_per_thread is CU_FUNC_ATTRIBUTE_NUM_REGS and smem_per_block
+    //its static plus dynamic shared memory. Nothing is launched, only attributes are read.
+    pub fn theoretical_occupancy(
+        &self,
+        block_size: u32,
+        regs_per_thread: u32,
+        smem_per_block: u32,
+    ) -> Result<Occupancy, result::CudaError> {
+        use sys::CUdevice_attribute::*;
+        const REG_ALLOC_UNIT: u32 = 256;
+        const SMEM_ALLOC_UNIT: u32 = 128;
+        let attr = |attrib| self.attribute(attrib).map(|v| v as u32);
+        let warp = self.warp_size()?;
+        let max_warps = self.max_threads_per_multiprocessor()? / warp;
+        let max_blocks = self.max_blocks_per_multiprocessor()?;
+        let regs_per_sm = attr(CU_DEVICE_ATTRIBUTE_MAX_REGISTERS_PER_MULTIPROCESSOR)?;
+        let regs_per_block = attr(CU_DEVICE_ATTRIBUTE_MAX_REGISTERS_PER_BLOCK)?;
+        let smem_per_sm = attr(CU_DEVICE_ATTRIBUTE_MAX_SHARED_MEMORY_PER_MULTIPROCESSOR)?;
+        let smem_reserved = attr(CU_DEVICE_ATTRIBUTE_RESERVED_SHARED_MEMORY_PER_BLOCK)?;
+
+        let warps_per_block = block_size.div_ceil(warp);
+        let launchable = block_size > 0 && block_size <= attr(CU_DEVICE_ATTRIBUTE_MAX_THREADS_PER_BLOCK)?;
+        let by_warps = if launchable { max_warps / warps_per_block } else { 0 };
+        let by_regs = if regs_per_thread == 0 {
+            u32::MAX
+        } else if regs_per_thread.saturating_mul(block_size) > regs_per_block {
+            0
+        } else {
+            let regs_per_warp = (regs_per_thread * warp).next_multiple_of(REG_ALLOC_UNIT);
+            regs_per_sm / regs_per_warp / warps_per_block.max(1)
+        };
+        let smem = smem_per_block
+            .next_multiple_of(SMEM_ALLOC_UNIT)
+            .saturating_add(smem_reserved);
+        let by_smem = smem_per_sm.checked_div(smem).unwrap_or(u32::MAX);
+
+        let (blocks_per_sm, limited_by) = [
+            (by_warps, OccupancyLimit::Warps),
+            (max_blocks, OccupancyLimit::Blocks),
+            (by_regs, OccupancyLimit::Registers),
+            (by_smem, OccupancyLimit::SharedMemory),
+        ]
+        .into_iter()
+        .min_by_key(|&(blocks, _)| blocks)
+        .unwrap();
+        let warps_per_sm = blocks_per_sm * warps_per_block;
+        Ok(Occupancy {
+            blocks_per_sm,
+            warps_per_sm,
+            ratio: warps_per_sm as f32 / max_warps.max(1) as f32,
+            limited_by,
+        })
+    }
+
+    //(major, minor), compares the way you'd expect: (8, 6) < (9, 0)
+    pub fn compute_capability(&self) -> Result<(u32, u32), result::CudaError> {
+        use sys::CUdevice_attribute::*;
+        let major = self.attribute(CU_DEVICE_ATTRIBUTE_COMPUTE_CAPABILITY_MAJOR)?;
+        let minor = self.attribute(CU_DEVICE_ATTRIBUTE_COMPUTE_CAPABILITY_MINOR)?;
+        Ok((major as u32, minor as u32))
+    }
+
+    pub fn warp_size(&self) -> Result<u32, result::CudaError> {
+        let threads = self.attribute(sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_WARP_SIZE)?;
+        Ok(threads as u32)
+    }
+
+    pub fn sm_count(&self) -> Result<u32, result::CudaError> {
+        let sms = self.attribute(sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_MULTIPROCESSOR_COUNT)?;
+        Ok(sms as u32)
+    }
+
+    //whether kernels from different streams can run at the same time
+    //(CU_DEVICE_ATTRIBUTE_CONCURRENT_KERNELS). Without it every kernel runs alone, whatever
+    //stream it's on.
+    pub fn supports_concurrent_kernels(&self) -> Result<bool, result::CudaError> {
+        Ok(self.attribute(sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_CONCURRENT_KERNELS)? != 0)
+    }
+
+    //whether ECC is on for the device memory (CU_DEVICE_ATTRIBUTE_ECC_ENABLED). Check before trusting
+    //results on hardware where it can be turned off. ECC keeps some of the memory for check bits and
+    //costs a little bandwidth, which is why total_mem can come in under the size on the box.
+    pub fn ecc_enabled(&self) -> Result<bool, result::CudaError> {
+        Ok(self.attribute(sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_ECC_ENABLED)? != 0)
+    }
+
+    //true when the windows driver runs the device in TCC mode, false for WDDM (and always on
+    //linux). WDDM batches launches before submitting them, so small kernels timed with time or
+    //CudaEvent can look slower or burstier than the same code on linux or under TCC.
+    pub fn tcc_driver(&self) -> Result<bool, result::CudaError> {
+        Ok(self.attribute(sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_TCC_DRIVER)? != 0)
+    }
+
+    //for_num_elems sized for this device, still one thread per element. Blocks are a multiple of
+    //the warp size and at most 256 threads, and get smaller for small n so that every SM gets at
+    //least one block. A grid too big for the device comes back from launch as GridDimTooLarge.
+    pub fn launch_config_for(&self, n: usize) -> Result<LaunchConfig, result::CudaError> {
+        const MAX_THREADS: usize = 256;
+        let warp = self.warp_size()? as usize;
+        let sms = self.sm_count()? as usize;
+        let per_sm = n.div_ceil(sms.max(1));
+        let block = per_sm.next_multiple_of(warp).clamp(warp, MAX_THREADS.max(warp));
+        let grid = u32::try_from(n.div_ceil(block)).unwrap_or(u32::MAX);
+        Ok(LaunchConfig {
+            grid_dim: (grid.max(1), 1, 1),
+            block_dim: (block as u32, 1, 1),
+            shared_mem_bytes: 0,
+        })
+    }
+
+    pub fn properties(&self) -> Result<DeviceProperties, result::CudaError> {
+        use sys::CUdevice_attribute::*;
+        let attr = |attrib| self.attribute(attrib).map(|v| v as u32);
+        Ok(DeviceProperties {
+            name: result::device::get_name(self.cu_device)?,
+            compute_capability: self.compute_capability()?,
+            total_mem_bytes: result::device::total_mem(self.cu_device)?,
+            multiprocessor_count: self.sm_count()?,
+            max_threads_per_block: attr(CU_DEVICE_ATTRIBUTE_MAX_THREADS_PER_BLOCK)?,
+            warp_size: self.warp_size()?,
+            max_block_dim: (
+                attr(CU_DEVICE_ATTRIBUTE_MAX_BLOCK_DIM_X)?,
+                attr(CU_DEVICE_ATTRIBUTE_MAX_BLOCK_DIM_Y)?,
+                attr(CU_DEVICE_ATTRIBUTE_MAX_BLOCK_DIM_Z)?,
+            ),
+            max_grid_dim: self.max_grid_dim,
+            clock_rate_khz: attr(CU_DEVICE_ATTRIBUTE_CLOCK_RATE)?,
+            memory_clock_khz: self.memory_clock_khz()?,
+            memory_bus_width_bits: self.memory_bus_width_bits()?,
+            l2_cache_bytes: self.l2_cache_size()?,
+            ecc_enabled: self.ecc_enabled()?,
+        })
+    }
+
+    //peak dram bandwidth in GB/s. the reported clock is the base clock and DDR memory moves data
+    //on both edges, hence the 2x. Real kernels land well below this number.
+    pub fn theoretical_bandwidth_gbps(&self) -> Result<f64, result::CudaError> {
+        let clock_hz = self.memory_clock_khz()? as f64 * 1e3;
+        let bus_bytes = self.memory_bus_width_bits()? as f64 / 8.0;
+        Ok(2.0 * clock_hz * bus_bytes / 1e9)
+    }
+
+}
+
+//achieved bandwidth of moving num_bytes in ms milliseconds (e.g. from CudaDevice::time), in the
+//same GB/s as theoretical_bandwidth_gbps
+pub fn bandwidth_gbps(num_bytes: usize, ms: f32) -> f64 {
+    num_bytes as f64 / (ms as f64 * 1e-3) / 1e9
+}
+
+//ptx nul terminated for cuModuleLoadData
+fn ptx_cstring(ptx: &str) -> Result<CString, result::CudaError> {
+    CString::new(ptx).map_err(|_| result::CudaError::Driver(sys::CUresult::CUDA_ERROR_INVALID_PTX))
+}
+
+//len as the unsigned int n that map_kernel/run kernels take
+fn kernel_len(len: usize) -> Result<u32, result::CudaError> {
+    u32::try_from(len).map_err(|_| result::CudaError::LengthTooLarge {
+        len,
+        limit: u32::MAX as usize,
+    })
+}
+
+//count * size_of::<T>() for the allocations that take a count from the caller, an overflow
+//would otherwise wrap around to a tiny allocation that kernels then write past
+fn checked_bytes<T>(count: usize) -> Result<usize, result::CudaError> {
+    let elem_size = size_of::<T>();
+    count
+        .checked_mul(elem_size)
+        .ok_or(result::CudaError::SizeOverflow { count, elem_size })
+}
+
+//the element count of a tensor shape. On overflow SizeOverflow says count rows of the dim that
+//overflowed didn't fit, in bytes since that is what the allocation would have been.
+fn checked_numel<T, const N: usize>(shape: &[usize; N]) -> Result<usize, result::CudaError> {
+    shape.iter().try_fold(1usize, |count, &dim| {
+        count.checked_mul(dim).ok_or(result::CudaError::SizeOverflow {
+            count,
+            elem_size: dim.saturating_mul(size_of::<T>()),
+        })
+    })
+}
+
+//debug builds only: says once per process when an async upload reads pageable host memory. The
+//driver has to stage such copies through its own pinned buffer, so they don't overlap with
+//anything and "async" quietly behaves like a blocking copy. Release builds skip the pointer query.
+#[cfg_attr(not(debug_assertions), allow(unused_variables))]
+fn debug_warn_pageable(src: *const c_void) {
+    #[cfg(debug_assertions)]
+    {
+        static WARNED: AtomicBool = AtomicBool::new(false);
+        if WARNED.load(Ordering::Relaxed) {
+            return;
+        }
+        if let Ok(None) = unsafe { result::pointer_memory_type(src) } {
+            if !WARNED.swap(true, Ordering::Relaxed) {
+                eprintln!(
+                    "cudas: async copy from pageable host memory won't overlap with other work, \
+                     use page locked memory (cuMemAllocHost/cuMemHostRegister) for the source"
+                );
+            }
+        }
+    }
+}
+
+fn check_stride<T>(expected_stride: usize) -> Result<(), result::CudaError> {
+    let actual_stride = size_of::<T>();
+    if actual_stride != expected_stride {
+        return Err(result::CudaError::LayoutMismatch { expected_stride, actual_stride });
+    }
+    Ok(())
+}
+
+fn check_range(range: &Range<usize>, len: usize) -> Result<(), result::CudaError> {
+    if range.start > range.end || range.end > len {
+        return Err(result::CudaError::RangeOutOfBounds {
+            start: range.start,
+            end: range.end,
+            len,
+        });
+    }
+    Ok(())
+}
+
+fn launch_attribute(id: sys::CUlaunchAttributeID, value: sys::CUlaunchAttributeValue) -> sys::CUlaunchAttribute {
+    sys::CUlaunchAttribute { id, pad: [0; 4], value }
+}
+
+impl From<LaunchConfig> for LaunchConfigEx {
+    fn from(cfg: LaunchConfig) -> Self {
+        Self {
+            grid_dim: cfg.grid_dim,
+            block_dim: cfg.block_dim,
+            shared_mem_bytes: cfg.shared_mem_bytes,
+            ..Default::default()
+        }
+    }
+}
+
+impl DynDevice for CudaDevice {
+    fn alloc_bytes(&self, n: usize) -> Result<InCudaMemory<'_, [u8]>, result::AllocError> {
+        CudaDevice::alloc_bytes(self, n)
+    }
+
+    fn discard_bytes(&self, mem: InCudaMemory<'_, [u8]>) -> Result<(), result::CudaError> {
+        self.discard(mem)
+    }
+
+    fn copy_bytes_to_device(&self, dst: &mut InCudaMemory<[u8]>, src: &[u8]) -> Result<(), result::CudaError> {
+        self.copy(dst, src, CopyMode::Sync)
+    }
+
+    fn copy_bytes_to_host(&self, src: &InCudaMemory<[u8]>, dst: &mut [u8]) -> Result<(), result::CudaError> {
+        self.copy_into(src, dst)
+    }
+
+    fn synchronize(&self) -> Result<(), result::CudaError> {
+        CudaDevice::synchronize(self)
+    }
+}
+
+impl std::fmt::Debug for DiagnosticCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("DiagnosticCallback")
+    }
+}
+
+impl Default for CudaDeviceBuilder {
+    fn default() -> Self {
+        Self {
+            ordinal: 0,
+            ctx_flags: None,
+            stream_flags: result::stream::CUstream_flags::CU_STREAM_NON_BLOCKING,
+            eager_error_check: false,
+            staging_bytes: 0,
+        }
+    }
+}
+
+impl CudaDeviceBuilder {
+    pub fn ordinal(mut self, ordinal: usize) -> Self {
+        self.ordinal = ordinal;
+        self
+    }
+
+    //flags for the primary context (scheduling mode, LMEM_RESIZE_TO_MAX, ...). They're shared by
+    //everything in the process using the primary context, not just this device.
+    pub fn ctx_flags(mut self, flags: sys::CUctx_flags) -> Self {
+        self.ctx_flags = Some(flags);
+        self
+    }
+
+    //see CudaDevice::new_with_stream_flags
+    pub fn stream_flags(mut self, flags: result::stream::CUstream_flags) -> Self {
+        self.stream_flags = flags;
+        self
+    }
+
+    //synchronize after every launch. Much slower, but an error is reported by the launch that
+    //caused it instead of by whatever call happens to come next.
+    pub fn eager_error_check(mut self, enabled: bool) -> Self {
+        self.eager_error_check = enabled;
+        self
+    }
+
+    //allocate a page locked host buffer of this many bytes. copy_to_host/copy_into of buffers that
+    //fit go through it, DMA from page locked memory is faster than into a pageable vec.
+    //0 (the default) means no staging buffer.
+    pub fn staging_bytes(mut self, num_bytes: usize) -> Self {
+        self.staging_bytes = num_bytes;
+        self
+    }
+
+    pub fn build(self) -> Result<CudaDevice, result::CudaError> {
+        result::ensure_init()?;
+        let cu_device = result::device::get(physical_ordinal(self.ordinal)?)?;
+        if let Some(flags) = self.ctx_flags {
+            unsafe { result::device::primary_ctx_set_flags(cu_device, flags) }?;
+        }
+        let cu_primary_ctx = unsafe { result::device::primary_ctx_retain(cu_device)}?;
+        let built = unsafe { result::ctx::set_current(cu_primary_ctx) }
+            .and_then(|()| self.with_context(cu_device, cu_primary_ctx, true));
+        if built.is_err() {
+            //no CudaDevice owns the retained context yet to release it. The first error is the
+            //one returned
+            let _ = unsafe { result::device::primary_ctx_release(cu_device) };
+        }
+        built
+    }
+
+    //the rest of build once there is a context and it is current. owns_ctx says whether drop
+    //releases it as the primary context, a failure here leaves releasing it to the caller.
+    //The stream and staging buffer are made last so an earlier failure has nothing to clean up.
+    fn with_context(
+        self,
+        cu_device: sys::CUdevice,
+        cu_primary_ctx: sys::CUcontext,
+        owns_ctx: bool,
+    ) -> Result<CudaDevice, result::CudaError> {
+        let max_grid_dim = (
+            result::device::get_attribute(cu_device, sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_MAX_GRID_DIM_X)? as u32,
+            result::device::get_attribute(cu_device, sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_MAX_GRID_DIM_Y)? as u32,
+            result::device::get_attribute(cu_device, sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_MAX_GRID_DIM_Z)? as u32,
+        );
+        let max_shared_mem = result::device::get_attribute(
+            cu_device,
+            sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_MAX_SHARED_MEMORY_PER_BLOCK,
+        )? as u32;
+        let max_shared_mem_optin = result::device::get_attribute(
+            cu_device,
+            sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_MAX_SHARED_MEMORY_PER_BLOCK_OPTIN,
+        )? as u32;
+        let pools_supported = result::device::get_attribute(
+            cu_device,
+            sys::CUdevice_attribute::CU_DEVICE_ATTRIBUTE_MEMORY_POOLS_SUPPORTED,
+        )? != 0;
+        //a driver without the entry points only costs the async allocator, not the device
+        let async_alloc = if pools_supported {
+            result::async_alloc::AsyncAllocFns::get()
+        } else {
+            None
+        };
+        let staging = alloc_staging(self.staging_bytes)?;
+        let cu_stream = match result::stream::create(self.stream_flags) {
+            Ok(cu_stream) => cu_stream,
+            Err(e) => {
+                if let Some(staging) = staging {
+                    let staging = staging.into_inner().unwrap_or_else(|e| e.into_inner());
+                    //the first error is the one returned
+                    let _ = unsafe { result::free_host(staging.ptr) };
+                }
+                return Err(e);
+            }
+        };
+        Ok(CudaDevice{
+            ordinal: self.ordinal,
+            cu_device,
+            cu_primary_ctx,
+            owns_ctx,
+            cu_stream,
+            loaded_modules: HashMap::new(),
+            ptx_cache: Mutex::new(HashMap::new()),
+            max_grid_dim,
+            max_shared_mem,
+            max_shared_mem_optin,
+            stream_flags: self.stream_flags,
+            async_alloc,
+            generation: 0,
+            tracking: AtomicBool::new(false),
+            current_bytes: AtomicUsize::new(0),
+            peak_bytes: AtomicUsize::new(0),
+            freelist_enabled: AtomicBool::new(false),
+            freelist: Mutex::new(HashMap::new()),
+            deferred_frees: Mutex::new(Vec::new()),
+            has_deferred: AtomicBool::new(false),
+            eager_error_check: self.eager_error_check,
+            staging,
+            diagnostics: None,
+        })
+    }
+}
+
+//the device pointer of every 0 byte buffer, malloc_on never asks the driver for those
+const ZST_DEVICE_PTR: sys::CUdeviceptr = 0;
+
+fn alloc_staging(num_bytes: usize) -> Result<Option<Mutex<HostStaging>>, result::CudaError> {
+    if num_bytes == 0 {
+        return Ok(None);
+    }
+    let ptr = unsafe { result::malloc_host(num_bytes) }?;
+    Ok(Some(Mutex::new(HostStaging { ptr, len: num_bytes })))
+}
+
+impl LaunchConfig {
+    //1d launch with one thread per element
+    pub fn for_num_elems(n: u32) -> Self {
+        const NUM_THREADS: u32 = 1024;
+        Self {
+            grid_dim: (n.div_ceil(NUM_THREADS), 1, 1),
+            block_dim: (NUM_THREADS, 1, 1),
+            shared_mem_bytes: 0,
+        }
+    }
+}
+
+impl Drop for CudaStream<'_> {
+    fn drop(&mut self) {
+        let stream = std::mem::replace(&mut self.cu_stream, std::ptr::null_mut());
+        if !stream.is_null() {
+            check_in_drop(unsafe { result::stream::destroy(stream) });
+        }
+    }
+}
+
+impl CudaStream<'_> {
+    pub fn synchronize(&self) -> Result<(), result::CudaError> {
+        unsafe { result::stream::synchronize(self.cu_stream) }
+    }
+
+    //raw CUstream_flags bits the stream was created with
+    pub fn flags(&self) -> Result<u32, result::CudaError> {
+        unsafe { result::stream::get_flags(self.cu_stream) }
+    }
+
+    pub fn priority(&self) -> Result<i32, result::CudaError> {
+        unsafe { result::stream::get_priority(self.cu_stream) }
+    }
+
+    //later work on this stream waits for event
+    pub fn wait(&self, event: &CudaEvent) -> Result<(), result::CudaError> {
+        unsafe { result::stream::wait_event(self.cu_stream, event.cu_event) }
+    }
+
+    //ties mem to this stream with CU_MEM_ATTACH_SINGLE, once the work already queued here is done.
+    //Afterwards only this stream's kernels count as users of it: the driver no longer migrates it
+    //for kernels on other streams, and on devices without CONCURRENT_MANAGED_ACCESS the host may
+    //use it while other streams run as long as this one is idle. Kernels on other streams must
+    //not touch it then. SINGLE means single device too, it's for one GPU working on the buffer.
+    pub fn attach<T: DeviceRepr>(&self, mem: &ManagedMemory<T>) -> Result<(), result::CudaError> {
+        let flags = sys::CUmemAttach_flags::CU_MEM_ATTACH_SINGLE;
+        unsafe { result::stream::attach_mem_async(self.cu_stream, mem.device_ptr(), 0, flags) }
+    }
+
+    //asks L2 to keep mem resident for the kernels launched on this stream from now on (Ampere and
+    //newer, 8.0): hit_ratio (0..=1) of it is persisting, the rest is treated as streaming. It only
+    //helps once some L2 is set aside for persisting lines with
+    //result::ctx::set_limit(CU_LIMIT_PERSISTING_L2_CACHE_SIZE, ..), and mem can't be bigger than
+    //CU_DEVICE_ATTRIBUTE_MAX_ACCESS_POLICY_WINDOW_SIZE. CudaDevice::reset_persisting_l2_cache
+    //flushes the lines out again. device is the one the stream belongs to.
+    pub fn set_l2_persisting<'a, T: ?Sized + 'a>(
+        &self,
+        device: &CudaDevice,
+        mem: impl Into<DeviceView<'a, T>>,
+        hit_ratio: f32,
+    ) -> Result<(), result::CudaError> {
+        let actual = device.compute_capability()?;
+        if actual < (8, 0) {
+            return Err(result::CudaError::ComputeCapabilityTooLow { required: (8, 0), actual });
+        }
+        let mem = mem.into();
+        device.check_generation(mem.generation)?;
+        unsafe { result::stream::set_access_policy_window(self.cu_stream, mem.cu_device_ptr, mem.len_bytes, hit_ratio) }
+    }
+}
+
+impl Drop for CudaEvent<'_> {
+    fn drop(&mut self) {
+        let event = std::mem::replace(&mut self.cu_event, std::ptr::null_mut());
+        if !event.is_null() {
+            check_in_drop(unsafe { result::event::destroy(event) });
+        }
+    }
+}
+
+impl CudaEvent<'_> {
+    //captures the work enqueued on stream so far. An event can be recorded any number of times,
+    //each record replaces the last one (synchronize/elapsed_ms_since see the latest), so there is
+    //nothing to reset. Hot loops should make their events once and re-record them:
+    //    let (start, end) = (dev.new_event(CU_EVENT_DEFAULT)?, dev.new_event(CU_EVENT_DEFAULT)?);
+    //    let mut total_ms = 0.0;
+    //    for _ in 0..n {
+    //        start.record(&stream)?;
+    //        step(&stream)?;
+    //        end.record(&stream)?;
+    //        end.synchronize()?;
+    //        total_ms += end.elapsed_ms_since(&start)?;
+    //    }
+    pub fn record(&self, stream: &CudaStream) -> Result<(), result::CudaError> {
+        unsafe { result::event::record(self.cu_event, stream.cu_stream) }
+    }
+
+    //record, on the device stream instead of a CudaStream
+    pub fn record_on_device(&self, device: &CudaDevice) -> Result<(), result::CudaError> {
+        unsafe { result::event::record(self.cu_event, device.cu_stream) }
+    }
+
+    pub fn synchronize(&self) -> Result<(), result::CudaError> {
+        unsafe { result::event::synchronize(self.cu_event) }
+    }
+
+    //time from start to self, both recorded and completed and neither made with DISABLE_TIMING
+    pub fn elapsed_ms_since(&self, start: &CudaEvent) -> Result<f32, result::CudaError> {
+        unsafe { result::event::elapsed_ms(start.cu_event, self.cu_event) }
+    }
+}
+
+//the byte range of the next T at or after offset, RangeOutOfBounds if it runs past len_bytes
+fn cursor_range<T>(offset: usize, len_bytes: usize) -> Result<Range<usize>, result::CudaError> {
+    let start = offset.next_multiple_of(std::mem::align_of::<T>());
+    let end = start.saturating_add(size_of::<T>());
+    if end > len_bytes {
+        return Err(result::CudaError::RangeOutOfBounds { start, end, len: len_bytes });
+    }
+    Ok(start..end)
+}
+
+impl DeviceWriter<'_> {
+    //copies value to the next aligned offset and moves past it. Finished when this returns, in
+    //order with the work on the device stream. A value that doesn't fit is RangeOutOfBounds (in
+    //bytes) and nothing is written.
+    pub fn write<T: DeviceRepr>(&mut self, value: &T) -> Result<(), result::CudaError> {
+        let range = cursor_range::<T>(self.offset, self.len_bytes)?;
+        let dst = self.cu_device_ptr + range.start as sys::CUdeviceptr;
+        unsafe { result::memcpy_htod_async_raw(dst, value as *const T as *const c_void, size_of::<T>(), self.device.cu_stream) }?;
+        self.device.synchronize()?;
+        self.offset = range.end;
+        Ok(())
+    }
+
+    //bytes from the start of the buffer to the end of the last write
+    pub fn position(&self) -> usize {
+        self.offset
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.len_bytes
+    }
+}
+
+impl DeviceReader<'_> {
+    //reads the next value the way DeviceWriter::write laid it out
+    pub fn read<T: DeviceRepr>(&mut self) -> Result<T, result::CudaError> {
+        let range = cursor_range::<T>(self.offset, self.len_bytes)?;
+        let src = self.cu_device_ptr + range.start as sys::CUdeviceptr;
+        let mut value = std::mem::MaybeUninit::<T>::uninit();
+        unsafe {
+            self.device.download_raw(value.as_mut_ptr() as *mut c_void, src, size_of::<T>())?;
+        }
+        self.offset = range.end;
+        //any bytes are a valid DeviceRepr
+        Ok(unsafe { value.assume_init() })
+    }
+
+    pub fn position(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<'device> EventPool<'device> {
+    //the next event in turn, re-recording it replaces whatever it captured last time
+    pub fn acquire(&self) -> &CudaEvent<'device> {
+        let i = self.next.get();
+        self.next.set((i + 1) % self.events.len());
+        &self.events[i]
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
+
+impl<'a> SynchronizeFuture<'a> {
+    fn enqueue(&mut self) -> Result<Arc<Mutex<SyncState>>, result::CudaError> {
+        let state = Arc::new(Mutex::new(SyncState::default()));
+        let signal = Arc::clone(&state);
+        //poll may run on any executor thread
+        self.device.bind_to_thread()?;
+        let event = self.device.new_event(result::event::CUevent_flags::CU_EVENT_DISABLE_TIMING)?;
+        self.device.launch_host_func(move || {
+            let mut state = signal.lock().unwrap_or_else(|e| e.into_inner());
+            state.done = true;
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        })?;
+        event.record_on_device(self.device)?;
+        watch(Watched {
+            cu_ctx: self.device.cu_primary_ctx,
+            cu_event: event.cu_event,
+            state: Arc::clone(&state),
+        });
+        self.event = Some(event);
+        Ok(state)
+    }
+}
+
+fn watch(watched: Watched) {
+    WATCHER.call_once(|| {
+        std::thread::Builder::new()
+            .name("cudas-sync-watcher".into())
+            .spawn(watcher)
+            .expect("couldn't start the synchronize_async watcher thread");
+    });
+    WATCHED.lock().unwrap_or_else(|e| e.into_inner()).push(watched);
+    WATCHED_ADDED.notify_one();
+}
+
+fn watcher() {
+    let mut watched = WATCHED.lock().unwrap_or_else(|e| e.into_inner());
+    loop {
+        if watched.is_empty() {
+            watched = WATCHED_ADDED.wait(watched).unwrap_or_else(|e| e.into_inner());
+            continue;
+        }
+        //woken with the lock released, a waker that polls or drops its future right away would
+        //otherwise deadlock on WATCHED
+        let mut wakers = Vec::new();
+        watched.retain(|w| !w.check(&mut wakers));
+        if !wakers.is_empty() {
+            drop(watched);
+            wakers.into_iter().for_each(Waker::wake);
+            watched = WATCHED.lock().unwrap_or_else(|e| e.into_inner());
+            continue;
+        }
+        watched = WATCHED_ADDED
+            .wait_timeout(watched, WATCH_INTERVAL)
+            .unwrap_or_else(|e| e.into_inner())
+            .0;
+    }
+}
+
+impl Watched {
+    //true once the future has its answer, then it stops being watched and its waker goes in wakers
+    fn check(&self, wakers: &mut Vec<Waker>) -> bool {
+        if self.state.lock().unwrap_or_else(|e| e.into_inner()).done {
+            return true;
+        }
+        let res = unsafe { result::ctx::set_current(self.cu_ctx) }.and_then(|()| unsafe { result::event::query(self.cu_event) });
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        match res {
+            Ok(false) => return false,
+            Ok(true) => state.done = true,
+            Err(e) => state.error = Some(e),
+        }
+        wakers.extend(state.waker.take());
+        true
+    }
+}
+
+impl Drop for SynchronizeFuture<'_> {
+    //out of WATCHED before the event field is destroyed. The watcher holds the lock while it
+    //queries, so it can't be using the event right now
+    fn drop(&mut self) {
+        if let Some(state) = &self.state {
+            WATCHED
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .retain(|w| !Arc::ptr_eq(&w.state, state));
+        }
+    }
+}
+
+impl Future for SynchronizeFuture<'_> {
+    type Output = Result<(), result::CudaError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let state = match &self.state {
+            Some(state) => Arc::clone(state),
+            None => match self.enqueue() {
+                Ok(state) => {
+                    self.state = Some(Arc::clone(&state));
+                    state
+                }
+                Err(e) => return Poll::Ready(Err(e)),
+            },
+        };
+        let done = {
+            let mut guard = state.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(e) = guard.error {
+                return Poll::Ready(Err(e));
+            }
+            if !guard.done {
+                guard.waker = Some(cx.waker().clone());
+            }
+            guard.done
+        };
+        //the host function doesn't see errors, the stream does, and after one the host function
+        //never runs. Not ready just means work is still (or, once done, again) queued.
+        let res = self
+            .device
+            .bind_to_thread()
+            .and_then(|()| unsafe { result::stream::query(self.device.cu_stream) });
+        match res {
+            Err(e) => Poll::Ready(Err(e)),
+            Ok(_) if done => Poll::Ready(Ok(())),
+            Ok(_) => Poll::Pending,
+        }
+    }
+}
+
+impl<T: DeviceRepr> DownloadChunks<'_, T> {
+    fn download(&mut self, len: usize) -> Result<Vec<T>, result::CudaError> {
+        self.device.check_generation(self.mem.generation)?;
+        if self.pinned.is_null() {
+            self.pinned = unsafe { result::malloc_host(checked_bytes::<T>(self.chunk_elems)?) }?;
+        }
+        let src = self.mem.cu_device_ptr + (self.offset * size_of::<T>()) as sys::CUdeviceptr;
+        let mut host = Vec::with_capacity(len);
+        unsafe {
+            result::memcpy_dtoh_async_raw(self.pinned, src, len * size_of::<T>(), self.device.cu_stream)?;
+            self.device.synchronize()?;
+            std::ptr::copy_nonoverlapping(self.pinned as *const T, host.as_mut_ptr(), len);
+            host.set_len(len);
+        }
+        Ok(host)
+    }
+}
+
+impl<T: DeviceRepr> Iterator for DownloadChunks<'_, T> {
+    type Item = Result<Vec<T>, result::CudaError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let total = self.mem.len();
+        if self.offset >= total {
+            return None;
+        }
+        let len = self.chunk_elems.min(total - self.offset);
+        match self.download(len) {
+            Ok(chunk) => {
+                self.offset += len;
+                Some(Ok(chunk))
+            }
+            Err(e) => {
+                self.offset = total;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl<T> Drop for DownloadChunks<'_, T> {
+    //download waits for every copy it starts, so nothing can still be writing to pinned
+    fn drop(&mut self) {
+        let pinned = std::mem::replace(&mut self.pinned, std::ptr::null_mut());
+        if !pinned.is_null() {
+            check_in_drop(unsafe { result::free_host(pinned) });
+        }
+    }
+}
+
+impl<'device, T: DeviceRepr> DoubleBuffer<'device, T> {
+    //uploads host_data into the next buffer and runs compute on it. compute has to enqueue its
+    //work on the device stream (CudaDevice::launch) and not wait for it, otherwise nothing overlaps.
+    //host_data is only borrowed, so this waits for its upload before returning and it can be
+    //reused right away. That wait still overlaps with the previous submit's compute, compute on
+    //this buffer is only enqueued, not waited for.
+    pub fn submit(
+        &mut self,
+        host_data: &[T],
+        compute: impl Fn(&InCudaMemory<'device, [T]>),
+    ) -> Result<(), result::CudaError> {
+        let i = self.next;
+        let buffer = &self.buffers[i];
+        if host_data.len() != buffer.len() {
+            return Err(result::CudaError::LengthMismatch {
+                expected: buffer.len(),
+                actual: host_data.len(),
+            });
+        }
+        let stream = &self.streams[i];
+        stream.wait(&self.computed[i])?;
+        debug_warn_pageable(host_data.as_ptr() as *const c_void);
+        unsafe {
+            result::memcpy_htod_async_raw(buffer.cu_device_ptr, host_data.as_ptr() as *const _, buffer.len_bytes, stream.cu_stream)
+        }?;
+        self.uploaded[i].record(stream)?;
+        unsafe { result::stream::wait_event(self.device.cu_stream, self.uploaded[i].cu_event) }?;
+        compute(buffer);
+        unsafe { result::event::record(self.computed[i].cu_event, self.device.cu_stream) }?;
+        self.next = 1 - i;
+        //page locked host_data is read by the copy engine until the upload finishes
+        self.uploaded[i].synchronize()
+    }
+
+    //waits for every submitted upload and compute
+    pub fn synchronize(&self) -> Result<(), result::CudaError> {
+        self.device.synchronize()
+    }
+}
+
+impl<T: DeviceRepr> ManagedMemory<'_, T> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn len_bytes(&self) -> usize {
+        self.len * size_of::<T>()
+    }
+
+    //pass this to kernels
+    pub fn device_ptr(&self) -> sys::CUdeviceptr {
+        self.cu_device_ptr
+    }
+
+    //the memory on the host side. Waits for all work in the context first: on most devices the
+    //host touching managed memory while any kernel runs is a segfault, not just a race.
+    pub fn host_slice(&mut self) -> Result<&mut [T], result::CudaError> {
+        self.device.bind_to_thread()?;
+        result::ctx::synchronize()?;
+        Ok(unsafe { std::slice::from_raw_parts_mut(self.cu_device_ptr as *mut T, self.len) })
+    }
+
+    //migration hint for the whole buffer. device is a CudaDevice::device_handle() or
+    //result::CU_DEVICE_CPU for the host. SET_READ_MOSTLY lets every processor keep a read only
+    //copy, SET_PREFERRED_LOCATION keeps pages on device, SET_ACCESSED_BY maps them into device's
+    //page tables so it never faults. The last two only do something useful on devices with
+    //CU_DEVICE_ATTRIBUTE_CONCURRENT_MANAGED_ACCESS (pascal+ on linux), elsewhere managed memory
+    //lives on the device during launches anyway. All of it relies on unified addressing, which
+    //every device that supports managed memory has.
+    pub fn advise(&self, device: sys::CUdevice, advice: sys::CUmem_advise) -> Result<(), result::CudaError> {
+        unsafe { result::mem_advise(self.cu_device_ptr, self.len_bytes(), advice, device) }
+    }
+}
+
+impl<T> Pitched2D<'_, T> {
+    pub fn device_ptr(&self) -> sys::CUdeviceptr {
+        self.cu_device_ptr
+    }
+
+    //bytes from the start of one row to the start of the next, >= width * size_of::<T>()
+    pub fn pitch(&self) -> usize {
+        self.pitch
+    }
+
+    //elements per row
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    //sets every byte of the width * height elements to byte, leaving the row padding as it is.
+    //A flat memset of pitch * height would also write the padding. Queued on the device stream.
+    pub fn memset(&self, device: &CudaDevice, byte: u8) -> Result<(), result::CudaError> {
+        device.check_generation(self.generation)?;
+        let width_bytes = self.width * size_of::<T>();
+        unsafe {
+            result::memset_2d_d8_async(self.cu_device_ptr, self.pitch, byte, width_bytes, self.height, device.cu_stream)
+        }
+    }
+}
+
+impl<T> Drop for ManagedMemory<'_, T> {
+    fn drop(&mut self) {
+        self.device.free_in_drop(DeferredFree {
+            dptr: self.cu_device_ptr,
+            num_bytes: self.len * size_of::<T>(),
+            managed: true,
+        });
+    }
+}
+
+impl<T> Drop for DoubleBuffer<'_, T> {
+    //the buffers may still be read by queued compute, free_on orders the frees behind it
+    fn drop(&mut self) {
+        for buffer in &self.buffers {
+            self.device.free_in_drop(DeferredFree {
+                dptr: buffer.cu_device_ptr,
+                num_bytes: buffer.len_bytes,
+                managed: false,
+            });
+        }
+    }
+}
+
+impl<'a> StreamScoped<'a> {
+    /// # Safety
+    /// T must be valid when all zeroes
+    pub unsafe fn alloc<T>(&self) -> Result<InCudaMemory<'a, T>, result::AllocError> {
+        Ok(self.device.alloc_on(self.stream.cu_stream)?)
+    }
+
+    pub fn take<T>(&self, host_data: Box<T>) -> Result<InCudaMemory<'a, T>, result::CudaError> {
+        self.device.take_on(host_data, self.stream.cu_stream)
+    }
+
+    pub fn release<T>(&self, t: InCudaMemory<T>) -> Result<Box<T>, result::CudaError> {
+        self.device.release_on(t, self.stream.cu_stream)
+    }
+
+    /// # Safety
+    /// same as CudaDevice::launch
+    pub unsafe fn launch(
+        &self,
+        func: &CudaFunction,
+        cfg: LaunchConfig,
+        params: &mut [*mut c_void],
+    ) -> Result<(), result::LaunchError> {
+        Ok(self.device.launch_on(func, cfg, params, self.stream.cu_stream)?)
+    }
+
+    pub fn synchronize(&self) -> Result<(), result::CudaError> {
+        self.stream.synchronize()
+    }
+}
+
+impl CapturedPipeline<'_> {
+    //enqueues one replay on the device stream, doesn't wait for it
+    pub fn run(&self) -> Result<(), result::CudaError> {
+        unsafe { result::graph::launch(self.cu_graph_exec, self.device.cu_stream) }
+    }
+
+    //count replays back to back, each starts after the previous one finished
+    pub fn run_n(&self, count: usize) -> Result<(), result::CudaError> {
+        for _ in 0..count {
+            self.run()?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> GraphBuilder<'a> {
+    //the raw handles, or ForeignGraphNode if one of them was made by another builder
+    fn dep_nodes(&self, deps: &[NodeHandle]) -> Result<Vec<sys::CUgraphNode>, result::CudaError> {
+        deps.iter()
+            .map(|dep| {
+                if dep.builder_id != self.id {
+                    return Err(result::CudaError::ForeignGraphNode);
+                }
+                Ok(dep.cu_node)
+            })
+            .collect()
+    }
+
+    fn node(&self, cu_node: sys::CUgraphNode) -> NodeHandle {
+        NodeHandle {
+            builder_id: self.id,
+            cu_node,
+        }
+    }
+
+    //unsafe for the same reasons as CudaDevice::launch, and the device pointers in params have to
+    //stay valid for as long as the instantiated graph can run, nothing borrows them here. The
+    //argument values are copied, params can go away after this returns. Same grid, shared memory
+    //and argument count checks as launch.
+    /// # Safety
+    /// same as CudaDevice::launch, and the device pointers in params must stay valid while the graph can run
+    pub unsafe fn add_kernel_node(
+        &mut self,
+        func: &CudaFunction,
+        cfg: LaunchConfig,
+        params: &mut [*mut c_void],
+        deps: &[NodeHandle],
+    ) -> Result<NodeHandle, result::CudaError> {
+        self.device.check_grid_dim(cfg.grid_dim)?;
+        self.device.check_shared_mem(func, cfg.shared_mem_bytes)?;
+        func.check_param_count(params.len())?;
+        let deps = self.dep_nodes(deps)?;
+        let node_params = sys::CUDA_KERNEL_NODE_PARAMS {
+            func: func.cu_function,
+            gridDimX: cfg.grid_dim.0,
+            gridDimY: cfg.grid_dim.1,
+            gridDimZ: cfg.grid_dim.2,
+            blockDimX: cfg.block_dim.0,
+            blockDimY: cfg.block_dim.1,
+            blockDimZ: cfg.block_dim.2,
+            sharedMemBytes: cfg.shared_mem_bytes,
+            kernelParams: params.as_mut_ptr(),
+            extra: std::ptr::null_mut(),
+            kern: std::ptr::null_mut(),
+            ctx: self.device.cu_primary_ctx,
+        };
+        let cu_node = result::graph::add_kernel_node(self.cu_graph, &deps, &node_params)?;
+        Ok(self.node(cu_node))
+    }
+
+    //device to device copy of src into dst, which must be the same length
+    pub fn add_memcpy_node<T>(
+        &mut self,
+        dst: &'a InCudaMemory<'_, [T]>,
+        src: &'a InCudaMemory<'_, [T]>,
+        deps: &[NodeHandle],
+    ) -> Result<NodeHandle, result::CudaError> {
+        self.device.check_generation(dst.generation)?;
+        self.device.check_generation(src.generation)?;
+        if dst.len() != src.len() {
+            return Err(result::CudaError::LengthMismatch {
+                expected: dst.len(),
+                actual: src.len(),
+            });
+        }
+        let deps = self.dep_nodes(deps)?;
+        let device_mem = sys::CUmemorytype::CU_MEMORYTYPE_DEVICE;
+        let copy = sys::CUDA_MEMCPY3D {
+            srcXInBytes: 0,
+            srcY: 0,
+            srcZ: 0,
+            srcLOD: 0,
+            srcMemoryType: device_mem,
+            srcHost: std::ptr::null(),
+            srcDevice: src.cu_device_ptr,
+            srcArray: std::ptr::null_mut(),
+            reserved0: std::ptr::null_mut(),
+            srcPitch: 0,
+            srcHeight: 0,
+            dstXInBytes: 0,
+            dstY: 0,
+            dstZ: 0,
+            dstLOD: 0,
+            dstMemoryType: device_mem,
+            dstHost: std::ptr::null_mut(),
+            dstDevice: dst.cu_device_ptr,
+            dstArray: std::ptr::null_mut(),
+            reserved1: std::ptr::null_mut(),
+            dstPitch: 0,
+            dstHeight: 0,
+            WidthInBytes: src.len_bytes,
+            Height: 1,
+            Depth: 1,
+        };
+        let cu_node = unsafe { result::graph::add_memcpy_node(self.cu_graph, &deps, &copy, self.device.cu_primary_ctx) }?;
+        Ok(self.node(cu_node))
+    }
+
+    //sets every byte of dst to value
+    pub fn add_memset_node<T: ?Sized>(
+        &mut self,
+        dst: &'a InCudaMemory<'_, T>,
+        value: u8,
+        deps: &[NodeHandle],
+    ) -> Result<NodeHandle, result::CudaError> {
+        self.device.check_generation(dst.generation)?;
+        let deps = self.dep_nodes(deps)?;
+        let memset = sys::CUDA_MEMSET_NODE_PARAMS {
+            dst: dst.cu_device_ptr,
+            pitch: dst.len_bytes,
+            value: value as u32,
+            elementSize: 1,
+            width: dst.len_bytes,
+            height: 1,
+        };
+        let cu_node = unsafe { result::graph::add_memset_node(self.cu_graph, &deps, &memset, self.device.cu_primary_ctx) }?;
+        Ok(self.node(cu_node))
+    }
+
+    pub fn instantiate(mut self) -> Result<CapturedPipeline<'a>, result::CudaError> {
+        let cu_graph_exec = unsafe { result::graph::instantiate(self.cu_graph) }?;
+        //the pipeline owns the graph from here, our Drop must not destroy it
+        let cu_graph = std::mem::replace(&mut self.cu_graph, std::ptr::null_mut());
+        Ok(CapturedPipeline {
+            device: self.device,
+            cu_graph,
+            cu_graph_exec,
+        })
+    }
+}
+
+impl Drop for GraphBuilder<'_> {
+    fn drop(&mut self) {
+        let graph = std::mem::replace(&mut self.cu_graph, std::ptr::null_mut());
+        if !graph.is_null() {
+            check_in_drop(unsafe { result::graph::destroy(graph) });
+        }
+    }
+}
+
+impl Drop for CapturedPipeline<'_> {
+    //replays still in flight are fine, the driver frees the exec once they're done
+    fn drop(&mut self) {
+        let exec = std::mem::replace(&mut self.cu_graph_exec, std::ptr::null_mut());
+        if !exec.is_null() {
+            check_in_drop(unsafe { result::graph::exec_destroy(exec) });
+        }
+        let graph = std::mem::replace(&mut self.cu_graph, std::ptr::null_mut());
+        if !graph.is_null() {
+            check_in_drop(unsafe { result::graph::destroy(graph) });
+        }
+    }
+}
+
+impl Drop for ProfilerScope<'_> {
+    //cuProfilerStop acts on the current context, which may have changed since the scope started
+    fn drop(&mut self) {
+        check_in_drop(self.device.bind_to_thread());
+        check_in_drop(result::profiler::stop());
+    }
+}
+
+impl CudaFunction {
+    //number of arguments the kernel takes, known for kernels loaded from PTX text
+    pub fn param_count(&self) -> Option<usize> {
+        self.param_count
+    }
+
+    //offset and size of every parameter as the driver sees them, for any kernel (cubins too, not
+    //only PTX). Needs a 12.3 driver, older ones give CUDA_ERROR_NOT_SUPPORTED.
+    pub fn param_info(&self) -> Result<Vec<ParamInfo>, result::CudaError> {
+        self.param_info_cached().clone()
+    }
+
+    fn param_info_cached(&self) -> &Result<Vec<ParamInfo>, result::CudaError> {
+        self.param_info.get_or_init(|| {
+            let params = unsafe { result::function::param_info(self.cu_function) }?;
+            Ok(params.into_iter().map(|(offset, size)| ParamInfo { offset, size }).collect())
+        })
+    }
+
+    //launch_args' check: count and size of every argument against param_info. Drivers without
+    //param_info skip it, check_param_count still covers the count for PTX kernels.
+    fn check_param_sizes(&self, sizes: &[usize]) -> Result<(), result::CudaError> {
+        let Ok(params) = self.param_info_cached() else {
+            return Ok(());
+        };
+        if params.len() != sizes.len() {
+            return Err(result::CudaError::ParamCountMismatch {
+                expected: params.len(),
+                actual: sizes.len(),
+            });
+        }
+        for (index, (param, &actual)) in params.iter().zip(sizes).enumerate() {
+            if param.size != actual {
+                return Err(result::CudaError::ParamSizeMismatch { index, expected: param.size, actual });
+            }
+        }
+        Ok(())
+    }
+
+    fn check_param_count(&self, actual: usize) -> Result<(), result::CudaError> {
+        match self.param_count {
+            Some(expected) if expected != actual => {
+                Err(result::CudaError::ParamCountMismatch { expected, actual })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn get_attribute(&self, attrib: result::function::CUfunction_attribute) -> Result<i32, result::CudaError> {
+        unsafe { result::function::get_attribute(self.cu_function, attrib) }
+    }
+
+    //e.g. CU_FUNC_ATTRIBUTE_MAX_DYNAMIC_SHARED_SIZE_BYTES to launch with more than 48KB of shared memory
+    pub fn set_attribute(&self, attrib: result::function::CUfunction_attribute, value: i32) -> Result<(), result::CudaError> {
+        unsafe { result::function::set_attribute(self.cu_function, attrib, value) }
+    }
+}
+
+impl CudaModule {
+    pub fn load_fn(&mut self, name: &'static str) -> Result<(), result::CudaError> {
+        let function = self.resolve_fn(name)?;
+        self.functions.insert(name, function);
+        Ok(())
+    }
+
+    fn resolve_fn(&self, name: &'static str) -> Result<CudaFunction, result::CudaError> {
+        let cu_function = unsafe { result::module::get_function(self.cu_module, name) }?;
+        let param_count = self.ptx.as_deref().and_then(|ptx| ptx_param_count(ptx, name));
+        Ok(CudaFunction {
+            cu_function,
+            param_count,
+            param_info: OnceLock::new(),
+        })
+    }
+
+    pub fn get_fn(&self, name: &str) -> Option<&CudaFunction> {
+        if let Some(function) = self.functions.get(name) {
+            return Some(function);
+        }
+        let lazy = self.lazy_functions.lock().unwrap_or_else(|e| e.into_inner());
+        let function: &CudaFunction = lazy.get(name)?;
+        //boxed and never removed before the module drops, see lazy_functions
+        Some(unsafe { &*(function as *const CudaFunction) })
+    }
+
+    //get_fn for modules from load_ptx_lazy, resolves name on first use and caches it. Takes &self,
+    //the cache is behind a lock, so several threads can resolve from one shared device
+    pub fn get_or_load_fn(&self, name: &'static str) -> Result<&CudaFunction, result::CudaError> {
+        if let Some(function) = self.functions.get(name) {
+            return Ok(function);
+        }
+        let mut lazy = self.lazy_functions.lock().unwrap_or_else(|e| e.into_inner());
+        if !lazy.contains_key(name) {
+            lazy.insert(name, Box::new(self.resolve_fn(name)?));
+        }
+        let function: &CudaFunction = &lazy[name];
+        //boxed and never removed before the module drops, see lazy_functions
+        Ok(unsafe { &*(function as *const CudaFunction) })
+    }
+
+    pub fn get_global(&self, name: &str) -> Result<(sys::CUdeviceptr, usize), result::CudaError> {
+        unsafe { result::module::get_global(self.cu_module, name) }
+    }
+
+    //sets a batch of __constant__/__device__ globals, each given as (symbol name, raw bytes).
+    //every symbol is resolved and size checked before anything is copied, so a LengthMismatch
+    //(expected = symbol size in bytes) leaves all of them untouched. The copies go out
+    //back to back on the device stream and are waited on once at the end.
+    pub fn copy_all_to_symbols(
+        &self,
+        device: &CudaDevice,
+        pairs: &[(&str, &[u8])],
+    ) -> Result<(), result::CudaError> {
+        let mut targets = Vec::with_capacity(pairs.len());
+        for &(name, bytes) in pairs {
+            let (dptr, size) = self.get_global(name)?;
+            if size != bytes.len() {
+                return Err(result::CudaError::LengthMismatch {
+                    expected: size,
+                    actual: bytes.len(),
+                });
+            }
+            targets.push((dptr, bytes));
+        }
+        for (dptr, bytes) in targets {
+            unsafe {
+                result::memcpy_htod_async_raw(dptr, bytes.as_ptr() as *const _, bytes.len(), device.cu_stream)
+            }?;
+        }
+        device.synchronize()
+    }
+
+    pub fn ptx(&self) -> Option<&str> {
+        self.ptx.as_deref()
+    }
+
+    //the `.entry` block of one kernel, from its declaration line to the closing brace of its body.
+    //Only what went into the JIT is available: the driver api has no way to read back the SASS it
+    //generated, for that dump the cubin with `cuobjdump -sass`.
+    pub fn get_function_ptx(&self, name: &str) -> Option<&str> {
+        ptx_entry(self.ptx.as_deref()?, name)
+    }
+}
+
+fn ptx_entry<'a>(ptx: &'a str, name: &str) -> Option<&'a str> {
+    let pattern = format!(".entry {}", name);
+    let mut search_from = 0;
+    let decl = loop {
+        let idx = search_from + ptx[search_from..].find(&pattern)?;
+        let after = ptx[idx + pattern.len()..].chars().next();
+        //don't match `.entry foo` when looking for `.entry fo`
+        if matches!(after, Some(c) if c == '(' || c.is_whitespace()) {
+            break idx;
+        }
+        search_from = idx + pattern.len();
+    };
+    let start = ptx[..decl].rfind('\n').map_or(0, |i| i + 1);
+    let body = decl + ptx[decl..].find('{')?;
+    let mut depth = 0;
+    for (i, c) in ptx[body..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&ptx[start..body + i + 1]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+//counts the .param declarations between the parens of `.entry name(...)`, no parens means none
+fn ptx_param_count(ptx: &str, name: &str) -> Option<usize> {
+    let entry = ptx_entry(ptx, name)?;
+    let signature = &entry[..entry.find('{')?];
+    let Some(open) = signature.find('(') else {
+        return Some(0);
+    };
+    let close = open + signature[open..].find(')')?;
+    Some(signature[open..close].matches(".param").count())
+}
+
+impl<'device, T: ?Sized> InCudaMemory<'device, T> {
+    pub fn len_bytes(&self) -> usize {
+        self.len_bytes
+    }
+
+    //bytes the driver actually reserved for the allocation this points into, which can be more
+    //than len_bytes after rounding up. Fails with CUDA_ERROR_NOT_FOUND if the pointer isn't in a
+    //live allocation of the current context, so it doubles as a check on computed pointers.
+    //The owning device's context has to be current (CudaDevice::bind_to_thread).
+    pub fn actual_size(&self) -> Result<usize, result::CudaError> {
+        let (_, size) = result::mem_get_address_range(self.cu_device_ptr)?;
+        Ok(size)
+    }
+
+    //a cursor for packing values of different types into this buffer one after the other, each
+    //at the next offset aligned for its type the way a C struct would lay them out. device has to
+    //be the device that owns self.
+    pub fn writer<'a>(&'a mut self, device: &'a CudaDevice) -> DeviceWriter<'a> {
+        DeviceWriter {
+            device,
+            cu_device_ptr: self.cu_device_ptr,
+            len_bytes: self.len_bytes,
+            offset: 0,
+            mem: PhantomData,
+        }
+    }
+
+    //the read side of writer, reading values back in the order (and so at the offsets) they were
+    //written
+    pub fn reader<'a>(&'a self, device: &'a CudaDevice) -> DeviceReader<'a> {
+        DeviceReader {
+            device,
+            cu_device_ptr: self.cu_device_ptr,
+            len_bytes: self.len_bytes,
+            offset: 0,
+            mem: PhantomData,
+        }
+    }
+
+    //identity, not contents: true when both are the same allocation (same device pointer, size
+    //and reset generation). Two buffers holding equal data are not ptr_eq.
+    pub fn ptr_eq<U: ?Sized>(&self, other: &InCudaMemory<'_, U>) -> bool {
+        self.cu_device_ptr == other.cu_device_ptr
+            && self.len_bytes == other.len_bytes
+            && self.generation == other.generation
+    }
+
+    pub fn view(&self) -> DeviceView<'_, T> {
+        DeviceView {
+            cu_device_ptr: self.cu_device_ptr,
+            len_bytes: self.len_bytes,
+            len: self.len,
+            generation: self.generation,
+            owner: PhantomData,
+        }
+    }
+
+    //duplicates the buffer without a round trip through the host: allocates len_bytes on the
+    //device and enqueues a device to device copy, both on the device's stream.
+    //device has to be the device that owns self. The host copy isn't cloned (T may not be Clone),
+    //releasing the clone reads it back from the gpu.
+    pub fn try_clone<'a>(&self, device: &'a CudaDevice) -> Result<InCudaMemory<'a, T>, result::CudaError> {
+        device.check_generation(self.generation)?;
+        let cu_device_ptr = device.malloc_on(self.len_bytes, device.cu_stream)?;
+        unsafe {
+            result::memcpy_dtod_async(cu_device_ptr, self.cu_device_ptr, self.len_bytes, device.cu_stream)
+        }?;
+        Ok(InCudaMemory {
+            cu_device_ptr,
+            len_bytes: self.len_bytes,
+            len: self.len,
+            generation: device.generation,
+            host_data: None,
+            owned: true,
+            device: PhantomData,
+        })
+    }
+}
+
+impl<'device, T> InCudaMemory<'device, T> {
+    //erases the element type, the device pointer and byte length stay the same.
+    //the retained host copy is dropped instead of being carried over: a Box<T> can't become a
+    //Box<[u8]> because the allocation layouts differ (align_of::<T>() vs 1) and any padding in T
+    //is uninitialized, which is not a valid u8. Reading the bytes back always goes through the device.
+    pub fn into_bytes(self) -> InCudaMemory<'device, [u8]> {
+        InCudaMemory {
+            cu_device_ptr: self.cu_device_ptr,
+            len_bytes: self.len_bytes,
+            len: self.len_bytes,
+            generation: self.generation,
+            host_data: None,
+            owned: self.owned,
+            device: PhantomData,
+        }
+    }
+}
+
+impl<'device, T> InCudaMemory<'device, [T]> {
+    //into_bytes for a slice, len_bytes is len() * size_of::<T>(). Same reason the host copy is
+    //dropped. from_bytes::<T>() gives back the slice.
+    pub fn into_bytes(self) -> InCudaMemory<'device, [u8]> {
+        InCudaMemory {
+            cu_device_ptr: self.cu_device_ptr,
+            len_bytes: self.len_bytes,
+            len: self.len_bytes,
+            generation: self.generation,
+            host_data: None,
+            owned: self.owned,
+            device: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<'device, T: DeviceRepr> InCudaMemory<'device, [T]> {
+    //the same device bytes read as U instead of T, e.g. a u32 buffer a kernel filled with f32s.
+    //No copy: pointer, element count and ownership carry over. U has to be exactly as big as T,
+    //otherwise LayoutMismatch (expected_stride = size_of::<T>()). Safe because every bit pattern
+    //is a valid DeviceRepr, whether the values mean anything as U is up to what wrote them. The
+    //retained host copy is dropped, it holds Ts and would be stale as Us. A U with stricter
+    //alignment than T is fine for memory allocated here (256 byte aligned), borrowed memory has
+    //to be aligned for U as well.
+    pub fn cast<U: DeviceRepr>(self) -> Result<InCudaMemory<'device, [U]>, result::CudaError> {
+        if size_of::<U>() != size_of::<T>() {
+            return Err(result::CudaError::LayoutMismatch {
+                expected_stride: size_of::<T>(),
+                actual_stride: size_of::<U>(),
+            });
+        }
+        Ok(InCudaMemory {
+            cu_device_ptr: self.cu_device_ptr,
+            len_bytes: self.len_bytes,
+            len: self.len,
+            generation: self.generation,
+            host_data: None,
+            owned: self.owned,
+            device: PhantomData,
+        })
+    }
+
+    //wraps len elements at ptr that some other library allocated, so the copy helpers and launch
+    //code here can use them. The result never frees ptr: release and discard just forget it, the
+    //allocating library stays responsible for freeing it.
+    //unsafe because nothing here can check the contract: ptr has to be a live allocation of at
+    //least len elements in device's context, aligned for T, and must not be freed by its owner
+    //before the wrapper is released or discarded. Breaking it means use after free on the
+    //device. A reset frees everything in the context, the other library's memory included.
+    //result::pointer_get_attributes tells whether ptr is device memory and whose, before trusting it.
+    /// # Safety
+    /// ptr must be a live allocation of at least len Ts in device's context, aligned for T, not freed before the wrapper is dropped
+    pub unsafe fn from_raw_borrowed(device: &'device CudaDevice, ptr: sys::CUdeviceptr, len: usize) -> Self {
+        InCudaMemory {
+            cu_device_ptr: ptr,
+            len_bytes: len * size_of::<T>(),
+            len,
+            generation: device.generation,
+            host_data: None,
+            owned: false,
+            device: PhantomData,
+        }
+    }
+}
+
+impl<'device> InCudaMemory<'device, [u8]> {
+    //a byte buffer of len_bytes at ptr, for DynDevice implementations other than CudaDevice: a
+    //mock can use any ptr as the id of its own storage and find it again with view().device_ptr().
+    //unsafe because a CudaDevice takes the result for memory it owns: handing it to one is only
+    //fine if ptr is a live allocation of at least len_bytes in that device's context, made since
+    //its last reset, and nothing else frees it.
+    /// # Safety
+    /// if it goes to a CudaDevice, ptr must be a live allocation of at least len_bytes it owns and nothing else frees
+    pub unsafe fn from_raw_parts(ptr: sys::CUdeviceptr, len_bytes: usize) -> Self {
+        InCudaMemory {
+            cu_device_ptr: ptr,
+            len_bytes,
+            len: len_bytes,
+            generation: 0,
+            host_data: None,
+            owned: true,
+            device: PhantomData,
+        }
+    }
+
+    //the inverse of into_bytes on a [T]. len_bytes must be a multiple of size_of::<T>() (0 is, and
+    //gives an empty slice) and T must not be zero sized, otherwise InvalidByteLength is returned.
+    //unsafe because every size_of::<T>() chunk of the device bytes must be a valid T, the same
+    //contract as alloc. Alignment is only guaranteed for the pointer the driver handed out
+    //(cuMemAlloc aligns to at least 256 bytes), so the pointer must not be an offset into another
+    //allocation unless that offset is a multiple of align_of::<T>().
+    /// # Safety
+    /// every size_of::<T>() chunk must be a valid T and the pointer aligned for T
+    pub unsafe fn from_bytes<T>(self) -> Result<InCudaMemory<'device, [T]>, result::CudaError> {
+        let elem_size = size_of::<T>();
+        if elem_size == 0 || !self.len_bytes.is_multiple_of(elem_size) {
+            return Err(result::CudaError::InvalidByteLength {
+                len_bytes: self.len_bytes,
+                elem_size,
+            });
+        }
+        Ok(InCudaMemory {
+            cu_device_ptr: self.cu_device_ptr,
+            len_bytes: self.len_bytes,
+            len: self.len_bytes / elem_size,
+            generation: self.generation,
+            host_data: None,
+            owned: self.owned,
+            device: PhantomData,
+        })
+    }
+}
+
+//hand written so logging a buffer doesn't dump the whole host copy: the device pointer in hex,
+//the sizes and whether a host copy is kept, never T's contents. Slices also show their length.
+impl<T> std::fmt::Debug for InCudaMemory<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InCudaMemory")
+            .field("device_ptr", &format_args!("{:#x}", self.cu_device_ptr))
+            .field("len_bytes", &self.len_bytes)
+            .field("generation", &self.generation)
+            .field("host_copy", &self.host_data.is_some())
+            .finish()
+    }
+}
+
+impl<T> std::fmt::Debug for InCudaMemory<'_, [T]> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InCudaMemory")
+            .field("device_ptr", &format_args!("{:#x}", self.cu_device_ptr))
+            .field("len", &self.len())
+            .field("len_bytes", &self.len_bytes)
+            .field("generation", &self.generation)
+            .field("host_copy", &self.host_data.is_some())
+            .finish()
+    }
+}
+
+//Eq and Hash are ptr_eq identity so buffers can key a memoization cache, they never look at what
+//the buffer holds
+impl<T: ?Sized> PartialEq for InCudaMemory<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other)
+    }
+}
+
+impl<T: ?Sized> Eq for InCudaMemory<'_, T> {}
+
+impl<T: ?Sized> Hash for InCudaMemory<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.cu_device_ptr.hash(state);
+        self.len_bytes.hash(state);
+        self.generation.hash(state);
+    }
+}
+
+//the one borrowed slice type: generic code can take impl Into<DeviceView<[T]>> and accept owned
+//buffers and views alike. (AsRef can't work, a view is a new value and not a field to point at.)
+impl<'a, T: ?Sized> From<&'a InCudaMemory<'_, T>> for DeviceView<'a, T> {
+    fn from(mem: &'a InCudaMemory<'_, T>) -> Self {
+        mem.view()
+    }
+}
+
+//derive would require T: Clone, a view is copyable whatever it points at
+impl<T: ?Sized> Clone for DeviceView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for DeviceView<'_, T> {}
+
+impl<T: ?Sized> DeviceView<'_, T> {
+    pub fn device_ptr(&self) -> sys::CUdeviceptr {
+        self.cu_device_ptr
+    }
+
+    pub fn len_bytes(&self) -> usize {
+        self.len_bytes
+    }
+}
+
+impl<T> DeviceView<'_, [T]> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<'device, T, const N: usize> DeviceTensor<'device, T, N> {
+    pub fn shape(&self) -> [usize; N] {
+        self.shape
+    }
+
+    pub fn numel(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    //row major, in elements: the last axis is contiguous
+    pub fn strides(&self) -> [usize; N] {
+        let mut strides = [1; N];
+        for axis in (0..N.saturating_sub(1)).rev() {
+            strides[axis] = strides[axis + 1] * self.shape[axis + 1];
+        }
+        strides
+    }
+
+    pub fn as_device_ptr(&self) -> sys::CUdeviceptr {
+        self.data.cu_device_ptr
+    }
+
+    pub fn as_slice(&self) -> &InCudaMemory<'device, [T]> {
+        &self.data
+    }
+
+    //gives back the flat buffer, e.g. to discard it
+    pub fn into_inner(self) -> InCudaMemory<'device, [T]> {
+        self.data
+    }
+}
+
+//the ones that touch the device need a CUDA gpu as device 0
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_bytes_overflow() {
+        assert_eq!(
+            checked_bytes::<u32>(usize::MAX),
+            Err(result::CudaError::SizeOverflow { count: usize::MAX, elem_size: 4 })
+        );
+        assert_eq!(checked_bytes::<u32>(3), Ok(12));
+        assert!(checked_numel::<f32, 3>(&[usize::MAX, 2, 1]).is_err());
+        assert_eq!(checked_numel::<f32, 3>(&[4, 5, 6]), Ok(120));
+    }
+
+    #[test]
+    fn ptx_with_a_nul() {
+        assert!(ptx_cstring(".version 7.0").is_ok());
+        assert_eq!(
+            ptx_cstring(".version 7.0\0.target sm_52"),
+            Err(result::CudaError::Driver(sys::CUresult::CUDA_ERROR_INVALID_PTX))
+        );
+    }
+
+    #[test]
+    fn alloc_overflow_is_an_error() {
+        let dev = CudaDevice::new(0).unwrap();
+        let err = dev.alloc_n::<f32>(usize::MAX).unwrap_err();
+        assert!(matches!(*err, result::CudaError::SizeOverflow { .. }));
+        let err = dev.alloc_tensor::<f32, 2>([usize::MAX, 2]).unwrap_err();
+        assert!(matches!(*err, result::CudaError::SizeOverflow { .. }));
+        let err = dev.take_tensor::<f32, 2>([usize::MAX, 2], vec![0.0; 4]).unwrap_err();
+        assert!(matches!(*err, result::CudaError::SizeOverflow { .. }));
+        let err = dev.alloc_pitched::<f32>(usize::MAX, 1).unwrap_err();
+        assert!(matches!(*err, result::CudaError::SizeOverflow { .. }));
+    }
+
+    #[test]
+    fn drop_right_after_launches() {
+        let mut dev = CudaDevice::new(0).unwrap();
+        dev.load_ptx("square", include_str!("../../examples/square.ptx"), &["square"]).unwrap();
+        let n = 1 << 24;
+        let inp = dev.take_slice(vec![1.5f32; n]).unwrap();
+        let out = dev.alloc_slice::<f32>(n).unwrap();
+        let square = dev.get_module("square").and_then(|m| m.get_fn("square")).unwrap();
+        let cfg = LaunchConfig::for_num_elems(n as u32);
+        for _ in 0..16 {
+            unsafe { dev.launch_args(square, cfg, (&inp, &out, n as u32)) }.unwrap();
+        }
+        //no synchronize, the kernels are still running when drop unloads the module. Drop has to
+        //wait for them instead of hanging or failing
+        drop(dev);
+    }
+
+    #[test]
+    fn bandwidth_units() {
+        assert_eq!(bandwidth_gbps(1_000_000_000, 1000.0), 1.0);
+        assert_eq!(bandwidth_gbps(500_000_000, 250.0), 2.0);
+    }
+
+    #[test]
+    fn time_uploads() {
+        let dev = CudaDevice::new(0).unwrap();
+        let host: Vec<u32> = (0..1 << 16).collect();
+        let (mem, ms) = dev.time(|| dev.take_slice(host.clone())).unwrap();
+        assert!(ms > 0.0);
+        assert!(bandwidth_gbps(host.len() * 4, ms).is_finite());
+        //time hands back what f returned, and the upload it timed really happened
+        assert_eq!(dev.copy_to_host(&mem).unwrap(), host);
+        //an error from f comes back instead of a time
+        let err = dev.time(|| dev.take_slice_checked(vec![0u32; 4], 8)).unwrap_err();
+        assert!(matches!(err, result::CudaError::LayoutMismatch { .. }));
+        dev.discard(mem).unwrap();
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Tag;
+    unsafe impl DeviceRepr for Tag {}
+
+    #[test]
+    fn zero_sized_types_skip_the_device() {
+        let dev = CudaDevice::new(0).unwrap();
+        let mem = dev.take(Box::new(PhantomData::<f32>)).unwrap();
+        assert_eq!(mem.cu_device_ptr, ZST_DEVICE_PTR);
+        let _: Box<PhantomData<f32>> = dev.release(mem).unwrap();
+        let unit = dev.take(Box::new(())).unwrap();
+        assert_eq!(unit.len_bytes, 0);
+        dev.discard(unit).unwrap();
+        let empty = dev.take_slice(Vec::<f32>::new()).unwrap();
+        assert_eq!(empty.cu_device_ptr, ZST_DEVICE_PTR);
+        assert!(dev.copy_to_host(&empty).unwrap().is_empty());
+        dev.discard(empty).unwrap();
+        //no bytes, but the element count survives
+        let tags = dev.take_slice(vec![Tag; 5]).unwrap();
+        assert_eq!(tags.cu_device_ptr, ZST_DEVICE_PTR);
+        assert_eq!(tags.len(), 5);
+        assert_eq!(tags.view().len(), 5);
+        assert!(format!("{:?}", tags).contains("len: 5"));
+        assert_eq!(dev.copy_to_host(&tags).unwrap(), vec![Tag; 5]);
+        let zeroed = dev.alloc_n::<Tag>(3).unwrap();
+        assert_eq!(dev.copy_to_host(&zeroed).unwrap(), vec![Tag; 3]);
+        dev.discard(tags).unwrap();
+        dev.discard(zeroed).unwrap();
+    }
+
+    #[test]
+    fn export_import_round_trip() {
+        let dev = CudaDevice::new(0).unwrap();
+        let data: Vec<f32> = (0..1000).map(|i| i as f32 * 0.5).collect();
+        let mem = dev.take_slice(data.clone()).unwrap();
+        let bytes = dev.export_bytes(&mem).unwrap();
+        assert_eq!(bytes.len(), 1000 * 4);
+        assert_eq!(&bytes[4..8], &0.5f32.to_le_bytes());
+        let back = dev.import_bytes::<f32>(&bytes).unwrap();
+        assert_eq!(dev.copy_to_host(&back).unwrap(), data);
+
+        //cut short, and read back as a type whose size doesn't divide the bytes
+        let err = dev.import_bytes::<f32>(&bytes[..bytes.len() - 1]).unwrap_err();
+        assert_eq!(err, result::CudaError::InvalidByteLength { len_bytes: 3999, elem_size: 4 });
+        let halves = dev.take_slice(vec![1u16, 2, 3]).unwrap();
+        let err = dev.import_bytes::<u32>(&dev.export_bytes(&halves).unwrap()).unwrap_err();
+        assert_eq!(err, result::CudaError::InvalidByteLength { len_bytes: 6, elem_size: 4 });
+
+        dev.discard(mem).unwrap();
+        dev.discard(back).unwrap();
+        dev.discard(halves).unwrap();
+    }
+
+    #[test]
+    fn nodes_only_fit_their_own_builder() {
+        let dev = CudaDevice::new(0).unwrap();
+        let buf = dev.alloc_bytes(64).unwrap();
+        let mut first = dev.graph_builder().unwrap();
+        let node = first.add_memset_node(&buf, 0, &[]).unwrap();
+        assert!(first.add_memset_node(&buf, 1, &[node]).is_ok());
+        let mut other = dev.graph_builder().unwrap();
+        assert_eq!(other.add_memset_node(&buf, 1, &[node]), Err(result::CudaError::ForeignGraphNode));
+        //the driver may hand a later graph the same address, the handle must still be refused
+        drop(first);
+        drop(other);
+        let mut later = dev.graph_builder().unwrap();
+        assert_eq!(later.add_memset_node(&buf, 1, &[node]), Err(result::CudaError::ForeignGraphNode));
+        drop(later);
+        dev.discard(buf).unwrap();
+    }
+
+    #[test]
+    fn sync_copy_waits_for_queued_kernels() {
+        let mut dev = CudaDevice::new(0).unwrap();
+        dev.load_ptx("square", include_str!("../../examples/square.ptx"), &["square"]).unwrap();
+        let n = 1 << 24;
+        let inp = dev.take_slice(vec![3.0f32; n]).unwrap();
+        let mut out = dev.alloc_slice::<f32>(n).unwrap();
+        let square = dev.get_module("square").and_then(|m| m.get_fn("square")).unwrap();
+        let cfg = LaunchConfig::for_num_elems(n as u32);
+        for _ in 0..8 {
+            unsafe { dev.launch_args(square, cfg, (&inp, &out, n as u32)) }.unwrap();
+        }
+        //the kernels write 9.0 everywhere, if the copy doesn't wait for them some of it survives
+        let src = vec![1.0f32; n];
+        dev.copy(&mut out, &src, CopyMode::Sync).unwrap();
+        assert!(dev.copy_to_host(&out).unwrap().iter().all(|&x| x == 1.0));
+        dev.discard(inp).unwrap();
+        dev.discard(out).unwrap();
+    }
+
+    //DynDevice with host vecs instead of a gpu, the point of the trait being object safe
+    #[derive(Debug, Default)]
+    struct HostDevice {
+        buffers: Mutex<HashMap<sys::CUdeviceptr, Vec<u8>>>,
+        next_id: AtomicU64,
+    }
+
+    impl DynDevice for HostDevice {
+        fn alloc_bytes(&self, n: usize) -> Result<InCudaMemory<'_, [u8]>, result::AllocError> {
+            let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+            self.buffers.lock().unwrap().insert(id, vec![0; n]);
+            Ok(unsafe { InCudaMemory::from_raw_parts(id, n) })
+        }
+
+        fn discard_bytes(&self, mem: InCudaMemory<'_, [u8]>) -> Result<(), result::CudaError> {
+            self.buffers.lock().unwrap().remove(&mem.view().device_ptr());
+            Ok(())
+        }
+
+        fn copy_bytes_to_device(&self, dst: &mut InCudaMemory<[u8]>, src: &[u8]) -> Result<(), result::CudaError> {
+            let mut buffers = self.buffers.lock().unwrap();
+            buffers.get_mut(&dst.view().device_ptr()).unwrap().copy_from_slice(src);
+            Ok(())
+        }
+
+        fn copy_bytes_to_host(&self, src: &InCudaMemory<[u8]>, dst: &mut [u8]) -> Result<(), result::CudaError> {
+            dst.copy_from_slice(&self.buffers.lock().unwrap()[&src.view().device_ptr()]);
+            Ok(())
+        }
+
+        fn synchronize(&self) -> Result<(), result::CudaError> {
+            Ok(())
+        }
+    }
+
+    fn round_trip(dev: &dyn DynDevice) {
+        let mut mem = dev.alloc_bytes(4).unwrap();
+        assert_eq!(mem.len(), 4);
+        dev.copy_bytes_to_device(&mut mem, &[1, 2, 3, 4]).unwrap();
+        dev.synchronize().unwrap();
+        let mut back = [0; 4];
+        dev.copy_bytes_to_host(&mem, &mut back).unwrap();
+        assert_eq!(back, [1, 2, 3, 4]);
+        dev.discard_bytes(mem).unwrap();
+    }
+
+    #[test]
+    fn dyn_device_mock() {
+        let mock = HostDevice::default();
+        round_trip(&mock);
+        assert!(mock.buffers.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn dyn_device_cuda() {
+        let dev: Box<dyn DynDevice> = Box::new(CudaDevice::new(0).unwrap());
+        round_trip(dev.as_ref());
+    }
+
+    #[test]
+    fn lazy_functions_resolve_on_first_use() {
+        let mut dev = CudaDevice::new(0).unwrap();
+        dev.load_ptx_lazy("square", include_str!("../../examples/square.ptx")).unwrap();
+        assert!(dev.get_module("square").unwrap().get_fn("square").is_none());
+        assert_eq!(
+            dev.get_func("square", "missing").unwrap_err(),
+            result::CudaError::Driver(sys::CUresult::CUDA_ERROR_NOT_FOUND)
+        );
+        let inp = dev.take_slice(vec![1.0f32, 2.0, 3.0]).unwrap();
+        let out = dev.alloc_slice::<f32>(3).unwrap();
+        let square = dev.get_func("square", "square").unwrap();
+        unsafe { dev.launch_args(square, LaunchConfig::for_num_elems(3), (&inp, &out, 3u32)) }.unwrap();
+        assert_eq!(dev.copy_to_host(&out).unwrap(), vec![1.0, 4.0, 9.0]);
+        //cached now, and the same function both ways
+        let again = dev.get_module("square").unwrap().get_fn("square").unwrap();
+        assert_eq!(again.cu_function, square.cu_function);
+        dev.discard(inp).unwrap();
+        dev.discard(out).unwrap();
+    }
+
+    //nvcc -ptx -arch=sm_52 of
+    //  extern "C" __global__ void axpn(float *buf, unsigned int n, float alpha) {
+    //      unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    //      if (i < n) buf[i] = alpha * buf[i] + (float)n;
+    //  }
+    const AXPN_PTX: &str = "
+.version 7.0
+.target sm_52
+.address_size 64
+
+.visible .entry axpn(
+	.param .u64 axpn_param_0,
+	.param .u32 axpn_param_1,
+	.param .f32 axpn_param_2
+)
+{
+	.reg .pred 	%p<2>;
+	.reg .f32 	%f<5>;
+	.reg .b32 	%r<6>;
+	.reg .b64 	%rd<5>;
+
+	ld.param.u64 	%rd1, [axpn_param_0];
+	ld.param.u32 	%r2, [axpn_param_1];
+	ld.param.f32 	%f1, [axpn_param_2];
+	mov.u32 	%r3, %ctaid.x;
+	mov.u32 	%r4, %ntid.x;
+	mov.u32 	%r5, %tid.x;
+	mad.lo.s32 	%r1, %r3, %r4, %r5;
+	setp.ge.u32 	%p1, %r1, %r2;
+	@%p1 bra 	$L__BB0_2;
+
+	cvta.to.global.u64 	%rd2, %rd1;
+	mul.wide.u32 	%rd3, %r1, 4;
+	add.s64 	%rd4, %rd2, %rd3;
+	ld.global.f32 	%f2, [%rd4];
+	cvt.rn.f32.u32 	%f3, %r2;
+	fma.rn.f32 	%f4, %f1, %f2, %f3;
+	st.global.f32 	[%rd4], %f4;
+
+$L__BB0_2:
+	ret;
+
+}
+";
+
+    #[test]
+    fn scalars_by_value() {
+        let mut dev = CudaDevice::new(0).unwrap();
+        dev.load_ptx("axpn", AXPN_PTX, &["axpn"]).unwrap();
+        let axpn = dev.get_module("axpn").and_then(|m| m.get_fn("axpn")).unwrap();
+        let buf = dev.take_slice(vec![1.0f32, 2.0, 3.0, 4.0]).unwrap();
+        //n and alpha straight from the stack, no device allocation for either
+        let (n, alpha) = (4u32, 0.5f32);
+        unsafe { dev.launch_args(axpn, LaunchConfig::for_num_elems(n), (&buf, n, alpha)) }.unwrap();
+        //alpha * x + n, swapping n and alpha or passing one by pointer gives something else
+        assert_eq!(dev.copy_to_host(&buf).unwrap(), vec![4.5, 5.0, 5.5, 6.0]);
+        dev.discard(buf).unwrap();
+    }
+}
@@ -1,3 +1,5 @@
+//HashMap, Mutex and friends, so the safe layer needs std. result and sys only need core + alloc.
+#[cfg(feature = "std")]
 pub mod borrow;
 pub mod rc;
 pub mod result;
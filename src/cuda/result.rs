@@ -1,58 +1,401 @@
-use std::{
+use core::{
     ffi::{c_uint, c_void},
     mem::{size_of, MaybeUninit},
+    sync::atomic::{AtomicPtr, Ordering},
 };
 
 use super::sys;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct CudaError(sys::CUresult); //tuple struct with one field accessed as self.0
+pub enum CudaError {
+    //error code returned by a driver call
+    Driver(sys::CUresult),
+    //a byte buffer was reinterpreted as elements that don't evenly divide it
+    InvalidByteLength { len_bytes: usize, elem_size: usize },
+    //a launch asked for more blocks along one grid axis than the device allows
+    GridDimTooLarge { axis: char, requested: u32, limit: u32 },
+    //the memory was allocated before the device was reset, the driver already freed it
+    StaleBuffer { buffer_generation: u64, device_generation: u64 },
+    //host data doesn't have the size the device side expects
+    LengthMismatch { expected: usize, actual: usize },
+    //the device is older than what the requested feature needs
+    ComputeCapabilityTooLow { required: (u32, u32), actual: (u32, u32) },
+    //a module image with no bytes in it
+    EmptyImage,
+    //an element range that is reversed or runs past the end of the buffer
+    RangeOutOfBounds { start: usize, end: usize, len: usize },
+    //more dynamic shared memory than the kernel is allowed to use
+    SharedMemTooLarge { requested: u32, limit: u32 },
+    //a launch passed a different number of kernel arguments than the kernel declares
+    ParamCountMismatch { expected: usize, actual: usize },
+    //count elements of elem_size bytes is more bytes than fit in a usize
+    SizeOverflow { count: usize, elem_size: usize },
+    //size_of::<T>() isn't the element stride the kernel side was declared with
+    LayoutMismatch { expected_stride: usize, actual_stride: usize },
+    //a launch argument's size differs from what the kernel declares for that parameter
+    ParamSizeMismatch { index: usize, expected: usize, actual: usize },
+    //more elements than a kernel's length argument can count, e.g. past u32::MAX for unsigned int n
+    LengthTooLarge { len: usize, limit: usize },
+    //a graph node was used as a dependency in a graph other than the one it was added to
+    ForeignGraphNode,
+    //cuMemAlloc on a stream that is being captured into a graph
+    AllocDuringCapture,
+    //memcpy_auto got host memory the driver doesn't know, i.e. neither page locked nor registered
+    PageableHostMemory { ptr: sys::CUdeviceptr },
+    //memcpy_auto got device memory allocated in a context other than the current one
+    ForeignContext { ptr: sys::CUdeviceptr },
+}
 
 impl sys::CUresult {
     pub fn result(self) -> Result<(), CudaError> {
         match self {
             sys::CUresult::CUDA_SUCCESS => Ok(()),
-            _ => Err(CudaError(self)),
+            _ => Err(CudaError::Driver(self)),
         }
     }
 }
 
-impl std::fmt::Display for CudaError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!("{:?}", self))
+impl core::fmt::Display for CudaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CudaError::Driver(code) => f.write_fmt(format_args!("{:?}", code)),
+            CudaError::InvalidByteLength { len_bytes, elem_size } => f.write_fmt(format_args!(
+                "byte length {} is not a multiple of the element size {}",
+                len_bytes, elem_size
+            )),
+            CudaError::GridDimTooLarge { axis, requested, limit } => f.write_fmt(format_args!(
+                "grid dimension {} is {} but the device maximum is {}",
+                axis, requested, limit
+            )),
+            CudaError::StaleBuffer { buffer_generation, device_generation } => f.write_fmt(format_args!(
+                "buffer is from device generation {} but the device was reset and is at generation {}",
+                buffer_generation, device_generation
+            )),
+            CudaError::LengthMismatch { expected, actual } => f.write_fmt(format_args!(
+                "expected length {} but got {}",
+                expected, actual
+            )),
+            CudaError::ComputeCapabilityTooLow { required, actual } => f.write_fmt(format_args!(
+                "needs compute capability {}.{} but the device is {}.{}",
+                required.0, required.1, actual.0, actual.1
+            )),
+            CudaError::EmptyImage => f.write_str("module image is empty"),
+            CudaError::RangeOutOfBounds { start, end, len } => f.write_fmt(format_args!(
+                "range {}..{} is out of bounds for a buffer of length {}",
+                start, end, len
+            )),
+            CudaError::SharedMemTooLarge { requested, limit } => f.write_fmt(format_args!(
+                "kernel asks for {} bytes of dynamic shared memory but may use {}. Above 48KB the \
+                 kernel has to opt in with CudaFunction::set_attribute(CU_FUNC_ATTRIBUTE_MAX_DYNAMIC_SHARED_SIZE_BYTES, ..)",
+                requested, limit
+            )),
+            CudaError::ParamCountMismatch { expected, actual } => f.write_fmt(format_args!(
+                "kernel expects {} args, got {}",
+                expected, actual
+            )),
+            CudaError::SizeOverflow { count, elem_size } => f.write_fmt(format_args!(
+                "{} elements of {} bytes overflows usize",
+                count, elem_size
+            )),
+            CudaError::LayoutMismatch { expected_stride, actual_stride } => f.write_fmt(format_args!(
+                "element is {} bytes on the rust side but {} on the device side, check the struct is #[repr(C)] and matches the CUDA one",
+                actual_stride, expected_stride
+            )),
+            CudaError::ParamSizeMismatch { index, expected, actual } => f.write_fmt(format_args!(
+                "kernel argument {} is {} bytes but the kernel expects {}",
+                index, actual, expected
+            )),
+            CudaError::LengthTooLarge { len, limit } => f.write_fmt(format_args!(
+                "length {} is more than the kernel's length argument can hold ({})",
+                len, limit
+            )),
+            CudaError::ForeignGraphNode => f.write_str("dependency node belongs to a different graph"),
+            CudaError::AllocDuringCapture => f.write_str(
+                "cannot allocate during stream capture, allocate before begin_capture or use a stream ordered allocator",
+            ),
+            CudaError::PageableHostMemory { ptr } => f.write_fmt(format_args!(
+                "{:#x} is pageable host memory, use page locked memory (cuMemAllocHost/cuMemHostRegister)",
+                ptr
+            )),
+            CudaError::ForeignContext { ptr } => f.write_fmt(format_args!(
+                "{:#x} was allocated in a different context than the current one",
+                ptr
+            )),
+        }
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for CudaError {}
 
+//what the alloc_* methods on CudaDevice return, and LaunchError the same for launch/launch_ex,
+//so callers can tell "couldn't allocate" from "kernel failed" by type. Both deref to the
+//CudaError inside and turn back into one with ? or .into().
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocError(pub CudaError);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LaunchError(pub CudaError);
+
+macro_rules! operation_error {
+    ($name:ident, $what:literal) => {
+        impl core::ops::Deref for $name {
+            type Target = CudaError;
+            fn deref(&self) -> &CudaError {
+                &self.0
+            }
+        }
+
+        impl From<CudaError> for $name {
+            fn from(e: CudaError) -> Self {
+                $name(e)
+            }
+        }
+
+        impl From<$name> for CudaError {
+            fn from(e: $name) -> Self {
+                e.0
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_fmt(format_args!(concat!($what, " failed: {}"), self.0))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl std::error::Error for $name {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+    };
+}
+
+operation_error!(AllocError, "allocation");
+operation_error!(LaunchError, "launch");
+
+pub type CallLogger = alloc::boxed::Box<dyn Fn(&str, sys::CUresult) + Send + Sync>;
+
+static CALL_LOGGER: AtomicPtr<CallLogger> = AtomicPtr::new(core::ptr::null_mut());
+
+//f sees every driver call made through this module, with the entry point's name (cuMemAlloc_v2,
+//cuLaunchKernel...) and what it returned, for tracing a hang or a failure back to the call. Not
+//set means one atomic load per call. A logger that gets replaced is leaked on purpose, a call on
+//another thread may still be running it. cuGetErrorName/cuGetErrorString aren't logged so f can
+//use error_name/error_string.
+pub fn set_call_logger(f: impl Fn(&str, sys::CUresult) + Send + Sync + 'static) {
+    let logger: alloc::boxed::Box<CallLogger> = alloc::boxed::Box::new(alloc::boxed::Box::new(f));
+    CALL_LOGGER.store(alloc::boxed::Box::into_raw(logger), Ordering::Release);
+}
+
+#[inline]
+pub(crate) fn log_call(name: &str, res: sys::CUresult) {
+    let logger = CALL_LOGGER.load(Ordering::Acquire);
+    if !logger.is_null() {
+        unsafe { (*logger)(name, res) };
+    }
+}
+
+//driver_call!(cuInit(0)) is sys::cuInit(0) reported to the call logger, every driver call here
+//goes through it
+macro_rules! driver_call {
+    ($f:ident($($arg:expr),* $(,)?)) => {{
+        let res = sys::$f($($arg),*);
+        $crate::cuda::result::log_call(stringify!($f), res);
+        res
+    }};
+}
+
+//CUDA_ERROR_ASSERT style name and the driver's sentence for a code, None for codes the driver
+//doesn't know
+pub fn error_name(code: sys::CUresult) -> Option<&'static str> {
+    let mut ptr = core::ptr::null();
+    unsafe { sys::cuGetErrorName(code, &mut ptr).result().ok()? };
+    unsafe { core::ffi::CStr::from_ptr(ptr) }.to_str().ok()
+}
+
+pub fn error_string(code: sys::CUresult) -> Option<&'static str> {
+    let mut ptr = core::ptr::null();
+    unsafe { sys::cuGetErrorString(code, &mut ptr).result().ok()? };
+    unsafe { core::ffi::CStr::from_ptr(ptr) }.to_str().ok()
+}
+
 pub fn init() -> Result<(), CudaError> {
-    unsafe { sys::cuInit(0).result() }
+    unsafe { driver_call!(cuInit(0)).result() }
 }
 
+#[cfg(feature = "std")]
+static INIT: std::sync::OnceLock<Result<(), CudaError>> = std::sync::OnceLock::new();
+
+//cuInit exactly once per process, whoever gets here first runs it and everyone else (including
+//threads racing with it) gets the same result back. A failed init isn't retried.
+#[cfg(feature = "std")]
+pub fn ensure_init() -> Result<(), CudaError> {
+    *INIT.get_or_init(init)
+}
+
+#[cfg(feature = "std")]
+pub fn is_initialized() -> bool {
+    matches!(INIT.get(), Some(Ok(())))
+}
+
+//no OnceLock without std, so the same thing by hand: a state word that one caller moves from
+//NOT_STARTED to RUNNING and later to DONE, with the CUresult stashed next to it.
+#[cfg(not(feature = "std"))]
+mod once {
+    use super::{sys, CudaError};
+    use core::sync::atomic::{AtomicI32, AtomicU8, Ordering};
+
+    const NOT_STARTED: u8 = 0;
+    const RUNNING: u8 = 1;
+    const DONE: u8 = 2;
+
+    static STATE: AtomicU8 = AtomicU8::new(NOT_STARTED);
+    static CODE: AtomicI32 = AtomicI32::new(0);
+
+    pub fn ensure_init() -> Result<(), CudaError> {
+        if STATE
+            .compare_exchange(NOT_STARTED, RUNNING, Ordering::Acquire, Ordering::Acquire)
+            .is_ok()
+        {
+            let code = unsafe { driver_call!(cuInit(0)) };
+            CODE.store(code as i32, Ordering::Relaxed);
+            STATE.store(DONE, Ordering::Release);
+            return code.result();
+        }
+        while STATE.load(Ordering::Acquire) != DONE {
+            core::hint::spin_loop();
+        }
+        code().result()
+    }
+
+    pub fn is_initialized() -> bool {
+        STATE.load(Ordering::Acquire) == DONE && code() == sys::CUresult::CUDA_SUCCESS
+    }
+
+    fn code() -> sys::CUresult {
+        //only ever holds a value that came out of cuInit
+        unsafe { core::mem::transmute::<i32, sys::CUresult>(CODE.load(Ordering::Relaxed)) }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+pub use once::{ensure_init, is_initialized};
+
 //this core pattern will be used everywhere. CUresult is the C enum type returned by
 //every CUDA call. We add a .result() method directly on it that converts CUDA_SUCCESS -> Ok(())
-//anything else -> Err(CudaError(...)). The CudaError newtype wraps the raw enum so we can
-//implement Display and Error on it.
+//anything else -> Err(CudaError::Driver(...)). The Driver variant wraps the raw enum so we can
+//implement Display and Error on it. The other variants are for mistakes we catch on the rust side
+//before anything is handed to the driver.
+
+//every driver call below works on the context current on the calling thread, and calling one from
+//a thread that never bound a context (CudaDevice::bind_to_thread) is the usual multi threading
+//mistake. It comes back as CUDA_ERROR_INVALID_CONTEXT from somewhere deep down, so debug builds
+//check up front and say what's wrong. Compiles to nothing in release.
+#[inline]
+pub fn debug_assert_ctx_current() {
+    #[cfg(debug_assertions)]
+    {
+        let current = ctx::get_current();
+        assert!(
+            matches!(current, Ok(ctx) if !ctx.is_null()),
+            "no CUDA context is current on this thread, call CudaDevice::bind_to_thread first"
+        );
+    }
+}
 
 pub mod device {
     use super::{sys, CudaError};
-    use std::mem::MaybeUninit;
+    use core::mem::MaybeUninit;
+
+    //devices the driver can see, CUDA_VISIBLE_DEVICES applied
+    pub fn get_count() -> Result<i32, CudaError> {
+        let mut count = 0;
+        unsafe { driver_call!(cuDeviceGetCount(&mut count)).result()? };
+        Ok(count)
+    }
 
-    pub fn get(ordinal: std::ffi::c_int) -> Result<sys::CUdevice, CudaError> {
+    pub fn get(ordinal: core::ffi::c_int) -> Result<sys::CUdevice, CudaError> {
         let mut dev: sys::CUdevice = 0;
-        unsafe {sys::cuDeviceGet((&mut dev) as *mut sys::CUdevice, ordinal).result()? }
+        unsafe {driver_call!(cuDeviceGet((&mut dev) as *mut sys::CUdevice, ordinal)).result()? }
         //&mut dev is a rust reference its not a raw C pointer. we need to cast it to a raw C pointer
         Ok(dev)
     }
 
+    pub use sys::CUdevice_attribute;
+
+    pub fn get_attribute(dev: sys::CUdevice, attrib: CUdevice_attribute) -> Result<i32, CudaError> {
+        let mut value = 0;
+        unsafe { driver_call!(cuDeviceGetAttribute(&mut value as *mut i32, attrib, dev)).result()? }
+        Ok(value)
+    }
+
+    pub use sys::CUdevice_P2PAttribute;
+
+    //how src reaches dst: PERFORMANCE_RANK (lower is faster, NVLink beats PCIe), ACCESS_SUPPORTED,
+    //NATIVE_ATOMIC_SUPPORTED. Asymmetric in principle, so ask in the direction of the copy.
+    pub fn get_p2p_attribute(
+        attrib: CUdevice_P2PAttribute,
+        src: sys::CUdevice,
+        dst: sys::CUdevice,
+    ) -> Result<i32, CudaError> {
+        let mut value = 0;
+        unsafe { driver_call!(cuDeviceGetP2PAttribute(&mut value, attrib, src, dst)).result()? }
+        Ok(value)
+    }
+
+    pub fn get_name(dev: sys::CUdevice) -> Result<alloc::string::String, CudaError> {
+        const BUF_LEN: usize = 256;
+        let mut buf = [0 as core::ffi::c_char; BUF_LEN];
+        unsafe {
+            driver_call!(cuDeviceGetName(buf.as_mut_ptr(), BUF_LEN as core::ffi::c_int, dev)).result()?;
+            Ok(core::ffi::CStr::from_ptr(buf.as_ptr()).to_string_lossy().into_owned())
+        }
+    }
+
+    //the 16 byte UUID nvidia-smi and CUDA_VISIBLE_DEVICES use, the MIG instance's for a MIG device
+    pub fn get_uuid(dev: sys::CUdevice) -> Result<[u8; 16], CudaError> {
+        let mut uuid = MaybeUninit::<sys::CUuuid>::uninit();
+        unsafe {
+            driver_call!(cuDeviceGetUuid_v2(uuid.as_mut_ptr(), dev)).result()?;
+            Ok(uuid.assume_init().bytes.map(|b| b as u8))
+        }
+    }
+
+    //bytes of memory on the device, doesn't need a context
+    pub fn total_mem(dev: sys::CUdevice) -> Result<usize, CudaError> {
+        let mut bytes = 0;
+        unsafe { driver_call!(cuDeviceTotalMem_v2(&mut bytes as *mut usize, dev)).result()? }
+        Ok(bytes)
+    }
+
+    //call before the primary context is retained, older drivers reject it once the context is active
+    /// # Safety
+    /// dev must come from device::get
+    pub unsafe fn primary_ctx_set_flags(dev: sys::CUdevice, flags: sys::CUctx_flags) -> Result<(), CudaError> {
+        driver_call!(cuDevicePrimaryCtxSetFlags_v2(dev, flags as u32)).result()
+    }
+
+    /// # Safety
+    /// dev must come from device::get, every retain needs a matching primary_ctx_release
     pub unsafe fn primary_ctx_retain(dev: sys::CUdevice) -> Result<sys::CUcontext, CudaError> {
         let mut ctx = MaybeUninit::uninit();
-        sys::cuDevicePrimaryCtxRetain(ctx.as_mut_ptr(), dev).result()?;
+        driver_call!(cuDevicePrimaryCtxRetain(ctx.as_mut_ptr(), dev)).result()?;
         Ok(ctx.assume_init())
     }
 
+    /// # Safety
+    /// dev must have been retained with primary_ctx_retain, nothing may use its context after the last release
     pub unsafe fn primary_ctx_release(dev: sys::CUdevice) -> Result<(), CudaError> {
-        sys::cuDevicePrimaryCtxRelease_v2(dev).result()
+        driver_call!(cuDevicePrimaryCtxRelease_v2(dev)).result()
+    }
+
+    /// # Safety
+    /// nothing may use dev's primary context or anything allocated in it afterwards
+    pub unsafe fn primary_ctx_reset(dev: sys::CUdevice) -> Result<(), CudaError> {
+        driver_call!(cuDevicePrimaryCtxReset_v2(dev)).result()
     }
 
 }
@@ -60,114 +403,855 @@ pub mod device {
 pub mod ctx {
     use super::{sys, CudaError};
 
+    /// # Safety
+    /// ctx must be a live context or null
     pub unsafe fn set_current(ctx: sys::CUcontext) -> Result<(), CudaError> {
-        sys::cuCtxSetCurrent(ctx).result()
+        driver_call!(cuCtxSetCurrent(ctx)).result()
+    }
+
+    //null when this thread has no context
+    pub fn get_current() -> Result<sys::CUcontext, CudaError> {
+        let mut ctx = core::ptr::null_mut();
+        unsafe { driver_call!(cuCtxGetCurrent(&mut ctx as *mut sys::CUcontext)).result()? }
+        Ok(ctx)
+    }
+
+    //device of the context current on this thread
+    pub fn get_device() -> Result<sys::CUdevice, CudaError> {
+        let mut dev: sys::CUdevice = 0;
+        unsafe { driver_call!(cuCtxGetDevice(&mut dev as *mut sys::CUdevice)).result()? }
+        Ok(dev)
+    }
+
+    //waits for all work in the current context, on every stream
+    pub fn synchronize() -> Result<(), CudaError> {
+        unsafe { driver_call!(cuCtxSynchronize()).result() }
+    }
+
+    //the api version ctx was created with, 3020 for anything made through the current api. Other
+    //libraries sharing the primary context may have created it with something else.
+    /// # Safety
+    /// ctx must be a live context
+    pub unsafe fn api_version(ctx: sys::CUcontext) -> Result<u32, CudaError> {
+        let mut version = 0;
+        driver_call!(cuCtxGetApiVersion(ctx, &mut version as *mut core::ffi::c_uint)).result()?;
+        Ok(version)
+    }
+
+    pub use sys::CUlimit;
+
+    //limits of the context current on this thread
+    pub fn get_limit(limit: CUlimit) -> Result<usize, CudaError> {
+        let mut value = 0;
+        unsafe { driver_call!(cuCtxGetLimit(&mut value as *mut usize, limit)).result()? }
+        Ok(value)
+    }
+
+    pub fn set_limit(limit: CUlimit, value: usize) -> Result<(), CudaError> {
+        unsafe { driver_call!(cuCtxSetLimit(limit, value)).result() }
+    }
+
+    //demotes every persisting L2 line of the current context back to normal
+    pub fn reset_persisting_l2_cache() -> Result<(), CudaError> {
+        unsafe { driver_call!(cuCtxResetPersistingL2Cache()).result() }
     }
 }
 
 
 pub mod stream {
     use super::{sys, CudaError};
-    use std::mem::MaybeUninit;
+    use core::mem::MaybeUninit;
 
     pub use sys::CUstream_flags;
 
     pub fn null() -> sys::CUstream {
-        std::ptr::null_mut()
+        core::ptr::null_mut()
     }
 
     pub fn create(flags: CUstream_flags) -> Result<sys::CUstream, CudaError> {
         let mut stream = MaybeUninit::uninit();
         unsafe {
-            sys::cuStreamCreate(stream.as_mut_ptr(), flags as u32).result()?;
+            driver_call!(cuStreamCreate(stream.as_mut_ptr(), flags as u32)).result()?;
             Ok(stream.assume_init())
         }
     }
 
+    //lower numbers are higher priority. The driver clamps out of range priorities instead of failing,
+    //get_priority tells what was actually applied.
+    pub fn create_with_priority(flags: CUstream_flags, priority: i32) -> Result<sys::CUstream, CudaError> {
+        let mut stream = MaybeUninit::uninit();
+        unsafe {
+            driver_call!(cuStreamCreateWithPriority(stream.as_mut_ptr(), flags as u32, priority)).result()?;
+            Ok(stream.assume_init())
+        }
+    }
+
+    /// # Safety
+    /// stream must be a live stream or null
+    pub unsafe fn get_flags(stream: sys::CUstream) -> Result<u32, CudaError> {
+        let mut flags = 0;
+        driver_call!(cuStreamGetFlags(stream, &mut flags as *mut u32)).result()?;
+        Ok(flags)
+    }
+
+    /// # Safety
+    /// stream must be a live stream or null
+    pub unsafe fn get_priority(stream: sys::CUstream) -> Result<i32, CudaError> {
+        let mut priority = 0;
+        driver_call!(cuStreamGetPriority(stream, &mut priority as *mut i32)).result()?;
+        Ok(priority)
+    }
+
+    /// # Safety
+    /// stream must be a live stream or null
     pub unsafe fn synchronize(stream: sys::CUstream) -> Result<(), CudaError> {
-        sys::cuStreamSynchronize(stream).result()
+        driver_call!(cuStreamSynchronize(stream)).result()
     }
 
+    /// # Safety
+    /// stream must be a live stream created by this process, not used afterwards
     pub unsafe fn destroy(stream: sys::CUstream) -> Result<(), CudaError> {
-        sys::cuStreamDestroy_v2(stream).result()
+        driver_call!(cuStreamDestroy_v2(stream)).result()
+    }
+
+    //runs func(user_data) on a driver thread once the work before it on stream is done.
+    //func must not call into CUDA and must never unwind, the driver is C code.
+    /// # Safety
+    /// stream must be a live stream, user_data must stay valid until func has run, func must not call into CUDA or unwind
+    pub unsafe fn launch_host_func(
+        stream: sys::CUstream,
+        func: unsafe extern "C" fn(*mut core::ffi::c_void),
+        user_data: *mut core::ffi::c_void,
+    ) -> Result<(), CudaError> {
+        driver_call!(cuLaunchHostFunc(stream, Some(func), user_data)).result()
+    }
+
+    pub use sys::CUstreamCaptureMode;
+
+    //from here until end_capture, work enqueued on stream is recorded into a graph instead of run
+    /// # Safety
+    /// stream must be a live non null stream that isn't already capturing
+    pub unsafe fn begin_capture(stream: sys::CUstream, mode: CUstreamCaptureMode) -> Result<(), CudaError> {
+        driver_call!(cuStreamBeginCapture_v2(stream, mode)).result()
+    }
+
+    /// # Safety
+    /// stream must be a live stream capturing since begin_capture
+    pub unsafe fn end_capture(stream: sys::CUstream) -> Result<sys::CUgraph, CudaError> {
+        let mut graph = MaybeUninit::uninit();
+        driver_call!(cuStreamEndCapture(stream, graph.as_mut_ptr())).result()?;
+        Ok(graph.assume_init())
+    }
+
+    //true from begin_capture until end_capture, also when the capture was invalidated by an
+    //illegal call and end_capture will fail. cuStreamIsCapturing reports the same status as
+    //cuStreamGetCaptureInfo without the graph details, and exists since 10.0 where the latter's _v2
+    //needs 11.3, which would raise the driver the library loads on for every alloc.
+    /// # Safety
+    /// stream must be a live stream or null
+    pub unsafe fn is_capturing(stream: sys::CUstream) -> Result<bool, CudaError> {
+        let mut status = MaybeUninit::uninit();
+        driver_call!(cuStreamIsCapturing(stream, status.as_mut_ptr())).result()?;
+        Ok(status.assume_init() != sys::CUstreamCaptureStatus::CU_STREAM_CAPTURE_STATUS_NONE)
+    }
+
+    //associates managed memory with stream (or the host / every stream, depending on flags).
+    //length 0 means the whole allocation. Takes effect in stream order.
+    /// # Safety
+    /// stream must be a live stream, dptr must be managed memory of at least length bytes (or the start of it for 0)
+    pub unsafe fn attach_mem_async(
+        stream: sys::CUstream,
+        dptr: sys::CUdeviceptr,
+        length: usize,
+        flags: sys::CUmemAttach_flags,
+    ) -> Result<(), CudaError> {
+        driver_call!(cuStreamAttachMemAsync(stream, dptr, length, flags as core::ffi::c_uint)).result()
+    }
+
+    //kernels launched on stream afterwards keep hit_ratio of the num_bytes at base_ptr in the
+    //set aside L2 (persisting), the rest streams through. num_bytes 0 clears the window.
+    /// # Safety
+    /// stream must be a live stream, base_ptr..base_ptr+num_bytes must be device memory of its context
+    pub unsafe fn set_access_policy_window(
+        stream: sys::CUstream,
+        base_ptr: sys::CUdeviceptr,
+        num_bytes: usize,
+        hit_ratio: f32,
+    ) -> Result<(), CudaError> {
+        let mut value: sys::CUstreamAttrValue = core::mem::zeroed();
+        value.accessPolicyWindow = sys::CUaccessPolicyWindow {
+            base_ptr: base_ptr as *mut core::ffi::c_void,
+            num_bytes,
+            hitRatio: hit_ratio,
+            hitProp: sys::CUaccessProperty::CU_ACCESS_PROPERTY_PERSISTING,
+            missProp: sys::CUaccessProperty::CU_ACCESS_PROPERTY_STREAMING,
+        };
+        let attr = sys::CUstreamAttrID::CU_LAUNCH_ATTRIBUTE_ACCESS_POLICY_WINDOW;
+        driver_call!(cuStreamSetAttribute(stream, attr, &value)).result()
+    }
+
+    //Ok(true) once everything on stream has finished, Ok(false) while work is still pending
+    /// # Safety
+    /// stream must be a live stream or null
+    pub unsafe fn query(stream: sys::CUstream) -> Result<bool, CudaError> {
+        match driver_call!(cuStreamQuery(stream)) {
+            sys::CUresult::CUDA_SUCCESS => Ok(true),
+            sys::CUresult::CUDA_ERROR_NOT_READY => Ok(false),
+            res => Err(CudaError::Driver(res)),
+        }
+    }
+
+    //work enqueued on stream after this waits until event has completed
+    /// # Safety
+    /// stream and event must be live, event in the same process
+    pub unsafe fn wait_event(stream: sys::CUstream, event: sys::CUevent) -> Result<(), CudaError> {
+        driver_call!(cuStreamWaitEvent(stream, event, 0)).result()
+    }
+}
+
+pub mod event {
+    use super::{sys, CudaError};
+    use core::mem::MaybeUninit;
+
+    pub use sys::CUevent_flags;
+
+    pub fn create(flags: CUevent_flags) -> Result<sys::CUevent, CudaError> {
+        let mut event = MaybeUninit::uninit();
+        unsafe {
+            driver_call!(cuEventCreate(event.as_mut_ptr(), flags as u32)).result()?;
+            Ok(event.assume_init())
+        }
+    }
+
+    /// # Safety
+    /// event must be live, stream live or null, both in the same context
+    pub unsafe fn record(event: sys::CUevent, stream: sys::CUstream) -> Result<(), CudaError> {
+        driver_call!(cuEventRecord(event, stream)).result()
+    }
+
+    /// # Safety
+    /// event must be a live event
+    pub unsafe fn synchronize(event: sys::CUevent) -> Result<(), CudaError> {
+        driver_call!(cuEventSynchronize(event)).result()
+    }
+
+    //true once the work before the event's last record has finished, without waiting for it
+    /// # Safety
+    /// event must be a live event
+    pub unsafe fn query(event: sys::CUevent) -> Result<bool, CudaError> {
+        match driver_call!(cuEventQuery(event)) {
+            sys::CUresult::CUDA_SUCCESS => Ok(true),
+            sys::CUresult::CUDA_ERROR_NOT_READY => Ok(false),
+            res => Err(CudaError::Driver(res)),
+        }
+    }
+
+    //milliseconds between two completed events, both need timing enabled
+    /// # Safety
+    /// start and end must be live events created with timing enabled
+    pub unsafe fn elapsed_ms(start: sys::CUevent, end: sys::CUevent) -> Result<f32, CudaError> {
+        let mut ms = 0.0;
+        driver_call!(cuEventElapsedTime(&mut ms as *mut f32, start, end)).result()?;
+        Ok(ms)
+    }
+
+    /// # Safety
+    /// event must be a live event, not used afterwards
+    pub unsafe fn destroy(event: sys::CUevent) -> Result<(), CudaError> {
+        driver_call!(cuEventDestroy_v2(event)).result()
+    }
+}
+
+//the pools behind malloc_async/free_async
+pub mod mem_pool {
+    use super::{sys, CudaError};
+    use core::mem::MaybeUninit;
+
+    pub use sys::CUmemPool_attribute;
+
+    pub fn get_default(dev: sys::CUdevice) -> Result<sys::CUmemoryPool, CudaError> {
+        let mut pool = MaybeUninit::uninit();
+        unsafe {
+            driver_call!(cuDeviceGetDefaultMemPool(pool.as_mut_ptr(), dev)).result()?;
+            Ok(pool.assume_init())
+        }
+    }
+
+    //bytes of freed memory the pool keeps reserved instead of returning it to the os at the next
+    //synchronization
+    /// # Safety
+    /// pool must be a live memory pool
+    pub unsafe fn set_release_threshold(pool: sys::CUmemoryPool, num_bytes: u64) -> Result<(), CudaError> {
+        let mut value = num_bytes;
+        driver_call!(cuMemPoolSetAttribute(
+            pool,
+            CUmemPool_attribute::CU_MEMPOOL_ATTR_RELEASE_THRESHOLD,
+            &mut value as *mut u64 as *mut _,
+        ))
+        .result()
+    }
+}
+
+//an entry point looked up in the driver library the process already loaded instead of linked
+//against, None when that driver doesn't export it. Linking the symbol directly would make the
+//whole library fail to load on a driver without it (the binary resolves every import at load
+//time), looking it up here only costs the feature that needs it. cuGetProcAddress isn't used for
+//this since it's an 11.3 symbol itself (the _v2 one 12.0), the lookup goes through the dynamic
+//loader on the library that holds cuInit.
+pub fn driver_symbol(symbol: &core::ffi::CStr) -> Option<*mut c_void> {
+    let pfn = unsafe { dl::lookup(symbol) };
+    (!pfn.is_null()).then_some(pfn)
+}
+
+#[cfg(unix)]
+mod dl {
+    use core::ffi::{c_char, c_int, c_void, CStr};
+
+    const RTLD_LAZY: c_int = 0x1;
+    //only hand out a handle for a library that is loaded already, never load a second copy
+    const RTLD_NOLOAD: c_int = 0x4;
+
+    #[cfg_attr(all(target_os = "linux", target_env = "gnu"), link(name = "dl"))]
+    unsafe extern "C" {
+        fn dlopen(filename: *const c_char, flags: c_int) -> *mut c_void;
+        fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+    }
+
+    pub(super) unsafe fn lookup(symbol: &CStr) -> *mut c_void {
+        //libcuda.so.1 is what the link against libcuda.so resolves to at runtime. The extra
+        //reference dlopen takes is never dropped, the library stays loaded for the process anyway.
+        let lib = dlopen(c"libcuda.so.1".as_ptr(), RTLD_LAZY | RTLD_NOLOAD);
+        if lib.is_null() {
+            return core::ptr::null_mut();
+        }
+        dlsym(lib, symbol.as_ptr())
     }
 }
 
+#[cfg(windows)]
+mod dl {
+    use core::ffi::{c_char, c_void, CStr};
+
+    #[link(name = "kernel32")]
+    unsafe extern "system" {
+        fn GetModuleHandleA(module_name: *const c_char) -> *mut c_void;
+        fn GetProcAddress(module: *mut c_void, proc_name: *const c_char) -> *mut c_void;
+    }
 
+    pub(super) unsafe fn lookup(symbol: &CStr) -> *mut c_void {
+        let lib = GetModuleHandleA(c"nvcuda.dll".as_ptr());
+        if lib.is_null() {
+            return core::ptr::null_mut();
+        }
+        GetProcAddress(lib, symbol.as_ptr())
+    }
+}
+
+//cuMemAllocAsync/cuMemFreeAsync found with driver_symbol, nothing here links against them, so the
+//library still loads on a driver older than 11.2 and the borrow layer falls back to malloc_raw/free.
+pub mod async_alloc {
+    use super::{debug_assert_ctx_current, driver_symbol, log_call, sys, CudaError};
+    use core::mem::MaybeUninit;
+    use core::sync::atomic::{AtomicPtr, AtomicU8, Ordering};
+
+    type MallocAsyncFn = unsafe extern "C" fn(*mut sys::CUdeviceptr, usize, sys::CUstream) -> sys::CUresult;
+    type FreeAsyncFn = unsafe extern "C" fn(sys::CUdeviceptr, sys::CUstream) -> sys::CUresult;
+
+    #[derive(Debug, Clone, Copy)]
+    pub struct AsyncAllocFns {
+        malloc: MallocAsyncFn,
+        free: FreeAsyncFn,
+    }
+
+    //what get found, so the lookup happens once per process. Two threads resolving at the same
+    //time store the same pointers.
+    const UNRESOLVED: u8 = 0;
+    const FOUND: u8 = 1;
+    const MISSING: u8 = 2;
+    static STATE: AtomicU8 = AtomicU8::new(UNRESOLVED);
+    static MALLOC: AtomicPtr<core::ffi::c_void> = AtomicPtr::new(core::ptr::null_mut());
+    static FREE: AtomicPtr<core::ffi::c_void> = AtomicPtr::new(core::ptr::null_mut());
+
+    impl AsyncAllocFns {
+        //None when the driver is missing either one. Can't fail otherwise, it's a symbol lookup,
+        //not a driver call.
+        pub fn get() -> Option<Self> {
+            match STATE.load(Ordering::Acquire) {
+                FOUND => {}
+                MISSING => return None,
+                _ => {
+                    let (Some(malloc), Some(free)) =
+                        (driver_symbol(c"cuMemAllocAsync"), driver_symbol(c"cuMemFreeAsync"))
+                    else {
+                        STATE.store(MISSING, Ordering::Release);
+                        return None;
+                    };
+                    MALLOC.store(malloc, Ordering::Relaxed);
+                    FREE.store(free, Ordering::Relaxed);
+                    STATE.store(FOUND, Ordering::Release);
+                }
+            }
+            //the driver exports these entry points with exactly these signatures
+            Some(unsafe {
+                AsyncAllocFns {
+                    malloc: core::mem::transmute::<*mut core::ffi::c_void, MallocAsyncFn>(MALLOC.load(Ordering::Relaxed)),
+                    free: core::mem::transmute::<*mut core::ffi::c_void, FreeAsyncFn>(FREE.load(Ordering::Relaxed)),
+                }
+            })
+        }
+
+        /// # Safety
+        /// a context must be current on this thread, stream must be a live stream of it
+        pub unsafe fn malloc(&self, num_bytes: usize, stream: sys::CUstream) -> Result<sys::CUdeviceptr, CudaError> {
+            debug_assert_ctx_current();
+            let mut dev_ptr = MaybeUninit::uninit();
+            let res = (self.malloc)(dev_ptr.as_mut_ptr(), num_bytes, stream);
+            log_call("cuMemAllocAsync", res);
+            res.result()?;
+            Ok(dev_ptr.assume_init())
+        }
+
+        /// # Safety
+        /// dptr must come from malloc on stream's context and not be freed twice, nothing may use it afterwards
+        pub unsafe fn free(&self, dptr: sys::CUdeviceptr, stream: sys::CUstream) -> Result<(), CudaError> {
+            let res = (self.free)(dptr, stream);
+            log_call("cuMemFreeAsync", res);
+            res.result()
+        }
+    }
+}
 
 //memory allocation and transfer
+/// # Safety
+/// a context must be current on this thread
 pub unsafe fn malloc<T>() -> Result<sys::CUdeviceptr, CudaError> {
-    let bytesize = size_of::<T>();
+    malloc_raw(size_of::<T>())
+}
+
+/// # Safety
+/// a context must be current on this thread
+pub unsafe fn malloc_raw(num_bytes: usize) -> Result<sys::CUdeviceptr, CudaError> {
+    debug_assert_ctx_current();
     let mut dev_ptr = MaybeUninit::uninit();
     unsafe {
-        sys::cuMemAlloc_v2(dev_ptr.as_mut_ptr(), bytesize).result()?;
+        driver_call!(cuMemAlloc_v2(dev_ptr.as_mut_ptr(), num_bytes)).result()?;
         Ok(dev_ptr.assume_init())
     }
 }
 
+/// # Safety
+/// a context must be current on this thread, stream must be a live stream of it
 pub unsafe fn malloc_async<T>(stream: sys::CUstream) -> Result<sys::CUdeviceptr, CudaError> {
-    let bytesize = size_of::<T>();
+    malloc_async_raw(size_of::<T>(), stream)
+}
+
+//same as malloc_async but for a byte count only known at runtime (slices, cloned buffers).
+//Both go through async_alloc, a driver without cuMemAllocAsync gets CUDA_ERROR_NOT_SUPPORTED.
+/// # Safety
+/// a context must be current on this thread, stream must be a live stream of it
+pub unsafe fn malloc_async_raw(
+    num_bytes: usize,
+    stream: sys::CUstream,
+) -> Result<sys::CUdeviceptr, CudaError> {
+    let fns = async_alloc::AsyncAllocFns::get().ok_or(CudaError::Driver(sys::CUresult::CUDA_ERROR_NOT_SUPPORTED))?;
+    fns.malloc(num_bytes, stream)
+}
+
+//unified memory the driver migrates between host and device on demand, mapped at the same
+//address on both
+/// # Safety
+/// a context must be current on this thread
+pub unsafe fn malloc_managed(num_bytes: usize, flags: sys::CUmemAttach_flags) -> Result<sys::CUdeviceptr, CudaError> {
+    debug_assert_ctx_current();
     let mut dev_ptr = MaybeUninit::uninit();
+    driver_call!(cuMemAllocManaged(dev_ptr.as_mut_ptr(), num_bytes, flags as c_uint)).result()?;
+    Ok(dev_ptr.assume_init())
+}
+
+//the device argument of mem_advise that means the host (CU_DEVICE_CPU in cuda.h)
+pub const CU_DEVICE_CPU: sys::CUdevice = -1;
+
+//usage hint for count bytes of managed memory at dptr, device is a CUdevice or CU_DEVICE_CPU
+/// # Safety
+/// dptr..dptr+count must be managed memory, device a live CUdevice or CU_DEVICE_CPU
+pub unsafe fn mem_advise(
+    dptr: sys::CUdeviceptr,
+    count: usize,
+    advice: sys::CUmem_advise,
+    device: sys::CUdevice,
+) -> Result<(), CudaError> {
+    driver_call!(cuMemAdvise(dptr, count, advice, device)).result()
+}
+
+//base address and size of the allocation dptr points into, the size being what the driver actually
+//reserved. A pointer outside every allocation of the current context is CUDA_ERROR_NOT_FOUND.
+pub fn mem_get_address_range(dptr: sys::CUdeviceptr) -> Result<(sys::CUdeviceptr, usize), CudaError> {
+    let mut base = MaybeUninit::uninit();
+    let mut size = MaybeUninit::uninit();
     unsafe {
-        sys::cuMemAllocAsync(dev_ptr.as_mut_ptr(), bytesize, stream).result()?;
-        Ok(dev_ptr.assume_init())
+        driver_call!(cuMemGetAddressRange_v2(base.as_mut_ptr(), size.as_mut_ptr(), dptr)).result()?;
+        Ok((base.assume_init(), size.assume_init()))
     }
 }
 
+//height rows of width_bytes each, every row starting at a multiple of the returned pitch (bytes).
+//elem_size is the access size kernels will use, 4, 8 or 16, and picks the row alignment.
+/// # Safety
+/// a context must be current on this thread
+pub unsafe fn malloc_pitch(
+    width_bytes: usize,
+    height: usize,
+    elem_size: c_uint,
+) -> Result<(sys::CUdeviceptr, usize), CudaError> {
+    debug_assert_ctx_current();
+    let mut dev_ptr = MaybeUninit::uninit();
+    let mut pitch = 0;
+    driver_call!(cuMemAllocPitch_v2(dev_ptr.as_mut_ptr(), &mut pitch as *mut usize, width_bytes, height, elem_size)).result()?;
+    Ok((dev_ptr.assume_init(), pitch))
+}
+
+//sets width bytes of each of height rows pitch apart, the padding at the end of rows is untouched
+/// # Safety
+/// dptr must be pitched device memory with at least height rows of pitch bytes, width <= pitch
+pub unsafe fn memset_2d_d8(
+    dptr: sys::CUdeviceptr,
+    pitch: usize,
+    value: core::ffi::c_uchar,
+    width: usize,
+    height: usize,
+) -> Result<(), CudaError> {
+    driver_call!(cuMemsetD2D8_v2(dptr, pitch, value, width, height)).result()
+}
+
+/// # Safety
+/// same as memset_2d_d8, and stream must be a live stream in the memory's context
+pub unsafe fn memset_2d_d8_async(
+    dptr: sys::CUdeviceptr,
+    pitch: usize,
+    value: core::ffi::c_uchar,
+    width: usize,
+    height: usize,
+    stream: sys::CUstream,
+) -> Result<(), CudaError> {
+    driver_call!(cuMemsetD2D8Async(dptr, pitch, value, width, height, stream)).result()
+}
+
+//page locked host memory, the fast path for DMA to and from the device
+/// # Safety
+/// a context must be current on this thread
+pub unsafe fn malloc_host(num_bytes: usize) -> Result<*mut c_void, CudaError> {
+    let mut ptr = core::ptr::null_mut();
+    driver_call!(cuMemAllocHost_v2(&mut ptr as *mut *mut c_void, num_bytes)).result()?;
+    Ok(ptr)
+}
+
+/// # Safety
+/// ptr must come from malloc_host and not be used afterwards
+pub unsafe fn free_host(ptr: *mut c_void) -> Result<(), CudaError> {
+    driver_call!(cuMemFreeHost(ptr)).result()
+}
+
+/// # Safety
+/// dptr must come from malloc_raw or malloc_managed, nothing may use it afterwards
 pub unsafe fn free(dptr: sys::CUdeviceptr) -> Result<(), CudaError> {
-    sys::cuMemFree_v2(dptr).result()
+    driver_call!(cuMemFree_v2(dptr)).result()
 }
 
+/// # Safety
+/// dptr must be a stream ordered allocation and stream live, nothing may use dptr after the free runs
 pub unsafe fn free_async (dptr: sys::CUdeviceptr, stream: sys::CUstream) -> Result<(), CudaError> {
-    sys::cuMemFreeAsync(dptr, stream).result()
+    let fns = async_alloc::AsyncAllocFns::get().ok_or(CudaError::Driver(sys::CUresult::CUDA_ERROR_NOT_SUPPORTED))?;
+    fns.free(dptr, stream)
 }
 
+//CU_POINTER_ATTRIBUTE_MEMORY_TYPE of ptr. Plain pageable host memory is unknown to the driver,
+//which reports CUDA_ERROR_INVALID_VALUE for it; that comes back as None instead of an error.
+/// # Safety
+/// ptr may be any address, the driver only looks it up
+pub unsafe fn pointer_memory_type(ptr: *const c_void) -> Result<Option<sys::CUmemorytype>, CudaError> {
+    let mut memory_type: c_uint = 0;
+    let res = driver_call!(cuPointerGetAttribute(
+        &mut memory_type as *mut c_uint as *mut c_void,
+        sys::CUpointer_attribute::CU_POINTER_ATTRIBUTE_MEMORY_TYPE,
+        ptr as sys::CUdeviceptr,
+    ));
+    match res {
+        sys::CUresult::CUDA_SUCCESS => {}
+        sys::CUresult::CUDA_ERROR_INVALID_VALUE => return Ok(None),
+        _ => return Err(CudaError::Driver(res)),
+    }
+    Ok(memory_type_from(memory_type))
+}
+
+fn memory_type_from(raw: c_uint) -> Option<sys::CUmemorytype> {
+    use sys::CUmemorytype::*;
+    match raw {
+        1 => Some(CU_MEMORYTYPE_HOST),
+        2 => Some(CU_MEMORYTYPE_DEVICE),
+        3 => Some(CU_MEMORYTYPE_ARRAY),
+        4 => Some(CU_MEMORYTYPE_UNIFIED),
+        _ => None,
+    }
+}
+
+//what the driver knows about a pointer, see pointer_get_attributes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointerAttributes {
+    //None for memory the driver doesn't know, e.g. plain malloc'd host memory
+    pub memory_type: Option<sys::CUmemorytype>,
+    //ordinal of the device the memory belongs to, None when it isn't tied to one
+    pub device_ordinal: Option<i32>,
+    //the context the memory was allocated or registered in, None for memory the driver doesn't know
+    pub context: Option<sys::CUcontext>,
+    pub is_managed: bool,
+}
+
+//unlike pointer_memory_type this doesn't fail for pointers the driver doesn't know, they just get
+//the empty answer (memory_type None, no device, not managed). Handy to check that a pointer going
+//into InCudaMemory::from_raw_borrowed really is device memory.
+pub fn pointer_get_attributes(ptr: sys::CUdeviceptr) -> Result<PointerAttributes, CudaError> {
+    use sys::CUpointer_attribute::*;
+    let mut memory_type: c_uint = 0;
+    let mut device_ordinal: core::ffi::c_int = -1;
+    let mut context: sys::CUcontext = core::ptr::null_mut();
+    //the driver stores a bool here, zeroing all four bytes first keeps reading a c_uint correct
+    let mut is_managed: c_uint = 0;
+    let mut attributes = [
+        CU_POINTER_ATTRIBUTE_MEMORY_TYPE,
+        CU_POINTER_ATTRIBUTE_DEVICE_ORDINAL,
+        CU_POINTER_ATTRIBUTE_CONTEXT,
+        CU_POINTER_ATTRIBUTE_IS_MANAGED,
+    ];
+    let mut data = [
+        &mut memory_type as *mut c_uint as *mut c_void,
+        &mut device_ordinal as *mut core::ffi::c_int as *mut c_void,
+        &mut context as *mut sys::CUcontext as *mut c_void,
+        &mut is_managed as *mut c_uint as *mut c_void,
+    ];
+    unsafe {
+        driver_call!(cuPointerGetAttributes(
+            attributes.len() as c_uint,
+            attributes.as_mut_ptr(),
+            data.as_mut_ptr(),
+            ptr,
+        ))
+        .result()?;
+    }
+    Ok(PointerAttributes {
+        memory_type: memory_type_from(memory_type),
+        device_ordinal: (device_ordinal >= 0).then_some(device_ordinal),
+        context: (!context.is_null()).then_some(context),
+        is_managed: is_managed != 0,
+    })
+}
 
 //Memset
 
-pub unsafe fn memset_d8<T>(dptr: sys::CUdeviceptr, uc: std::ffi::c_uchar) -> Result<(), CudaError> {
-    sys::cuMemsetD8_v2(dptr, uc, size_of::<T>()).result()
+/// # Safety
+/// dptr must be device memory of at least size_of::<T>() bytes in the current context
+pub unsafe fn memset_d8<T>(dptr: sys::CUdeviceptr, uc: core::ffi::c_uchar) -> Result<(), CudaError> {
+    if size_of::<T>() == 0 {
+        return Ok(());
+    }
+    driver_call!(cuMemsetD8_v2(dptr, uc, size_of::<T>())).result()
 }
 
+/// # Safety
+/// dptr must be device memory of at least size_of::<T>() bytes, stream a live stream in its context
 pub unsafe fn memset_d8_async<T>(
     dptr: sys::CUdeviceptr, 
-    uc: std::ffi::c_uchar,
+    uc: core::ffi::c_uchar,
     stream: sys::CUstream,
 ) -> Result<(), CudaError> {
-    sys::cuMemsetD8Async(dptr, uc, size_of::<T>(), stream).result()
+    memset_d8_async_raw(dptr, uc, size_of::<T>(), stream)
+}
+
+/// # Safety
+/// dptr must be device memory of at least num_bytes, stream a live stream in its context
+pub unsafe fn memset_d8_async_raw(
+    dptr: sys::CUdeviceptr,
+    uc: core::ffi::c_uchar,
+    num_bytes: usize,
+    stream: sys::CUstream,
+) -> Result<(), CudaError> {
+    if num_bytes == 0 {
+        return Ok(());
+    }
+    driver_call!(cuMemsetD8Async(dptr, uc, num_bytes, stream)).result()
 }
 
 //host <-> device memory copy
+//the typed versions are what the safe api uses. They all delegate to the *_raw versions, which take a
+//plain pointer + byte count for memory that isn't a rust value (ffi buffers, runtime sized blobs).
+//Making a &[u8] over foreign memory just to call the typed version would be UB if it isn't initialized.
+//A 0 byte copy (a zero sized T, an empty slice) returns Ok without calling the driver, the pointers
+//may be the null sentinel borrow uses for 0 byte buffers.
+/// # Safety
+/// dst must be device memory of at least size_of::<T>() bytes in the current context
 pub unsafe fn memcpy_htod<T>(dst: sys::CUdeviceptr, src: &T) -> Result<(), CudaError> {
-    sys::cuMemcpyHtoD_v2(dst, src as *const T as *const _, size_of::<T>()).result()
+    memcpy_htod_raw(dst, src as *const T as *const _, size_of::<T>())
     //from rust reference to raw c pointer. from raw c pointer to generic void pointer. void pointer in c refers to "any type of data"
 }
 
+/// # Safety
+/// dst must be device memory and src readable host memory, both of at least num_bytes
+pub unsafe fn memcpy_htod_raw(
+    dst: sys::CUdeviceptr,
+    src: *const c_void,
+    num_bytes: usize,
+) -> Result<(), CudaError> {
+    if num_bytes == 0 {
+        return Ok(());
+    }
+    debug_assert_ctx_current();
+    driver_call!(cuMemcpyHtoD_v2(dst, src, num_bytes)).result()
+}
+
+/// # Safety
+/// dst must be device memory of at least size_of::<T>() bytes and stream live in its context, src must not move or change until the copy has run
 pub unsafe fn memcpy_htod_async<T>(
     dst: sys::CUdeviceptr,
     src: &T,
     stream: sys::CUstream,
 ) -> Result<(), CudaError> {
-    sys::cuMemcpyHtoDAsync_v2(dst, src as *const T as *const _, size_of::<T>(), stream).result()
+    memcpy_htod_async_raw(dst, src as *const T as *const _, size_of::<T>(), stream)
+}
+
+/// # Safety
+/// dst must be device memory and src readable host memory of at least num_bytes, stream live, src must stay valid until the copy has run
+pub unsafe fn memcpy_htod_async_raw(
+    dst: sys::CUdeviceptr,
+    src: *const c_void,
+    num_bytes: usize,
+    stream: sys::CUstream,
+) -> Result<(), CudaError> {
+    if num_bytes == 0 {
+        return Ok(());
+    }
+    debug_assert_ctx_current();
+    driver_call!(cuMemcpyHtoDAsync_v2(dst, src, num_bytes, stream)).result()
 }
 
+/// # Safety
+/// src must be device memory holding a valid T in the current context
 pub unsafe fn memcpy_dtoh<T>(dst: &mut T, src: sys::CUdeviceptr) -> Result<(), CudaError> {
-    sys::cuMemcpyDtoH_v2(dst as *mut T as *mut _, src, size_of::<T>()).result()
+    memcpy_dtoh_raw(dst as *mut T as *mut _, src, size_of::<T>())
+}
+
+//memcpy_dtoh into memory that doesn't hold a T yet. After Ok the bytes are the device's, so
+//assume_init is fine as long as they form a valid T.
+/// # Safety
+/// src must be device memory of at least size_of::<T>() bytes in the current context
+pub unsafe fn memcpy_dtoh_uninit<T>(dst: &mut MaybeUninit<T>, src: sys::CUdeviceptr) -> Result<(), CudaError> {
+    memcpy_dtoh_raw(dst.as_mut_ptr() as *mut _, src, size_of::<T>())
 }
 
+/// # Safety
+/// dst must be writable host memory and src device memory, both of at least num_bytes
+pub unsafe fn memcpy_dtoh_raw(
+    dst: *mut c_void,
+    src: sys::CUdeviceptr,
+    num_bytes: usize,
+) -> Result<(), CudaError> {
+    if num_bytes == 0 {
+        return Ok(());
+    }
+    debug_assert_ctx_current();
+    driver_call!(cuMemcpyDtoH_v2(dst, src, num_bytes)).result()
+}
+
+/// # Safety
+/// src must be device memory holding a valid T, stream live, dst must not be read or moved until the copy has run
 pub unsafe fn memcpy_dtoh_async<T>(
     dst: &mut T,
     src: sys::CUdeviceptr,
     stream: sys::CUstream,
 ) -> Result<(), CudaError> {
-    sys::cuMemcpyDtoHAsync_v2(dst as *mut T as *mut _, src, size_of::<T>(), stream).result()
+    memcpy_dtoh_async_raw(dst as *mut T as *mut _, src, size_of::<T>(), stream)
+}
+
+//memcpy_dtoh_uninit on a stream, dst is only written once the stream gets there
+/// # Safety
+/// src must be device memory of at least size_of::<T>() bytes, stream live, dst must not be read or moved until the copy has run
+pub unsafe fn memcpy_dtoh_async_uninit<T>(
+    dst: &mut MaybeUninit<T>,
+    src: sys::CUdeviceptr,
+    stream: sys::CUstream,
+) -> Result<(), CudaError> {
+    memcpy_dtoh_async_raw(dst.as_mut_ptr() as *mut _, src, size_of::<T>(), stream)
+}
+
+/// # Safety
+/// dst must be writable host memory and src device memory of at least num_bytes, stream live, dst must stay valid until the copy has run
+pub unsafe fn memcpy_dtoh_async_raw(
+    dst: *mut c_void,
+    src: sys::CUdeviceptr,
+    num_bytes: usize,
+    stream: sys::CUstream,
+) -> Result<(), CudaError> {
+    if num_bytes == 0 {
+        return Ok(());
+    }
+    debug_assert_ctx_current();
+    driver_call!(cuMemcpyDtoHAsync_v2(dst, src, num_bytes, stream)).result()
+}
+
+//device <-> device memory copy, there is no host reference involved so it just takes a byte count
+/// # Safety
+/// dst and src must be device memory of at least num_bytes, stream live in their context
+pub unsafe fn memcpy_dtod_async(
+    dst: sys::CUdeviceptr,
+    src: sys::CUdeviceptr,
+    num_bytes: usize,
+    stream: sys::CUstream,
+) -> Result<(), CudaError> {
+    if num_bytes == 0 {
+        return Ok(());
+    }
+    debug_assert_ctx_current();
+    driver_call!(cuMemcpyDtoDAsync_v2(dst, src, num_bytes, stream)).result()
+}
+
+//copy in whatever direction dst and src need. With unified addressing (every 64 bit platform)
+//host and device pointers share one address space, host pointers go in cast to CUdeviceptr.
+//Both pointers are looked up with pointer_get_attributes first: pageable host memory
+//(PageableHostMemory) and device memory from another context (ForeignContext) are rejected,
+//the driver would copy the first synchronously and the second only with peer access. Registered
+//and managed memory are fine, cuMemcpyAsync then picks htod, dtoh, dtod or host to host itself.
+/// # Safety
+/// dst and src must be valid for num_bytes and stay valid until the copy on stream has run
+pub unsafe fn memcpy_auto(
+    dst: sys::CUdeviceptr,
+    src: sys::CUdeviceptr,
+    num_bytes: usize,
+    stream: sys::CUstream,
+) -> Result<(), CudaError> {
+    if num_bytes == 0 {
+        return Ok(());
+    }
+    debug_assert_ctx_current();
+    let current = ctx::get_current()?;
+    for ptr in [dst, src] {
+        let attributes = pointer_get_attributes(ptr)?;
+        match attributes.memory_type {
+            None => return Err(CudaError::PageableHostMemory { ptr }),
+            Some(sys::CUmemorytype::CU_MEMORYTYPE_DEVICE) if !attributes.is_managed => {
+                if attributes.context != Some(current) {
+                    return Err(CudaError::ForeignContext { ptr });
+                }
+            }
+            Some(_) => {}
+        }
+    }
+    driver_call!(cuMemcpyAsync(dst, src, num_bytes, stream)).result()
 }
 
 //module loading
 
 pub mod module {
     use super::{sys, CudaError};
-    use std::{ffi::CString, mem::MaybeUninit};
+    use alloc::ffi::CString;
+    use core::mem::MaybeUninit;
 
     pub fn load<S: AsRef<str>>(fname: S) -> Result<sys::CUmodule, CudaError> {
         //&CStr expects data to be null terminated, but rust strings arent
@@ -176,18 +1260,44 @@ pub mod module {
         let fname_ptr = fname_cstr.as_c_str().as_ptr();
         let mut module = MaybeUninit::uninit();
         unsafe {
-            sys::cuModuleLoad(module.as_mut_ptr(), fname_ptr).result()?;
+            driver_call!(cuModuleLoad(module.as_mut_ptr(), fname_ptr)).result()?;
             Ok(module.assume_init())
         }
     }
 
-    pub unsafe fn load_data(image: *const std::ffi::c_void) -> Result<sys::CUmodule, CudaError> {
+    /// # Safety
+    /// a context must be current on this thread, image must point at a nul terminated PTX string or a cubin/fatbin
+    pub unsafe fn load_data(image: *const core::ffi::c_void) -> Result<sys::CUmodule, CudaError> {
+        let mut module = MaybeUninit::uninit();
+        driver_call!(cuModuleLoadData(module.as_mut_ptr(), image)).result()?;
+        Ok(module.assume_init())
+    }
+
+    //load_data with JIT options, values[i] goes with options[i]. Log buffer sizes are passed as
+    //the value itself cast to a pointer, that's how the driver api wants them.
+    /// # Safety
+    /// same as load_data, and options and values must be num_options long with valid values for each option
+    pub unsafe fn load_data_ex(
+        image: *const core::ffi::c_void,
+        options: &mut [sys::CUjit_option],
+        values: &mut [*mut core::ffi::c_void],
+    ) -> Result<sys::CUmodule, CudaError> {
+        debug_assert_eq!(options.len(), values.len());
         let mut module = MaybeUninit::uninit();
-        sys::cuModuleLoadData(module.as_mut_ptr(), image).result()?;
+        driver_call!(cuModuleLoadDataEx(
+            module.as_mut_ptr(),
+            image,
+            options.len() as core::ffi::c_uint,
+            options.as_mut_ptr(),
+            values.as_mut_ptr(),
+        ))
+        .result()?;
         Ok(module.assume_init())
     }
 
-    pub unsafe fn get_functions<S: AsRef<str>>(
+    /// # Safety
+    /// module must be a loaded module
+    pub unsafe fn get_function<S: AsRef<str>>(
         module: sys::CUmodule,
         name: S,
     ) -> Result<sys::CUfunction, CudaError> {
@@ -195,17 +1305,189 @@ pub mod module {
         let name_ptr = name_cstr.as_c_str().as_ptr();
         let mut func = MaybeUninit::uninit();
         unsafe {
-            sys::cuModuleGetFunction(func.as_mut_ptr(), module, name_ptr).result()?;
+            driver_call!(cuModuleGetFunction(func.as_mut_ptr(), module, name_ptr)).result()?;
             Ok(func.assume_init())
         }
     }
 
+    //device pointer and size in bytes of a __device__ or __constant__ global
+    /// # Safety
+    /// module must be a loaded module
+    pub unsafe fn get_global<S: AsRef<str>>(
+        module: sys::CUmodule,
+        name: S,
+    ) -> Result<(sys::CUdeviceptr, usize), CudaError> {
+        let name_cstr = CString::new(name.as_ref()).unwrap();
+        let mut dptr = MaybeUninit::uninit();
+        let mut bytes = 0;
+        driver_call!(cuModuleGetGlobal_v2(dptr.as_mut_ptr(), &mut bytes as *mut usize, module, name_cstr.as_ptr()))
+            .result()?;
+        Ok((dptr.assume_init(), bytes))
+    }
+
+    /// # Safety
+    /// module must be loaded, nothing may use its functions or globals afterwards
     pub unsafe fn unload(module: sys::CUmodule) -> Result<(), CudaError> {
-        unsafe { sys::cuModuleUnload(module).result() }
+        unsafe { driver_call!(cuModuleUnload(module)).result() }
+    }
+
+}
+
+pub mod graph {
+    use super::{sys, CudaError};
+    use core::mem::MaybeUninit;
+
+    //an empty graph for adding nodes to by hand
+    pub fn create() -> Result<sys::CUgraph, CudaError> {
+        let mut graph = MaybeUninit::uninit();
+        unsafe {
+            driver_call!(cuGraphCreate(graph.as_mut_ptr(), 0)).result()?;
+            Ok(graph.assume_init())
+        }
+    }
+
+    //the add_*_node functions copy params, the node starts after every node in deps
+    /// # Safety
+    /// graph must be live, deps live nodes of it, and params must match the kernel's signature
+    pub unsafe fn add_kernel_node(
+        graph: sys::CUgraph,
+        deps: &[sys::CUgraphNode],
+        params: &sys::CUDA_KERNEL_NODE_PARAMS,
+    ) -> Result<sys::CUgraphNode, CudaError> {
+        let mut node = MaybeUninit::uninit();
+        driver_call!(cuGraphAddKernelNode_v2(node.as_mut_ptr(), graph, deps.as_ptr(), deps.len(), params)).result()?;
+        Ok(node.assume_init())
     }
 
+    /// # Safety
+    /// graph must be live, deps live nodes of it, and the pointers in params valid whenever the graph runs
+    pub unsafe fn add_memcpy_node(
+        graph: sys::CUgraph,
+        deps: &[sys::CUgraphNode],
+        params: &sys::CUDA_MEMCPY3D,
+        ctx: sys::CUcontext,
+    ) -> Result<sys::CUgraphNode, CudaError> {
+        let mut node = MaybeUninit::uninit();
+        driver_call!(cuGraphAddMemcpyNode(node.as_mut_ptr(), graph, deps.as_ptr(), deps.len(), params, ctx)).result()?;
+        Ok(node.assume_init())
+    }
+
+    /// # Safety
+    /// graph must be live, deps live nodes of it, and params.dst device memory valid whenever the graph runs
+    pub unsafe fn add_memset_node(
+        graph: sys::CUgraph,
+        deps: &[sys::CUgraphNode],
+        params: &sys::CUDA_MEMSET_NODE_PARAMS,
+        ctx: sys::CUcontext,
+    ) -> Result<sys::CUgraphNode, CudaError> {
+        let mut node = MaybeUninit::uninit();
+        driver_call!(cuGraphAddMemsetNode(node.as_mut_ptr(), graph, deps.as_ptr(), deps.len(), params, ctx)).result()?;
+        Ok(node.assume_init())
+    }
+
+    /// # Safety
+    /// graph must be a live graph
+    pub unsafe fn instantiate(graph: sys::CUgraph) -> Result<sys::CUgraphExec, CudaError> {
+        let mut exec = MaybeUninit::uninit();
+        driver_call!(cuGraphInstantiateWithFlags(exec.as_mut_ptr(), graph, 0)).result()?;
+        Ok(exec.assume_init())
+    }
+
+    /// # Safety
+    /// exec must come from instantiate and stream be live, everything the graph touches valid until it has run
+    pub unsafe fn launch(exec: sys::CUgraphExec, stream: sys::CUstream) -> Result<(), CudaError> {
+        driver_call!(cuGraphLaunch(exec, stream)).result()
+    }
+
+    /// # Safety
+    /// exec must come from instantiate and not be running or used afterwards
+    pub unsafe fn exec_destroy(exec: sys::CUgraphExec) -> Result<(), CudaError> {
+        driver_call!(cuGraphExecDestroy(exec)).result()
+    }
+
+    /// # Safety
+    /// graph must be live, nothing may use it or its nodes afterwards
+    pub unsafe fn destroy(graph: sys::CUgraph) -> Result<(), CudaError> {
+        driver_call!(cuGraphDestroy(graph)).result()
+    }
 }
 
+//region of interest markers for nsys/ncu runs with --profile-from-start off (ncu) or
+//--capture-range=cudaProfilerApi (nsys). Without a profiler attached they do nothing.
+//cuProfilerStart/Stop come from cudaProfiler.h, which the bindings aren't generated from, so
+//they're looked up in the driver at runtime like param_info does.
+pub mod profiler {
+    use super::{driver_symbol, log_call, sys, CudaError};
+    use core::ffi::CStr;
+
+    type ProfilerFn = unsafe extern "C" fn() -> sys::CUresult;
+
+    pub fn start() -> Result<(), CudaError> {
+        call(c"cuProfilerStart")
+    }
+
+    pub fn stop() -> Result<(), CudaError> {
+        call(c"cuProfilerStop")
+    }
+
+    fn call(name: &CStr) -> Result<(), CudaError> {
+        let Some(pfn) = driver_symbol(name) else {
+            return Err(CudaError::Driver(sys::CUresult::CUDA_ERROR_NOT_SUPPORTED));
+        };
+        //both take nothing and return a CUresult
+        let res = unsafe { core::mem::transmute::<*mut core::ffi::c_void, ProfilerFn>(pfn)() };
+        log_call(name.to_str().unwrap_or("cuProfiler"), res);
+        res.result()
+    }
+}
+
+pub mod function {
+    use super::{sys, CudaError};
+    use core::mem::MaybeUninit;
+
+    pub use sys::CUfunction_attribute;
+
+    /// # Safety
+    /// f must come from a loaded module
+    pub unsafe fn get_attribute(f: sys::CUfunction, attrib: CUfunction_attribute) -> Result<i32, CudaError> {
+        let mut value = MaybeUninit::uninit();
+        driver_call!(cuFuncGetAttribute(value.as_mut_ptr(), attrib, f)).result()?;
+        Ok(value.assume_init())
+    }
+
+    /// # Safety
+    /// f must come from a loaded module
+    pub unsafe fn set_attribute(f: sys::CUfunction, attrib: CUfunction_attribute, value: i32) -> Result<(), CudaError> {
+        driver_call!(cuFuncSetAttribute(f, attrib, value)).result()
+    }
+
+    //(offset, size) in bytes of each kernel parameter, from cuFuncGetParamInfo. That only exists
+    //from 12.3 on, so it's looked up at runtime and older drivers get CUDA_ERROR_NOT_SUPPORTED.
+    /// # Safety
+    /// f must come from a loaded module
+    pub unsafe fn param_info(f: sys::CUfunction) -> Result<alloc::vec::Vec<(usize, usize)>, CudaError> {
+        type GetParamInfoFn = unsafe extern "C" fn(sys::CUfunction, usize, *mut usize, *mut usize) -> sys::CUresult;
+        let Some(pfn) = super::driver_symbol(c"cuFuncGetParamInfo") else {
+            return Err(CudaError::Driver(sys::CUresult::CUDA_ERROR_NOT_SUPPORTED));
+        };
+        let get_param_info = core::mem::transmute::<*mut core::ffi::c_void, GetParamInfoFn>(pfn);
+        let mut params = alloc::vec::Vec::new();
+        loop {
+            let (mut offset, mut size) = (0, 0);
+            let res = get_param_info(f, params.len(), &mut offset, &mut size);
+            super::log_call("cuFuncGetParamInfo", res);
+            match res {
+                sys::CUresult::CUDA_SUCCESS => params.push((offset, size)),
+                //asked for the index one past the last parameter
+                sys::CUresult::CUDA_ERROR_INVALID_VALUE => return Ok(params),
+                _ => return Err(CudaError::Driver(res)),
+            }
+        }
+    }
+}
+
+/// # Safety
+/// f must come from a loaded module, kernel_params must hold a pointer to a value of the right type for each kernel argument, stream live
 pub unsafe fn launch_kernel (
         f: sys::CUfunction,
         grid_dim: (c_uint, c_uint, c_uint),
@@ -214,7 +1496,8 @@ pub unsafe fn launch_kernel (
         stream: sys::CUstream,
         kerenel_params: &mut [*mut c_void]
     ) -> Result<(), CudaError> {
-        sys::cuLaunchKernel(
+        debug_assert_ctx_current();
+        driver_call!(cuLaunchKernel(
             f,
             grid_dim.0,
             grid_dim.1,
@@ -225,7 +1508,19 @@ pub unsafe fn launch_kernel (
             shared_mem_bytes,
             stream,
             kerenel_params.as_mut_ptr(),
-            std::ptr::null_mut(),
-        )
+            core::ptr::null_mut(),
+        ))
         .result()
-    }
\ No newline at end of file
+    }
+
+//cuLaunchKernel with a full CUlaunchConfig, the stream and any launch attributes are in config
+/// # Safety
+/// same as launch_kernel, config.hStream live and config.attrs valid for config.numAttrs
+pub unsafe fn launch_kernel_ex(
+    config: &sys::CUlaunchConfig,
+    f: sys::CUfunction,
+    kernel_params: &mut [*mut c_void],
+) -> Result<(), CudaError> {
+    debug_assert_ctx_current();
+    driver_call!(cuLaunchKernelEx(config, f, kernel_params.as_mut_ptr(), core::ptr::null_mut())).result()
+}
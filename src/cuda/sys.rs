@@ -43,11 +43,11 @@ pub const CU_LAUNCH_PARAM_END_AS_INT: u32 = 0;
 pub const CU_LAUNCH_PARAM_BUFFER_POINTER_AS_INT: u32 = 1;
 pub const CU_LAUNCH_PARAM_BUFFER_SIZE_AS_INT: u32 = 2;
 pub const CU_PARAM_TR_DEFAULT: i32 = -1;
-pub type cuuint32_t = ::std::os::raw::c_uint;
-pub type cuuint64_t = ::std::os::raw::c_ulonglong;
-pub type CUdeviceptr_v2 = ::std::os::raw::c_ulonglong;
+pub type cuuint32_t = ::core::ffi::c_uint;
+pub type cuuint64_t = ::core::ffi::c_ulonglong;
+pub type CUdeviceptr_v2 = ::core::ffi::c_ulonglong;
 pub type CUdeviceptr = CUdeviceptr_v2;
-pub type CUdevice_v1 = ::std::os::raw::c_int;
+pub type CUdevice_v1 = ::core::ffi::c_int;
 pub type CUdevice = CUdevice_v1;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -121,9 +121,9 @@ pub struct CUgraphicsResource_st {
     _unused: [u8; 0],
 }
 pub type CUgraphicsResource = *mut CUgraphicsResource_st;
-pub type CUtexObject_v1 = ::std::os::raw::c_ulonglong;
+pub type CUtexObject_v1 = ::core::ffi::c_ulonglong;
 pub type CUtexObject = CUtexObject_v1;
-pub type CUsurfObject_v1 = ::std::os::raw::c_ulonglong;
+pub type CUsurfObject_v1 = ::core::ffi::c_ulonglong;
 pub type CUsurfObject = CUsurfObject_v1;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -183,32 +183,32 @@ pub type CUasyncCallbackHandle = *mut CUasyncCallbackEntry_st;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUuuid_st {
-    pub bytes: [::std::os::raw::c_char; 16usize],
+    pub bytes: [::core::ffi::c_char; 16usize],
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUuuid_st"][::std::mem::size_of::<CUuuid_st>() - 16usize];
-    ["Alignment of CUuuid_st"][::std::mem::align_of::<CUuuid_st>() - 1usize];
-    ["Offset of field: CUuuid_st::bytes"][::std::mem::offset_of!(CUuuid_st, bytes) - 0usize];
+    ["Size of CUuuid_st"][::core::mem::size_of::<CUuuid_st>() - 16usize];
+    ["Alignment of CUuuid_st"][::core::mem::align_of::<CUuuid_st>() - 1usize];
+    ["Offset of field: CUuuid_st::bytes"][::core::mem::offset_of!(CUuuid_st, bytes) - 0usize];
 };
 pub type CUuuid = CUuuid_st;
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUmemFabricHandle_st {
-    pub data: [::std::os::raw::c_uchar; 64usize],
+    pub data: [::core::ffi::c_uchar; 64usize],
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUmemFabricHandle_st"][::std::mem::size_of::<CUmemFabricHandle_st>() - 64usize];
-    ["Alignment of CUmemFabricHandle_st"][::std::mem::align_of::<CUmemFabricHandle_st>() - 1usize];
+    ["Size of CUmemFabricHandle_st"][::core::mem::size_of::<CUmemFabricHandle_st>() - 64usize];
+    ["Alignment of CUmemFabricHandle_st"][::core::mem::align_of::<CUmemFabricHandle_st>() - 1usize];
     ["Offset of field: CUmemFabricHandle_st::data"]
-        [::std::mem::offset_of!(CUmemFabricHandle_st, data) - 0usize];
+        [::core::mem::offset_of!(CUmemFabricHandle_st, data) - 0usize];
 };
 impl Default for CUmemFabricHandle_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -218,20 +218,20 @@ pub type CUmemFabricHandle = CUmemFabricHandle_v1;
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUipcEventHandle_st {
-    pub reserved: [::std::os::raw::c_char; 64usize],
+    pub reserved: [::core::ffi::c_char; 64usize],
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUipcEventHandle_st"][::std::mem::size_of::<CUipcEventHandle_st>() - 64usize];
-    ["Alignment of CUipcEventHandle_st"][::std::mem::align_of::<CUipcEventHandle_st>() - 1usize];
+    ["Size of CUipcEventHandle_st"][::core::mem::size_of::<CUipcEventHandle_st>() - 64usize];
+    ["Alignment of CUipcEventHandle_st"][::core::mem::align_of::<CUipcEventHandle_st>() - 1usize];
     ["Offset of field: CUipcEventHandle_st::reserved"]
-        [::std::mem::offset_of!(CUipcEventHandle_st, reserved) - 0usize];
+        [::core::mem::offset_of!(CUipcEventHandle_st, reserved) - 0usize];
 };
 impl Default for CUipcEventHandle_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -241,20 +241,20 @@ pub type CUipcEventHandle = CUipcEventHandle_v1;
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUipcMemHandle_st {
-    pub reserved: [::std::os::raw::c_char; 64usize],
+    pub reserved: [::core::ffi::c_char; 64usize],
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUipcMemHandle_st"][::std::mem::size_of::<CUipcMemHandle_st>() - 64usize];
-    ["Alignment of CUipcMemHandle_st"][::std::mem::align_of::<CUipcMemHandle_st>() - 1usize];
+    ["Size of CUipcMemHandle_st"][::core::mem::size_of::<CUipcMemHandle_st>() - 64usize];
+    ["Alignment of CUipcMemHandle_st"][::core::mem::align_of::<CUipcMemHandle_st>() - 1usize];
     ["Offset of field: CUipcMemHandle_st::reserved"]
-        [::std::mem::offset_of!(CUipcMemHandle_st, reserved) - 0usize];
+        [::core::mem::offset_of!(CUipcMemHandle_st, reserved) - 0usize];
 };
 impl Default for CUipcMemHandle_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -385,7 +385,7 @@ pub struct CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st {
     pub address: CUdeviceptr,
     pub __bindgen_anon_1:
         CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1,
-    pub flags: ::std::os::raw::c_uint,
+    pub flags: ::core::ffi::c_uint,
     pub alias: CUdeviceptr,
 }
 #[repr(C)]
@@ -397,45 +397,45 @@ pub union CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindge
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1"]
-        [::std::mem::size_of::<
+        [::core::mem::size_of::<
             CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1,
         >() - 8usize];
     ["Alignment of CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1"]
-        [::std::mem::align_of::<
+        [::core::mem::align_of::<
             CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1,
         >() - 8usize];
-    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1::value"] [:: std :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1 , value) - 0usize] ;
-    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1::value64"] [:: std :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1 , value64) - 0usize] ;
+    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1::value"] [:: core :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1 , value) - 0usize] ;
+    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1::value64"] [:: core :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1 , value64) - 0usize] ;
 };
 impl Default for CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st"][::std::mem::size_of::<
+    ["Size of CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st"][::core::mem::size_of::<
         CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st,
     >() - 40usize];
     ["Alignment of CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st"]
-        [::std::mem::align_of::<CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st>()
+        [::core::mem::align_of::<CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st>()
             - 8usize];
-    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st::operation"] [:: std :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st , operation) - 0usize] ;
-    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st::address"][::std::mem::offset_of!(
+    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st::operation"] [:: core :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st , operation) - 0usize] ;
+    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st::address"][::core::mem::offset_of!(
         CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st,
         address
     )
         - 8usize];
-    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st::flags"][::std::mem::offset_of!(
+    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st::flags"][::core::mem::offset_of!(
         CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st,
         flags
     )
         - 24usize];
-    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st::alias"][::std::mem::offset_of!(
+    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st::alias"][::core::mem::offset_of!(
         CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st,
         alias
     )
@@ -443,9 +443,9 @@ const _: () = {
 };
 impl Default for CUstreamBatchMemOpParams_union_CUstreamMemOpWaitValueParams_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -457,7 +457,7 @@ pub struct CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st {
     pub address: CUdeviceptr,
     pub __bindgen_anon_1:
         CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1,
-    pub flags: ::std::os::raw::c_uint,
+    pub flags: ::core::ffi::c_uint,
     pub alias: CUdeviceptr,
 }
 #[repr(C)]
@@ -469,42 +469,42 @@ pub union CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindg
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1"]
-        [::std::mem::size_of::<
+        [::core::mem::size_of::<
             CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1,
         >() - 8usize];
-    ["Alignment of CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1"] [:: std :: mem :: align_of :: < CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1 > () - 8usize] ;
-    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1::value"] [:: std :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1 , value) - 0usize] ;
-    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1::value64"] [:: std :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1 , value64) - 0usize] ;
+    ["Alignment of CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1"] [:: core :: mem :: align_of :: < CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1 > () - 8usize] ;
+    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1::value"] [:: core :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1 , value) - 0usize] ;
+    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1::value64"] [:: core :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1 , value64) - 0usize] ;
 };
 impl Default for CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st"][::std::mem::size_of::<
+    ["Size of CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st"][::core::mem::size_of::<
         CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st,
     >() - 40usize];
     ["Alignment of CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st"]
-        [::std::mem::align_of::<CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st>()
+        [::core::mem::align_of::<CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st>()
             - 8usize];
-    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st::operation"] [:: std :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st , operation) - 0usize] ;
-    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st::address"][::std::mem::offset_of!(
+    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st::operation"] [:: core :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st , operation) - 0usize] ;
+    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st::address"][::core::mem::offset_of!(
         CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st,
         address
     )
         - 8usize];
-    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st::flags"][::std::mem::offset_of!(
+    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st::flags"][::core::mem::offset_of!(
         CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st,
         flags
     )
         - 24usize];
-    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st::alias"][::std::mem::offset_of!(
+    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st::alias"][::core::mem::offset_of!(
         CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st,
         alias
     )
@@ -512,9 +512,9 @@ const _: () = {
 };
 impl Default for CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -523,26 +523,26 @@ impl Default for CUstreamBatchMemOpParams_union_CUstreamMemOpWriteValueParams_st
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st {
     pub operation: CUstreamBatchMemOpType,
-    pub flags: ::std::os::raw::c_uint,
+    pub flags: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st"]
-        [::std::mem::size_of::<
+        [::core::mem::size_of::<
             CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st,
         >() - 8usize];
     ["Alignment of CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st"]
-        [::std::mem::align_of::<
+        [::core::mem::align_of::<
             CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st,
         >() - 4usize];
-    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st::operation"] [:: std :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st , operation) - 0usize] ;
-    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st::flags"] [:: std :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st , flags) - 4usize] ;
+    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st::operation"] [:: core :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st , operation) - 0usize] ;
+    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st::flags"] [:: core :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st , flags) - 4usize] ;
 };
 impl Default for CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesParams_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -551,24 +551,24 @@ impl Default for CUstreamBatchMemOpParams_union_CUstreamMemOpFlushRemoteWritesPa
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st {
     pub operation: CUstreamBatchMemOpType,
-    pub flags: ::std::os::raw::c_uint,
+    pub flags: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st"]
-        [::std::mem::size_of::<CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st>(
+        [::core::mem::size_of::<CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st>(
         ) - 8usize];
     ["Alignment of CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st"]
-        [::std::mem::align_of::<CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st>(
+        [::core::mem::align_of::<CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st>(
         ) - 4usize];
-    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st::operation"] [:: std :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st , operation) - 0usize] ;
-    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st::flags"] [:: std :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st , flags) - 4usize] ;
+    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st::operation"] [:: core :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st , operation) - 0usize] ;
+    ["Offset of field: CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st::flags"] [:: core :: mem :: offset_of ! (CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st , flags) - 4usize] ;
 };
 impl Default for CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -576,27 +576,27 @@ impl Default for CUstreamBatchMemOpParams_union_CUstreamMemOpMemoryBarrierParams
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUstreamBatchMemOpParams_union"]
-        [::std::mem::size_of::<CUstreamBatchMemOpParams_union>() - 48usize];
+        [::core::mem::size_of::<CUstreamBatchMemOpParams_union>() - 48usize];
     ["Alignment of CUstreamBatchMemOpParams_union"]
-        [::std::mem::align_of::<CUstreamBatchMemOpParams_union>() - 8usize];
+        [::core::mem::align_of::<CUstreamBatchMemOpParams_union>() - 8usize];
     ["Offset of field: CUstreamBatchMemOpParams_union::operation"]
-        [::std::mem::offset_of!(CUstreamBatchMemOpParams_union, operation) - 0usize];
+        [::core::mem::offset_of!(CUstreamBatchMemOpParams_union, operation) - 0usize];
     ["Offset of field: CUstreamBatchMemOpParams_union::waitValue"]
-        [::std::mem::offset_of!(CUstreamBatchMemOpParams_union, waitValue) - 0usize];
+        [::core::mem::offset_of!(CUstreamBatchMemOpParams_union, waitValue) - 0usize];
     ["Offset of field: CUstreamBatchMemOpParams_union::writeValue"]
-        [::std::mem::offset_of!(CUstreamBatchMemOpParams_union, writeValue) - 0usize];
+        [::core::mem::offset_of!(CUstreamBatchMemOpParams_union, writeValue) - 0usize];
     ["Offset of field: CUstreamBatchMemOpParams_union::flushRemoteWrites"]
-        [::std::mem::offset_of!(CUstreamBatchMemOpParams_union, flushRemoteWrites) - 0usize];
+        [::core::mem::offset_of!(CUstreamBatchMemOpParams_union, flushRemoteWrites) - 0usize];
     ["Offset of field: CUstreamBatchMemOpParams_union::memoryBarrier"]
-        [::std::mem::offset_of!(CUstreamBatchMemOpParams_union, memoryBarrier) - 0usize];
+        [::core::mem::offset_of!(CUstreamBatchMemOpParams_union, memoryBarrier) - 0usize];
     ["Offset of field: CUstreamBatchMemOpParams_union::pad"]
-        [::std::mem::offset_of!(CUstreamBatchMemOpParams_union, pad) - 0usize];
+        [::core::mem::offset_of!(CUstreamBatchMemOpParams_union, pad) - 0usize];
 };
 impl Default for CUstreamBatchMemOpParams_union {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -607,30 +607,30 @@ pub type CUstreamBatchMemOpParams = CUstreamBatchMemOpParams_v1;
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st {
     pub ctx: CUcontext,
-    pub count: ::std::os::raw::c_uint,
+    pub count: ::core::ffi::c_uint,
     pub paramArray: *mut CUstreamBatchMemOpParams,
-    pub flags: ::std::os::raw::c_uint,
+    pub flags: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st"]
-        [::std::mem::size_of::<CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st>() - 32usize];
+        [::core::mem::size_of::<CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st>() - 32usize];
     ["Alignment of CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st"]
-        [::std::mem::align_of::<CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st>() - 8usize];
     ["Offset of field: CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st::ctx"]
-        [::std::mem::offset_of!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st, ctx) - 0usize];
+        [::core::mem::offset_of!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st, ctx) - 0usize];
     ["Offset of field: CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st::count"]
-        [::std::mem::offset_of!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st, count) - 8usize];
+        [::core::mem::offset_of!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st, count) - 8usize];
     ["Offset of field: CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st::paramArray"]
-        [::std::mem::offset_of!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st, paramArray) - 16usize];
+        [::core::mem::offset_of!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st, paramArray) - 16usize];
     ["Offset of field: CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st::flags"]
-        [::std::mem::offset_of!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st, flags) - 24usize];
+        [::core::mem::offset_of!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st, flags) - 24usize];
 };
 impl Default for CUDA_BATCH_MEM_OP_NODE_PARAMS_v1_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -641,30 +641,30 @@ pub type CUDA_BATCH_MEM_OP_NODE_PARAMS = CUDA_BATCH_MEM_OP_NODE_PARAMS_v1;
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st {
     pub ctx: CUcontext,
-    pub count: ::std::os::raw::c_uint,
+    pub count: ::core::ffi::c_uint,
     pub paramArray: *mut CUstreamBatchMemOpParams,
-    pub flags: ::std::os::raw::c_uint,
+    pub flags: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st"]
-        [::std::mem::size_of::<CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st>() - 32usize];
+        [::core::mem::size_of::<CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st>() - 32usize];
     ["Alignment of CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st"]
-        [::std::mem::align_of::<CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st>() - 8usize];
     ["Offset of field: CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st::ctx"]
-        [::std::mem::offset_of!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st, ctx) - 0usize];
+        [::core::mem::offset_of!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st, ctx) - 0usize];
     ["Offset of field: CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st::count"]
-        [::std::mem::offset_of!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st, count) - 8usize];
+        [::core::mem::offset_of!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st, count) - 8usize];
     ["Offset of field: CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st::paramArray"]
-        [::std::mem::offset_of!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st, paramArray) - 16usize];
+        [::core::mem::offset_of!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st, paramArray) - 16usize];
     ["Offset of field: CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st::flags"]
-        [::std::mem::offset_of!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st, flags) - 24usize];
+        [::core::mem::offset_of!(CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st, flags) - 24usize];
 };
 impl Default for CUDA_BATCH_MEM_OP_NODE_PARAMS_v2_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -704,15 +704,15 @@ pub union CUasyncNotificationInfo_st__bindgen_ty_1 {
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1 {
-    pub bytesOverBudget: ::std::os::raw::c_ulonglong,
+    pub bytesOverBudget: ::core::ffi::c_ulonglong,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1"]
-        [::std::mem::size_of::<CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1>() - 8usize];
+        [::core::mem::size_of::<CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1>() - 8usize];
     ["Alignment of CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1"]
-        [::std::mem::align_of::<CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1>() - 8usize];
-    ["Offset of field: CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1::bytesOverBudget"][::std::mem::offset_of!(
+        [::core::mem::align_of::<CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1>() - 8usize];
+    ["Offset of field: CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1::bytesOverBudget"][::core::mem::offset_of!(
         CUasyncNotificationInfo_st__bindgen_ty_1__bindgen_ty_1,
         bytesOverBudget
     )
@@ -721,17 +721,17 @@ const _: () = {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUasyncNotificationInfo_st__bindgen_ty_1"]
-        [::std::mem::size_of::<CUasyncNotificationInfo_st__bindgen_ty_1>() - 8usize];
+        [::core::mem::size_of::<CUasyncNotificationInfo_st__bindgen_ty_1>() - 8usize];
     ["Alignment of CUasyncNotificationInfo_st__bindgen_ty_1"]
-        [::std::mem::align_of::<CUasyncNotificationInfo_st__bindgen_ty_1>() - 8usize];
+        [::core::mem::align_of::<CUasyncNotificationInfo_st__bindgen_ty_1>() - 8usize];
     ["Offset of field: CUasyncNotificationInfo_st__bindgen_ty_1::overBudget"]
-        [::std::mem::offset_of!(CUasyncNotificationInfo_st__bindgen_ty_1, overBudget) - 0usize];
+        [::core::mem::offset_of!(CUasyncNotificationInfo_st__bindgen_ty_1, overBudget) - 0usize];
 };
 impl Default for CUasyncNotificationInfo_st__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -739,28 +739,28 @@ impl Default for CUasyncNotificationInfo_st__bindgen_ty_1 {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUasyncNotificationInfo_st"]
-        [::std::mem::size_of::<CUasyncNotificationInfo_st>() - 16usize];
+        [::core::mem::size_of::<CUasyncNotificationInfo_st>() - 16usize];
     ["Alignment of CUasyncNotificationInfo_st"]
-        [::std::mem::align_of::<CUasyncNotificationInfo_st>() - 8usize];
+        [::core::mem::align_of::<CUasyncNotificationInfo_st>() - 8usize];
     ["Offset of field: CUasyncNotificationInfo_st::type_"]
-        [::std::mem::offset_of!(CUasyncNotificationInfo_st, type_) - 0usize];
+        [::core::mem::offset_of!(CUasyncNotificationInfo_st, type_) - 0usize];
     ["Offset of field: CUasyncNotificationInfo_st::info"]
-        [::std::mem::offset_of!(CUasyncNotificationInfo_st, info) - 8usize];
+        [::core::mem::offset_of!(CUasyncNotificationInfo_st, info) - 8usize];
 };
 impl Default for CUasyncNotificationInfo_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 pub type CUasyncNotificationInfo = CUasyncNotificationInfo_st;
-pub type CUasyncCallback = ::std::option::Option<
+pub type CUasyncCallback = ::core::option::Option<
     unsafe extern "C" fn(
         info: *mut CUasyncNotificationInfo,
-        userData: *mut ::std::os::raw::c_void,
+        userData: *mut ::core::ffi::c_void,
         callback: CUasyncCallbackHandle,
     ),
 >;
@@ -987,41 +987,41 @@ pub use self::CUdevice_attribute_enum as CUdevice_attribute;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUdevprop_st {
-    pub maxThreadsPerBlock: ::std::os::raw::c_int,
-    pub maxThreadsDim: [::std::os::raw::c_int; 3usize],
-    pub maxGridSize: [::std::os::raw::c_int; 3usize],
-    pub sharedMemPerBlock: ::std::os::raw::c_int,
-    pub totalConstantMemory: ::std::os::raw::c_int,
-    pub SIMDWidth: ::std::os::raw::c_int,
-    pub memPitch: ::std::os::raw::c_int,
-    pub regsPerBlock: ::std::os::raw::c_int,
-    pub clockRate: ::std::os::raw::c_int,
-    pub textureAlign: ::std::os::raw::c_int,
+    pub maxThreadsPerBlock: ::core::ffi::c_int,
+    pub maxThreadsDim: [::core::ffi::c_int; 3usize],
+    pub maxGridSize: [::core::ffi::c_int; 3usize],
+    pub sharedMemPerBlock: ::core::ffi::c_int,
+    pub totalConstantMemory: ::core::ffi::c_int,
+    pub SIMDWidth: ::core::ffi::c_int,
+    pub memPitch: ::core::ffi::c_int,
+    pub regsPerBlock: ::core::ffi::c_int,
+    pub clockRate: ::core::ffi::c_int,
+    pub textureAlign: ::core::ffi::c_int,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUdevprop_st"][::std::mem::size_of::<CUdevprop_st>() - 56usize];
-    ["Alignment of CUdevprop_st"][::std::mem::align_of::<CUdevprop_st>() - 4usize];
+    ["Size of CUdevprop_st"][::core::mem::size_of::<CUdevprop_st>() - 56usize];
+    ["Alignment of CUdevprop_st"][::core::mem::align_of::<CUdevprop_st>() - 4usize];
     ["Offset of field: CUdevprop_st::maxThreadsPerBlock"]
-        [::std::mem::offset_of!(CUdevprop_st, maxThreadsPerBlock) - 0usize];
+        [::core::mem::offset_of!(CUdevprop_st, maxThreadsPerBlock) - 0usize];
     ["Offset of field: CUdevprop_st::maxThreadsDim"]
-        [::std::mem::offset_of!(CUdevprop_st, maxThreadsDim) - 4usize];
+        [::core::mem::offset_of!(CUdevprop_st, maxThreadsDim) - 4usize];
     ["Offset of field: CUdevprop_st::maxGridSize"]
-        [::std::mem::offset_of!(CUdevprop_st, maxGridSize) - 16usize];
+        [::core::mem::offset_of!(CUdevprop_st, maxGridSize) - 16usize];
     ["Offset of field: CUdevprop_st::sharedMemPerBlock"]
-        [::std::mem::offset_of!(CUdevprop_st, sharedMemPerBlock) - 28usize];
+        [::core::mem::offset_of!(CUdevprop_st, sharedMemPerBlock) - 28usize];
     ["Offset of field: CUdevprop_st::totalConstantMemory"]
-        [::std::mem::offset_of!(CUdevprop_st, totalConstantMemory) - 32usize];
+        [::core::mem::offset_of!(CUdevprop_st, totalConstantMemory) - 32usize];
     ["Offset of field: CUdevprop_st::SIMDWidth"]
-        [::std::mem::offset_of!(CUdevprop_st, SIMDWidth) - 36usize];
+        [::core::mem::offset_of!(CUdevprop_st, SIMDWidth) - 36usize];
     ["Offset of field: CUdevprop_st::memPitch"]
-        [::std::mem::offset_of!(CUdevprop_st, memPitch) - 40usize];
+        [::core::mem::offset_of!(CUdevprop_st, memPitch) - 40usize];
     ["Offset of field: CUdevprop_st::regsPerBlock"]
-        [::std::mem::offset_of!(CUdevprop_st, regsPerBlock) - 44usize];
+        [::core::mem::offset_of!(CUdevprop_st, regsPerBlock) - 44usize];
     ["Offset of field: CUdevprop_st::clockRate"]
-        [::std::mem::offset_of!(CUdevprop_st, clockRate) - 48usize];
+        [::core::mem::offset_of!(CUdevprop_st, clockRate) - 48usize];
     ["Offset of field: CUdevprop_st::textureAlign"]
-        [::std::mem::offset_of!(CUdevprop_st, textureAlign) - 52usize];
+        [::core::mem::offset_of!(CUdevprop_st, textureAlign) - 52usize];
 };
 pub type CUdevprop_v1 = CUdevprop_st;
 pub type CUdevprop = CUdevprop_v1;
@@ -1287,7 +1287,7 @@ pub enum CUresourcetype_enum {
 }
 pub use self::CUresourcetype_enum as CUresourcetype;
 pub type CUhostFn =
-    ::std::option::Option<unsafe extern "C" fn(userData: *mut ::std::os::raw::c_void)>;
+    ::core::option::Option<unsafe extern "C" fn(userData: *mut ::core::ffi::c_void)>;
 #[repr(i32)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub enum CUaccessProperty_enum {
@@ -1299,7 +1299,7 @@ pub use self::CUaccessProperty_enum as CUaccessProperty;
 #[repr(C)]
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
 pub struct CUaccessPolicyWindow_st {
-    pub base_ptr: *mut ::std::os::raw::c_void,
+    pub base_ptr: *mut ::core::ffi::c_void,
     pub num_bytes: usize,
     pub hitRatio: f32,
     pub hitProp: CUaccessProperty,
@@ -1307,25 +1307,25 @@ pub struct CUaccessPolicyWindow_st {
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUaccessPolicyWindow_st"][::std::mem::size_of::<CUaccessPolicyWindow_st>() - 32usize];
+    ["Size of CUaccessPolicyWindow_st"][::core::mem::size_of::<CUaccessPolicyWindow_st>() - 32usize];
     ["Alignment of CUaccessPolicyWindow_st"]
-        [::std::mem::align_of::<CUaccessPolicyWindow_st>() - 8usize];
+        [::core::mem::align_of::<CUaccessPolicyWindow_st>() - 8usize];
     ["Offset of field: CUaccessPolicyWindow_st::base_ptr"]
-        [::std::mem::offset_of!(CUaccessPolicyWindow_st, base_ptr) - 0usize];
+        [::core::mem::offset_of!(CUaccessPolicyWindow_st, base_ptr) - 0usize];
     ["Offset of field: CUaccessPolicyWindow_st::num_bytes"]
-        [::std::mem::offset_of!(CUaccessPolicyWindow_st, num_bytes) - 8usize];
+        [::core::mem::offset_of!(CUaccessPolicyWindow_st, num_bytes) - 8usize];
     ["Offset of field: CUaccessPolicyWindow_st::hitRatio"]
-        [::std::mem::offset_of!(CUaccessPolicyWindow_st, hitRatio) - 16usize];
+        [::core::mem::offset_of!(CUaccessPolicyWindow_st, hitRatio) - 16usize];
     ["Offset of field: CUaccessPolicyWindow_st::hitProp"]
-        [::std::mem::offset_of!(CUaccessPolicyWindow_st, hitProp) - 20usize];
+        [::core::mem::offset_of!(CUaccessPolicyWindow_st, hitProp) - 20usize];
     ["Offset of field: CUaccessPolicyWindow_st::missProp"]
-        [::std::mem::offset_of!(CUaccessPolicyWindow_st, missProp) - 24usize];
+        [::core::mem::offset_of!(CUaccessPolicyWindow_st, missProp) - 24usize];
 };
 impl Default for CUaccessPolicyWindow_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -1336,48 +1336,48 @@ pub type CUaccessPolicyWindow = CUaccessPolicyWindow_v1;
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_KERNEL_NODE_PARAMS_st {
     pub func: CUfunction,
-    pub gridDimX: ::std::os::raw::c_uint,
-    pub gridDimY: ::std::os::raw::c_uint,
-    pub gridDimZ: ::std::os::raw::c_uint,
-    pub blockDimX: ::std::os::raw::c_uint,
-    pub blockDimY: ::std::os::raw::c_uint,
-    pub blockDimZ: ::std::os::raw::c_uint,
-    pub sharedMemBytes: ::std::os::raw::c_uint,
-    pub kernelParams: *mut *mut ::std::os::raw::c_void,
-    pub extra: *mut *mut ::std::os::raw::c_void,
+    pub gridDimX: ::core::ffi::c_uint,
+    pub gridDimY: ::core::ffi::c_uint,
+    pub gridDimZ: ::core::ffi::c_uint,
+    pub blockDimX: ::core::ffi::c_uint,
+    pub blockDimY: ::core::ffi::c_uint,
+    pub blockDimZ: ::core::ffi::c_uint,
+    pub sharedMemBytes: ::core::ffi::c_uint,
+    pub kernelParams: *mut *mut ::core::ffi::c_void,
+    pub extra: *mut *mut ::core::ffi::c_void,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_KERNEL_NODE_PARAMS_st"]
-        [::std::mem::size_of::<CUDA_KERNEL_NODE_PARAMS_st>() - 56usize];
+        [::core::mem::size_of::<CUDA_KERNEL_NODE_PARAMS_st>() - 56usize];
     ["Alignment of CUDA_KERNEL_NODE_PARAMS_st"]
-        [::std::mem::align_of::<CUDA_KERNEL_NODE_PARAMS_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_KERNEL_NODE_PARAMS_st>() - 8usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_st::func"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, func) - 0usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, func) - 0usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_st::gridDimX"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, gridDimX) - 8usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, gridDimX) - 8usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_st::gridDimY"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, gridDimY) - 12usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, gridDimY) - 12usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_st::gridDimZ"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, gridDimZ) - 16usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, gridDimZ) - 16usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_st::blockDimX"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, blockDimX) - 20usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, blockDimX) - 20usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_st::blockDimY"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, blockDimY) - 24usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, blockDimY) - 24usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_st::blockDimZ"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, blockDimZ) - 28usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, blockDimZ) - 28usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_st::sharedMemBytes"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, sharedMemBytes) - 32usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, sharedMemBytes) - 32usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_st::kernelParams"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, kernelParams) - 40usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, kernelParams) - 40usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_st::extra"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, extra) - 48usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_st, extra) - 48usize];
 };
 impl Default for CUDA_KERNEL_NODE_PARAMS_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -1387,54 +1387,54 @@ pub type CUDA_KERNEL_NODE_PARAMS_v1 = CUDA_KERNEL_NODE_PARAMS_st;
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_KERNEL_NODE_PARAMS_v2_st {
     pub func: CUfunction,
-    pub gridDimX: ::std::os::raw::c_uint,
-    pub gridDimY: ::std::os::raw::c_uint,
-    pub gridDimZ: ::std::os::raw::c_uint,
-    pub blockDimX: ::std::os::raw::c_uint,
-    pub blockDimY: ::std::os::raw::c_uint,
-    pub blockDimZ: ::std::os::raw::c_uint,
-    pub sharedMemBytes: ::std::os::raw::c_uint,
-    pub kernelParams: *mut *mut ::std::os::raw::c_void,
-    pub extra: *mut *mut ::std::os::raw::c_void,
+    pub gridDimX: ::core::ffi::c_uint,
+    pub gridDimY: ::core::ffi::c_uint,
+    pub gridDimZ: ::core::ffi::c_uint,
+    pub blockDimX: ::core::ffi::c_uint,
+    pub blockDimY: ::core::ffi::c_uint,
+    pub blockDimZ: ::core::ffi::c_uint,
+    pub sharedMemBytes: ::core::ffi::c_uint,
+    pub kernelParams: *mut *mut ::core::ffi::c_void,
+    pub extra: *mut *mut ::core::ffi::c_void,
     pub kern: CUkernel,
     pub ctx: CUcontext,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_KERNEL_NODE_PARAMS_v2_st"]
-        [::std::mem::size_of::<CUDA_KERNEL_NODE_PARAMS_v2_st>() - 72usize];
+        [::core::mem::size_of::<CUDA_KERNEL_NODE_PARAMS_v2_st>() - 72usize];
     ["Alignment of CUDA_KERNEL_NODE_PARAMS_v2_st"]
-        [::std::mem::align_of::<CUDA_KERNEL_NODE_PARAMS_v2_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_KERNEL_NODE_PARAMS_v2_st>() - 8usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v2_st::func"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, func) - 0usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, func) - 0usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v2_st::gridDimX"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, gridDimX) - 8usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, gridDimX) - 8usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v2_st::gridDimY"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, gridDimY) - 12usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, gridDimY) - 12usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v2_st::gridDimZ"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, gridDimZ) - 16usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, gridDimZ) - 16usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v2_st::blockDimX"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, blockDimX) - 20usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, blockDimX) - 20usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v2_st::blockDimY"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, blockDimY) - 24usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, blockDimY) - 24usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v2_st::blockDimZ"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, blockDimZ) - 28usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, blockDimZ) - 28usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v2_st::sharedMemBytes"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, sharedMemBytes) - 32usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, sharedMemBytes) - 32usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v2_st::kernelParams"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, kernelParams) - 40usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, kernelParams) - 40usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v2_st::extra"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, extra) - 48usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, extra) - 48usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v2_st::kern"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, kern) - 56usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, kern) - 56usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v2_st::ctx"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, ctx) - 64usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v2_st, ctx) - 64usize];
 };
 impl Default for CUDA_KERNEL_NODE_PARAMS_v2_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -1445,54 +1445,54 @@ pub type CUDA_KERNEL_NODE_PARAMS = CUDA_KERNEL_NODE_PARAMS_v2;
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_KERNEL_NODE_PARAMS_v3_st {
     pub func: CUfunction,
-    pub gridDimX: ::std::os::raw::c_uint,
-    pub gridDimY: ::std::os::raw::c_uint,
-    pub gridDimZ: ::std::os::raw::c_uint,
-    pub blockDimX: ::std::os::raw::c_uint,
-    pub blockDimY: ::std::os::raw::c_uint,
-    pub blockDimZ: ::std::os::raw::c_uint,
-    pub sharedMemBytes: ::std::os::raw::c_uint,
-    pub kernelParams: *mut *mut ::std::os::raw::c_void,
-    pub extra: *mut *mut ::std::os::raw::c_void,
+    pub gridDimX: ::core::ffi::c_uint,
+    pub gridDimY: ::core::ffi::c_uint,
+    pub gridDimZ: ::core::ffi::c_uint,
+    pub blockDimX: ::core::ffi::c_uint,
+    pub blockDimY: ::core::ffi::c_uint,
+    pub blockDimZ: ::core::ffi::c_uint,
+    pub sharedMemBytes: ::core::ffi::c_uint,
+    pub kernelParams: *mut *mut ::core::ffi::c_void,
+    pub extra: *mut *mut ::core::ffi::c_void,
     pub kern: CUkernel,
     pub ctx: CUcontext,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_KERNEL_NODE_PARAMS_v3_st"]
-        [::std::mem::size_of::<CUDA_KERNEL_NODE_PARAMS_v3_st>() - 72usize];
+        [::core::mem::size_of::<CUDA_KERNEL_NODE_PARAMS_v3_st>() - 72usize];
     ["Alignment of CUDA_KERNEL_NODE_PARAMS_v3_st"]
-        [::std::mem::align_of::<CUDA_KERNEL_NODE_PARAMS_v3_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_KERNEL_NODE_PARAMS_v3_st>() - 8usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v3_st::func"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, func) - 0usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, func) - 0usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v3_st::gridDimX"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, gridDimX) - 8usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, gridDimX) - 8usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v3_st::gridDimY"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, gridDimY) - 12usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, gridDimY) - 12usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v3_st::gridDimZ"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, gridDimZ) - 16usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, gridDimZ) - 16usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v3_st::blockDimX"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, blockDimX) - 20usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, blockDimX) - 20usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v3_st::blockDimY"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, blockDimY) - 24usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, blockDimY) - 24usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v3_st::blockDimZ"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, blockDimZ) - 28usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, blockDimZ) - 28usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v3_st::sharedMemBytes"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, sharedMemBytes) - 32usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, sharedMemBytes) - 32usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v3_st::kernelParams"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, kernelParams) - 40usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, kernelParams) - 40usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v3_st::extra"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, extra) - 48usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, extra) - 48usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v3_st::kern"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, kern) - 56usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, kern) - 56usize];
     ["Offset of field: CUDA_KERNEL_NODE_PARAMS_v3_st::ctx"]
-        [::std::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, ctx) - 64usize];
+        [::core::mem::offset_of!(CUDA_KERNEL_NODE_PARAMS_v3_st, ctx) - 64usize];
 };
 impl Default for CUDA_KERNEL_NODE_PARAMS_v3_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -1503,29 +1503,29 @@ pub type CUDA_KERNEL_NODE_PARAMS_v3 = CUDA_KERNEL_NODE_PARAMS_v3_st;
 pub struct CUDA_MEMSET_NODE_PARAMS_st {
     pub dst: CUdeviceptr,
     pub pitch: usize,
-    pub value: ::std::os::raw::c_uint,
-    pub elementSize: ::std::os::raw::c_uint,
+    pub value: ::core::ffi::c_uint,
+    pub elementSize: ::core::ffi::c_uint,
     pub width: usize,
     pub height: usize,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_MEMSET_NODE_PARAMS_st"]
-        [::std::mem::size_of::<CUDA_MEMSET_NODE_PARAMS_st>() - 40usize];
+        [::core::mem::size_of::<CUDA_MEMSET_NODE_PARAMS_st>() - 40usize];
     ["Alignment of CUDA_MEMSET_NODE_PARAMS_st"]
-        [::std::mem::align_of::<CUDA_MEMSET_NODE_PARAMS_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_MEMSET_NODE_PARAMS_st>() - 8usize];
     ["Offset of field: CUDA_MEMSET_NODE_PARAMS_st::dst"]
-        [::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, dst) - 0usize];
+        [::core::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, dst) - 0usize];
     ["Offset of field: CUDA_MEMSET_NODE_PARAMS_st::pitch"]
-        [::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, pitch) - 8usize];
+        [::core::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, pitch) - 8usize];
     ["Offset of field: CUDA_MEMSET_NODE_PARAMS_st::value"]
-        [::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, value) - 16usize];
+        [::core::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, value) - 16usize];
     ["Offset of field: CUDA_MEMSET_NODE_PARAMS_st::elementSize"]
-        [::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, elementSize) - 20usize];
+        [::core::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, elementSize) - 20usize];
     ["Offset of field: CUDA_MEMSET_NODE_PARAMS_st::width"]
-        [::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, width) - 24usize];
+        [::core::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, width) - 24usize];
     ["Offset of field: CUDA_MEMSET_NODE_PARAMS_st::height"]
-        [::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, height) - 32usize];
+        [::core::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_st, height) - 32usize];
 };
 pub type CUDA_MEMSET_NODE_PARAMS_v1 = CUDA_MEMSET_NODE_PARAMS_st;
 pub type CUDA_MEMSET_NODE_PARAMS = CUDA_MEMSET_NODE_PARAMS_v1;
@@ -1534,8 +1534,8 @@ pub type CUDA_MEMSET_NODE_PARAMS = CUDA_MEMSET_NODE_PARAMS_v1;
 pub struct CUDA_MEMSET_NODE_PARAMS_v2_st {
     pub dst: CUdeviceptr,
     pub pitch: usize,
-    pub value: ::std::os::raw::c_uint,
-    pub elementSize: ::std::os::raw::c_uint,
+    pub value: ::core::ffi::c_uint,
+    pub elementSize: ::core::ffi::c_uint,
     pub width: usize,
     pub height: usize,
     pub ctx: CUcontext,
@@ -1543,29 +1543,29 @@ pub struct CUDA_MEMSET_NODE_PARAMS_v2_st {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_MEMSET_NODE_PARAMS_v2_st"]
-        [::std::mem::size_of::<CUDA_MEMSET_NODE_PARAMS_v2_st>() - 48usize];
+        [::core::mem::size_of::<CUDA_MEMSET_NODE_PARAMS_v2_st>() - 48usize];
     ["Alignment of CUDA_MEMSET_NODE_PARAMS_v2_st"]
-        [::std::mem::align_of::<CUDA_MEMSET_NODE_PARAMS_v2_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_MEMSET_NODE_PARAMS_v2_st>() - 8usize];
     ["Offset of field: CUDA_MEMSET_NODE_PARAMS_v2_st::dst"]
-        [::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_v2_st, dst) - 0usize];
+        [::core::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_v2_st, dst) - 0usize];
     ["Offset of field: CUDA_MEMSET_NODE_PARAMS_v2_st::pitch"]
-        [::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_v2_st, pitch) - 8usize];
+        [::core::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_v2_st, pitch) - 8usize];
     ["Offset of field: CUDA_MEMSET_NODE_PARAMS_v2_st::value"]
-        [::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_v2_st, value) - 16usize];
+        [::core::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_v2_st, value) - 16usize];
     ["Offset of field: CUDA_MEMSET_NODE_PARAMS_v2_st::elementSize"]
-        [::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_v2_st, elementSize) - 20usize];
+        [::core::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_v2_st, elementSize) - 20usize];
     ["Offset of field: CUDA_MEMSET_NODE_PARAMS_v2_st::width"]
-        [::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_v2_st, width) - 24usize];
+        [::core::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_v2_st, width) - 24usize];
     ["Offset of field: CUDA_MEMSET_NODE_PARAMS_v2_st::height"]
-        [::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_v2_st, height) - 32usize];
+        [::core::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_v2_st, height) - 32usize];
     ["Offset of field: CUDA_MEMSET_NODE_PARAMS_v2_st::ctx"]
-        [::std::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_v2_st, ctx) - 40usize];
+        [::core::mem::offset_of!(CUDA_MEMSET_NODE_PARAMS_v2_st, ctx) - 40usize];
 };
 impl Default for CUDA_MEMSET_NODE_PARAMS_v2_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -1575,24 +1575,24 @@ pub type CUDA_MEMSET_NODE_PARAMS_v2 = CUDA_MEMSET_NODE_PARAMS_v2_st;
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_HOST_NODE_PARAMS_st {
     pub fn_: CUhostFn,
-    pub userData: *mut ::std::os::raw::c_void,
+    pub userData: *mut ::core::ffi::c_void,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_HOST_NODE_PARAMS_st"]
-        [::std::mem::size_of::<CUDA_HOST_NODE_PARAMS_st>() - 16usize];
+        [::core::mem::size_of::<CUDA_HOST_NODE_PARAMS_st>() - 16usize];
     ["Alignment of CUDA_HOST_NODE_PARAMS_st"]
-        [::std::mem::align_of::<CUDA_HOST_NODE_PARAMS_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_HOST_NODE_PARAMS_st>() - 8usize];
     ["Offset of field: CUDA_HOST_NODE_PARAMS_st::fn_"]
-        [::std::mem::offset_of!(CUDA_HOST_NODE_PARAMS_st, fn_) - 0usize];
+        [::core::mem::offset_of!(CUDA_HOST_NODE_PARAMS_st, fn_) - 0usize];
     ["Offset of field: CUDA_HOST_NODE_PARAMS_st::userData"]
-        [::std::mem::offset_of!(CUDA_HOST_NODE_PARAMS_st, userData) - 8usize];
+        [::core::mem::offset_of!(CUDA_HOST_NODE_PARAMS_st, userData) - 8usize];
 };
 impl Default for CUDA_HOST_NODE_PARAMS_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -1603,24 +1603,24 @@ pub type CUDA_HOST_NODE_PARAMS = CUDA_HOST_NODE_PARAMS_v1;
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_HOST_NODE_PARAMS_v2_st {
     pub fn_: CUhostFn,
-    pub userData: *mut ::std::os::raw::c_void,
+    pub userData: *mut ::core::ffi::c_void,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_HOST_NODE_PARAMS_v2_st"]
-        [::std::mem::size_of::<CUDA_HOST_NODE_PARAMS_v2_st>() - 16usize];
+        [::core::mem::size_of::<CUDA_HOST_NODE_PARAMS_v2_st>() - 16usize];
     ["Alignment of CUDA_HOST_NODE_PARAMS_v2_st"]
-        [::std::mem::align_of::<CUDA_HOST_NODE_PARAMS_v2_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_HOST_NODE_PARAMS_v2_st>() - 8usize];
     ["Offset of field: CUDA_HOST_NODE_PARAMS_v2_st::fn_"]
-        [::std::mem::offset_of!(CUDA_HOST_NODE_PARAMS_v2_st, fn_) - 0usize];
+        [::core::mem::offset_of!(CUDA_HOST_NODE_PARAMS_v2_st, fn_) - 0usize];
     ["Offset of field: CUDA_HOST_NODE_PARAMS_v2_st::userData"]
-        [::std::mem::offset_of!(CUDA_HOST_NODE_PARAMS_v2_st, userData) - 8usize];
+        [::core::mem::offset_of!(CUDA_HOST_NODE_PARAMS_v2_st, userData) - 8usize];
 };
 impl Default for CUDA_HOST_NODE_PARAMS_v2_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -1638,32 +1638,32 @@ pub use self::CUgraphConditionalNodeType_enum as CUgraphConditionalNodeType;
 pub struct CUDA_CONDITIONAL_NODE_PARAMS {
     pub handle: CUgraphConditionalHandle,
     pub type_: CUgraphConditionalNodeType,
-    pub size: ::std::os::raw::c_uint,
+    pub size: ::core::ffi::c_uint,
     pub phGraph_out: *mut CUgraph,
     pub ctx: CUcontext,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_CONDITIONAL_NODE_PARAMS"]
-        [::std::mem::size_of::<CUDA_CONDITIONAL_NODE_PARAMS>() - 32usize];
+        [::core::mem::size_of::<CUDA_CONDITIONAL_NODE_PARAMS>() - 32usize];
     ["Alignment of CUDA_CONDITIONAL_NODE_PARAMS"]
-        [::std::mem::align_of::<CUDA_CONDITIONAL_NODE_PARAMS>() - 8usize];
+        [::core::mem::align_of::<CUDA_CONDITIONAL_NODE_PARAMS>() - 8usize];
     ["Offset of field: CUDA_CONDITIONAL_NODE_PARAMS::handle"]
-        [::std::mem::offset_of!(CUDA_CONDITIONAL_NODE_PARAMS, handle) - 0usize];
+        [::core::mem::offset_of!(CUDA_CONDITIONAL_NODE_PARAMS, handle) - 0usize];
     ["Offset of field: CUDA_CONDITIONAL_NODE_PARAMS::type_"]
-        [::std::mem::offset_of!(CUDA_CONDITIONAL_NODE_PARAMS, type_) - 8usize];
+        [::core::mem::offset_of!(CUDA_CONDITIONAL_NODE_PARAMS, type_) - 8usize];
     ["Offset of field: CUDA_CONDITIONAL_NODE_PARAMS::size"]
-        [::std::mem::offset_of!(CUDA_CONDITIONAL_NODE_PARAMS, size) - 12usize];
+        [::core::mem::offset_of!(CUDA_CONDITIONAL_NODE_PARAMS, size) - 12usize];
     ["Offset of field: CUDA_CONDITIONAL_NODE_PARAMS::phGraph_out"]
-        [::std::mem::offset_of!(CUDA_CONDITIONAL_NODE_PARAMS, phGraph_out) - 16usize];
+        [::core::mem::offset_of!(CUDA_CONDITIONAL_NODE_PARAMS, phGraph_out) - 16usize];
     ["Offset of field: CUDA_CONDITIONAL_NODE_PARAMS::ctx"]
-        [::std::mem::offset_of!(CUDA_CONDITIONAL_NODE_PARAMS, ctx) - 24usize];
+        [::core::mem::offset_of!(CUDA_CONDITIONAL_NODE_PARAMS, ctx) - 24usize];
 };
 impl Default for CUDA_CONDITIONAL_NODE_PARAMS {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -1697,23 +1697,23 @@ pub use self::CUgraphDependencyType_enum as CUgraphDependencyType;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUgraphEdgeData_st {
-    pub from_port: ::std::os::raw::c_uchar,
-    pub to_port: ::std::os::raw::c_uchar,
-    pub type_: ::std::os::raw::c_uchar,
-    pub reserved: [::std::os::raw::c_uchar; 5usize],
+    pub from_port: ::core::ffi::c_uchar,
+    pub to_port: ::core::ffi::c_uchar,
+    pub type_: ::core::ffi::c_uchar,
+    pub reserved: [::core::ffi::c_uchar; 5usize],
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUgraphEdgeData_st"][::std::mem::size_of::<CUgraphEdgeData_st>() - 8usize];
-    ["Alignment of CUgraphEdgeData_st"][::std::mem::align_of::<CUgraphEdgeData_st>() - 1usize];
+    ["Size of CUgraphEdgeData_st"][::core::mem::size_of::<CUgraphEdgeData_st>() - 8usize];
+    ["Alignment of CUgraphEdgeData_st"][::core::mem::align_of::<CUgraphEdgeData_st>() - 1usize];
     ["Offset of field: CUgraphEdgeData_st::from_port"]
-        [::std::mem::offset_of!(CUgraphEdgeData_st, from_port) - 0usize];
+        [::core::mem::offset_of!(CUgraphEdgeData_st, from_port) - 0usize];
     ["Offset of field: CUgraphEdgeData_st::to_port"]
-        [::std::mem::offset_of!(CUgraphEdgeData_st, to_port) - 1usize];
+        [::core::mem::offset_of!(CUgraphEdgeData_st, to_port) - 1usize];
     ["Offset of field: CUgraphEdgeData_st::type_"]
-        [::std::mem::offset_of!(CUgraphEdgeData_st, type_) - 2usize];
+        [::core::mem::offset_of!(CUgraphEdgeData_st, type_) - 2usize];
     ["Offset of field: CUgraphEdgeData_st::reserved"]
-        [::std::mem::offset_of!(CUgraphEdgeData_st, reserved) - 3usize];
+        [::core::mem::offset_of!(CUgraphEdgeData_st, reserved) - 3usize];
 };
 pub type CUgraphEdgeData = CUgraphEdgeData_st;
 #[repr(i32)]
@@ -1737,23 +1737,23 @@ pub struct CUDA_GRAPH_INSTANTIATE_PARAMS_st {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_GRAPH_INSTANTIATE_PARAMS_st"]
-        [::std::mem::size_of::<CUDA_GRAPH_INSTANTIATE_PARAMS_st>() - 32usize];
+        [::core::mem::size_of::<CUDA_GRAPH_INSTANTIATE_PARAMS_st>() - 32usize];
     ["Alignment of CUDA_GRAPH_INSTANTIATE_PARAMS_st"]
-        [::std::mem::align_of::<CUDA_GRAPH_INSTANTIATE_PARAMS_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_GRAPH_INSTANTIATE_PARAMS_st>() - 8usize];
     ["Offset of field: CUDA_GRAPH_INSTANTIATE_PARAMS_st::flags"]
-        [::std::mem::offset_of!(CUDA_GRAPH_INSTANTIATE_PARAMS_st, flags) - 0usize];
+        [::core::mem::offset_of!(CUDA_GRAPH_INSTANTIATE_PARAMS_st, flags) - 0usize];
     ["Offset of field: CUDA_GRAPH_INSTANTIATE_PARAMS_st::hUploadStream"]
-        [::std::mem::offset_of!(CUDA_GRAPH_INSTANTIATE_PARAMS_st, hUploadStream) - 8usize];
+        [::core::mem::offset_of!(CUDA_GRAPH_INSTANTIATE_PARAMS_st, hUploadStream) - 8usize];
     ["Offset of field: CUDA_GRAPH_INSTANTIATE_PARAMS_st::hErrNode_out"]
-        [::std::mem::offset_of!(CUDA_GRAPH_INSTANTIATE_PARAMS_st, hErrNode_out) - 16usize];
+        [::core::mem::offset_of!(CUDA_GRAPH_INSTANTIATE_PARAMS_st, hErrNode_out) - 16usize];
     ["Offset of field: CUDA_GRAPH_INSTANTIATE_PARAMS_st::result_out"]
-        [::std::mem::offset_of!(CUDA_GRAPH_INSTANTIATE_PARAMS_st, result_out) - 24usize];
+        [::core::mem::offset_of!(CUDA_GRAPH_INSTANTIATE_PARAMS_st, result_out) - 24usize];
 };
 impl Default for CUDA_GRAPH_INSTANTIATE_PARAMS_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -1786,19 +1786,19 @@ pub use self::CUlaunchMemSyncDomain_enum as CUlaunchMemSyncDomain;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUlaunchMemSyncDomainMap_st {
-    pub default_: ::std::os::raw::c_uchar,
-    pub remote: ::std::os::raw::c_uchar,
+    pub default_: ::core::ffi::c_uchar,
+    pub remote: ::core::ffi::c_uchar,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUlaunchMemSyncDomainMap_st"]
-        [::std::mem::size_of::<CUlaunchMemSyncDomainMap_st>() - 2usize];
+        [::core::mem::size_of::<CUlaunchMemSyncDomainMap_st>() - 2usize];
     ["Alignment of CUlaunchMemSyncDomainMap_st"]
-        [::std::mem::align_of::<CUlaunchMemSyncDomainMap_st>() - 1usize];
+        [::core::mem::align_of::<CUlaunchMemSyncDomainMap_st>() - 1usize];
     ["Offset of field: CUlaunchMemSyncDomainMap_st::default_"]
-        [::std::mem::offset_of!(CUlaunchMemSyncDomainMap_st, default_) - 0usize];
+        [::core::mem::offset_of!(CUlaunchMemSyncDomainMap_st, default_) - 0usize];
     ["Offset of field: CUlaunchMemSyncDomainMap_st::remote"]
-        [::std::mem::offset_of!(CUlaunchMemSyncDomainMap_st, remote) - 1usize];
+        [::core::mem::offset_of!(CUlaunchMemSyncDomainMap_st, remote) - 1usize];
 };
 pub type CUlaunchMemSyncDomainMap = CUlaunchMemSyncDomainMap_st;
 #[repr(i32)]
@@ -1822,16 +1822,16 @@ pub use self::CUlaunchAttributeID_enum as CUlaunchAttributeID;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union CUlaunchAttributeValue_union {
-    pub pad: [::std::os::raw::c_char; 64usize],
+    pub pad: [::core::ffi::c_char; 64usize],
     pub accessPolicyWindow: CUaccessPolicyWindow,
-    pub cooperative: ::std::os::raw::c_int,
+    pub cooperative: ::core::ffi::c_int,
     pub syncPolicy: CUsynchronizationPolicy,
     pub clusterDim: CUlaunchAttributeValue_union__bindgen_ty_1,
     pub clusterSchedulingPolicyPreference: CUclusterSchedulingPolicy,
-    pub programmaticStreamSerializationAllowed: ::std::os::raw::c_int,
+    pub programmaticStreamSerializationAllowed: ::core::ffi::c_int,
     pub programmaticEvent: CUlaunchAttributeValue_union__bindgen_ty_2,
     pub launchCompletionEvent: CUlaunchAttributeValue_union__bindgen_ty_3,
-    pub priority: ::std::os::raw::c_int,
+    pub priority: ::core::ffi::c_int,
     pub memSyncDomainMap: CUlaunchMemSyncDomainMap,
     pub memSyncDomain: CUlaunchMemSyncDomain,
     pub deviceUpdatableKernelNode: CUlaunchAttributeValue_union__bindgen_ty_4,
@@ -1839,41 +1839,41 @@ pub union CUlaunchAttributeValue_union {
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUlaunchAttributeValue_union__bindgen_ty_1 {
-    pub x: ::std::os::raw::c_uint,
-    pub y: ::std::os::raw::c_uint,
-    pub z: ::std::os::raw::c_uint,
+    pub x: ::core::ffi::c_uint,
+    pub y: ::core::ffi::c_uint,
+    pub z: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUlaunchAttributeValue_union__bindgen_ty_1"]
-        [::std::mem::size_of::<CUlaunchAttributeValue_union__bindgen_ty_1>() - 12usize];
+        [::core::mem::size_of::<CUlaunchAttributeValue_union__bindgen_ty_1>() - 12usize];
     ["Alignment of CUlaunchAttributeValue_union__bindgen_ty_1"]
-        [::std::mem::align_of::<CUlaunchAttributeValue_union__bindgen_ty_1>() - 4usize];
+        [::core::mem::align_of::<CUlaunchAttributeValue_union__bindgen_ty_1>() - 4usize];
     ["Offset of field: CUlaunchAttributeValue_union__bindgen_ty_1::x"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union__bindgen_ty_1, x) - 0usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union__bindgen_ty_1, x) - 0usize];
     ["Offset of field: CUlaunchAttributeValue_union__bindgen_ty_1::y"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union__bindgen_ty_1, y) - 4usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union__bindgen_ty_1, y) - 4usize];
     ["Offset of field: CUlaunchAttributeValue_union__bindgen_ty_1::z"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union__bindgen_ty_1, z) - 8usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union__bindgen_ty_1, z) - 8usize];
 };
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUlaunchAttributeValue_union__bindgen_ty_2 {
     pub event: CUevent,
-    pub flags: ::std::os::raw::c_int,
-    pub triggerAtBlockStart: ::std::os::raw::c_int,
+    pub flags: ::core::ffi::c_int,
+    pub triggerAtBlockStart: ::core::ffi::c_int,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUlaunchAttributeValue_union__bindgen_ty_2"]
-        [::std::mem::size_of::<CUlaunchAttributeValue_union__bindgen_ty_2>() - 16usize];
+        [::core::mem::size_of::<CUlaunchAttributeValue_union__bindgen_ty_2>() - 16usize];
     ["Alignment of CUlaunchAttributeValue_union__bindgen_ty_2"]
-        [::std::mem::align_of::<CUlaunchAttributeValue_union__bindgen_ty_2>() - 8usize];
+        [::core::mem::align_of::<CUlaunchAttributeValue_union__bindgen_ty_2>() - 8usize];
     ["Offset of field: CUlaunchAttributeValue_union__bindgen_ty_2::event"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union__bindgen_ty_2, event) - 0usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union__bindgen_ty_2, event) - 0usize];
     ["Offset of field: CUlaunchAttributeValue_union__bindgen_ty_2::flags"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union__bindgen_ty_2, flags) - 8usize];
-    ["Offset of field: CUlaunchAttributeValue_union__bindgen_ty_2::triggerAtBlockStart"][::std::mem::offset_of!(
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union__bindgen_ty_2, flags) - 8usize];
+    ["Offset of field: CUlaunchAttributeValue_union__bindgen_ty_2::triggerAtBlockStart"][::core::mem::offset_of!(
         CUlaunchAttributeValue_union__bindgen_ty_2,
         triggerAtBlockStart
     )
@@ -1881,9 +1881,9 @@ const _: () = {
 };
 impl Default for CUlaunchAttributeValue_union__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -1892,24 +1892,24 @@ impl Default for CUlaunchAttributeValue_union__bindgen_ty_2 {
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUlaunchAttributeValue_union__bindgen_ty_3 {
     pub event: CUevent,
-    pub flags: ::std::os::raw::c_int,
+    pub flags: ::core::ffi::c_int,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUlaunchAttributeValue_union__bindgen_ty_3"]
-        [::std::mem::size_of::<CUlaunchAttributeValue_union__bindgen_ty_3>() - 16usize];
+        [::core::mem::size_of::<CUlaunchAttributeValue_union__bindgen_ty_3>() - 16usize];
     ["Alignment of CUlaunchAttributeValue_union__bindgen_ty_3"]
-        [::std::mem::align_of::<CUlaunchAttributeValue_union__bindgen_ty_3>() - 8usize];
+        [::core::mem::align_of::<CUlaunchAttributeValue_union__bindgen_ty_3>() - 8usize];
     ["Offset of field: CUlaunchAttributeValue_union__bindgen_ty_3::event"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union__bindgen_ty_3, event) - 0usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union__bindgen_ty_3, event) - 0usize];
     ["Offset of field: CUlaunchAttributeValue_union__bindgen_ty_3::flags"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union__bindgen_ty_3, flags) - 8usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union__bindgen_ty_3, flags) - 8usize];
 };
 impl Default for CUlaunchAttributeValue_union__bindgen_ty_3 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -1917,27 +1917,27 @@ impl Default for CUlaunchAttributeValue_union__bindgen_ty_3 {
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUlaunchAttributeValue_union__bindgen_ty_4 {
-    pub deviceUpdatable: ::std::os::raw::c_int,
+    pub deviceUpdatable: ::core::ffi::c_int,
     pub devNode: CUgraphDeviceNode,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUlaunchAttributeValue_union__bindgen_ty_4"]
-        [::std::mem::size_of::<CUlaunchAttributeValue_union__bindgen_ty_4>() - 16usize];
+        [::core::mem::size_of::<CUlaunchAttributeValue_union__bindgen_ty_4>() - 16usize];
     ["Alignment of CUlaunchAttributeValue_union__bindgen_ty_4"]
-        [::std::mem::align_of::<CUlaunchAttributeValue_union__bindgen_ty_4>() - 8usize];
-    ["Offset of field: CUlaunchAttributeValue_union__bindgen_ty_4::deviceUpdatable"][::std::mem::offset_of!(
+        [::core::mem::align_of::<CUlaunchAttributeValue_union__bindgen_ty_4>() - 8usize];
+    ["Offset of field: CUlaunchAttributeValue_union__bindgen_ty_4::deviceUpdatable"][::core::mem::offset_of!(
         CUlaunchAttributeValue_union__bindgen_ty_4,
         deviceUpdatable
     ) - 0usize];
     ["Offset of field: CUlaunchAttributeValue_union__bindgen_ty_4::devNode"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union__bindgen_ty_4, devNode) - 8usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union__bindgen_ty_4, devNode) - 8usize];
 };
 impl Default for CUlaunchAttributeValue_union__bindgen_ty_4 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -1945,47 +1945,47 @@ impl Default for CUlaunchAttributeValue_union__bindgen_ty_4 {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUlaunchAttributeValue_union"]
-        [::std::mem::size_of::<CUlaunchAttributeValue_union>() - 64usize];
+        [::core::mem::size_of::<CUlaunchAttributeValue_union>() - 64usize];
     ["Alignment of CUlaunchAttributeValue_union"]
-        [::std::mem::align_of::<CUlaunchAttributeValue_union>() - 8usize];
+        [::core::mem::align_of::<CUlaunchAttributeValue_union>() - 8usize];
     ["Offset of field: CUlaunchAttributeValue_union::pad"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union, pad) - 0usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union, pad) - 0usize];
     ["Offset of field: CUlaunchAttributeValue_union::accessPolicyWindow"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union, accessPolicyWindow) - 0usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union, accessPolicyWindow) - 0usize];
     ["Offset of field: CUlaunchAttributeValue_union::cooperative"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union, cooperative) - 0usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union, cooperative) - 0usize];
     ["Offset of field: CUlaunchAttributeValue_union::syncPolicy"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union, syncPolicy) - 0usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union, syncPolicy) - 0usize];
     ["Offset of field: CUlaunchAttributeValue_union::clusterDim"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union, clusterDim) - 0usize];
-    ["Offset of field: CUlaunchAttributeValue_union::clusterSchedulingPolicyPreference"][::std::mem::offset_of!(
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union, clusterDim) - 0usize];
+    ["Offset of field: CUlaunchAttributeValue_union::clusterSchedulingPolicyPreference"][::core::mem::offset_of!(
         CUlaunchAttributeValue_union,
         clusterSchedulingPolicyPreference
     )
         - 0usize];
-    ["Offset of field: CUlaunchAttributeValue_union::programmaticStreamSerializationAllowed"][::std::mem::offset_of!(
+    ["Offset of field: CUlaunchAttributeValue_union::programmaticStreamSerializationAllowed"][::core::mem::offset_of!(
         CUlaunchAttributeValue_union,
         programmaticStreamSerializationAllowed
     )
         - 0usize];
     ["Offset of field: CUlaunchAttributeValue_union::programmaticEvent"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union, programmaticEvent) - 0usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union, programmaticEvent) - 0usize];
     ["Offset of field: CUlaunchAttributeValue_union::launchCompletionEvent"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union, launchCompletionEvent) - 0usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union, launchCompletionEvent) - 0usize];
     ["Offset of field: CUlaunchAttributeValue_union::priority"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union, priority) - 0usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union, priority) - 0usize];
     ["Offset of field: CUlaunchAttributeValue_union::memSyncDomainMap"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union, memSyncDomainMap) - 0usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union, memSyncDomainMap) - 0usize];
     ["Offset of field: CUlaunchAttributeValue_union::memSyncDomain"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union, memSyncDomain) - 0usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union, memSyncDomain) - 0usize];
     ["Offset of field: CUlaunchAttributeValue_union::deviceUpdatableKernelNode"]
-        [::std::mem::offset_of!(CUlaunchAttributeValue_union, deviceUpdatableKernelNode) - 0usize];
+        [::core::mem::offset_of!(CUlaunchAttributeValue_union, deviceUpdatableKernelNode) - 0usize];
 };
 impl Default for CUlaunchAttributeValue_union {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -1995,25 +1995,25 @@ pub type CUlaunchAttributeValue = CUlaunchAttributeValue_union;
 #[derive(Copy, Clone)]
 pub struct CUlaunchAttribute_st {
     pub id: CUlaunchAttributeID,
-    pub pad: [::std::os::raw::c_char; 4usize],
+    pub pad: [::core::ffi::c_char; 4usize],
     pub value: CUlaunchAttributeValue,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUlaunchAttribute_st"][::std::mem::size_of::<CUlaunchAttribute_st>() - 72usize];
-    ["Alignment of CUlaunchAttribute_st"][::std::mem::align_of::<CUlaunchAttribute_st>() - 8usize];
+    ["Size of CUlaunchAttribute_st"][::core::mem::size_of::<CUlaunchAttribute_st>() - 72usize];
+    ["Alignment of CUlaunchAttribute_st"][::core::mem::align_of::<CUlaunchAttribute_st>() - 8usize];
     ["Offset of field: CUlaunchAttribute_st::id"]
-        [::std::mem::offset_of!(CUlaunchAttribute_st, id) - 0usize];
+        [::core::mem::offset_of!(CUlaunchAttribute_st, id) - 0usize];
     ["Offset of field: CUlaunchAttribute_st::pad"]
-        [::std::mem::offset_of!(CUlaunchAttribute_st, pad) - 4usize];
+        [::core::mem::offset_of!(CUlaunchAttribute_st, pad) - 4usize];
     ["Offset of field: CUlaunchAttribute_st::value"]
-        [::std::mem::offset_of!(CUlaunchAttribute_st, value) - 8usize];
+        [::core::mem::offset_of!(CUlaunchAttribute_st, value) - 8usize];
 };
 impl Default for CUlaunchAttribute_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -2022,47 +2022,47 @@ pub type CUlaunchAttribute = CUlaunchAttribute_st;
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUlaunchConfig_st {
-    pub gridDimX: ::std::os::raw::c_uint,
-    pub gridDimY: ::std::os::raw::c_uint,
-    pub gridDimZ: ::std::os::raw::c_uint,
-    pub blockDimX: ::std::os::raw::c_uint,
-    pub blockDimY: ::std::os::raw::c_uint,
-    pub blockDimZ: ::std::os::raw::c_uint,
-    pub sharedMemBytes: ::std::os::raw::c_uint,
+    pub gridDimX: ::core::ffi::c_uint,
+    pub gridDimY: ::core::ffi::c_uint,
+    pub gridDimZ: ::core::ffi::c_uint,
+    pub blockDimX: ::core::ffi::c_uint,
+    pub blockDimY: ::core::ffi::c_uint,
+    pub blockDimZ: ::core::ffi::c_uint,
+    pub sharedMemBytes: ::core::ffi::c_uint,
     pub hStream: CUstream,
     pub attrs: *mut CUlaunchAttribute,
-    pub numAttrs: ::std::os::raw::c_uint,
+    pub numAttrs: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUlaunchConfig_st"][::std::mem::size_of::<CUlaunchConfig_st>() - 56usize];
-    ["Alignment of CUlaunchConfig_st"][::std::mem::align_of::<CUlaunchConfig_st>() - 8usize];
+    ["Size of CUlaunchConfig_st"][::core::mem::size_of::<CUlaunchConfig_st>() - 56usize];
+    ["Alignment of CUlaunchConfig_st"][::core::mem::align_of::<CUlaunchConfig_st>() - 8usize];
     ["Offset of field: CUlaunchConfig_st::gridDimX"]
-        [::std::mem::offset_of!(CUlaunchConfig_st, gridDimX) - 0usize];
+        [::core::mem::offset_of!(CUlaunchConfig_st, gridDimX) - 0usize];
     ["Offset of field: CUlaunchConfig_st::gridDimY"]
-        [::std::mem::offset_of!(CUlaunchConfig_st, gridDimY) - 4usize];
+        [::core::mem::offset_of!(CUlaunchConfig_st, gridDimY) - 4usize];
     ["Offset of field: CUlaunchConfig_st::gridDimZ"]
-        [::std::mem::offset_of!(CUlaunchConfig_st, gridDimZ) - 8usize];
+        [::core::mem::offset_of!(CUlaunchConfig_st, gridDimZ) - 8usize];
     ["Offset of field: CUlaunchConfig_st::blockDimX"]
-        [::std::mem::offset_of!(CUlaunchConfig_st, blockDimX) - 12usize];
+        [::core::mem::offset_of!(CUlaunchConfig_st, blockDimX) - 12usize];
     ["Offset of field: CUlaunchConfig_st::blockDimY"]
-        [::std::mem::offset_of!(CUlaunchConfig_st, blockDimY) - 16usize];
+        [::core::mem::offset_of!(CUlaunchConfig_st, blockDimY) - 16usize];
     ["Offset of field: CUlaunchConfig_st::blockDimZ"]
-        [::std::mem::offset_of!(CUlaunchConfig_st, blockDimZ) - 20usize];
+        [::core::mem::offset_of!(CUlaunchConfig_st, blockDimZ) - 20usize];
     ["Offset of field: CUlaunchConfig_st::sharedMemBytes"]
-        [::std::mem::offset_of!(CUlaunchConfig_st, sharedMemBytes) - 24usize];
+        [::core::mem::offset_of!(CUlaunchConfig_st, sharedMemBytes) - 24usize];
     ["Offset of field: CUlaunchConfig_st::hStream"]
-        [::std::mem::offset_of!(CUlaunchConfig_st, hStream) - 32usize];
+        [::core::mem::offset_of!(CUlaunchConfig_st, hStream) - 32usize];
     ["Offset of field: CUlaunchConfig_st::attrs"]
-        [::std::mem::offset_of!(CUlaunchConfig_st, attrs) - 40usize];
+        [::core::mem::offset_of!(CUlaunchConfig_st, attrs) - 40usize];
     ["Offset of field: CUlaunchConfig_st::numAttrs"]
-        [::std::mem::offset_of!(CUlaunchConfig_st, numAttrs) - 48usize];
+        [::core::mem::offset_of!(CUlaunchConfig_st, numAttrs) - 48usize];
 };
 impl Default for CUlaunchConfig_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -2116,16 +2116,16 @@ pub use self::CUexecAffinityType_enum as CUexecAffinityType;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUexecAffinitySmCount_st {
-    pub val: ::std::os::raw::c_uint,
+    pub val: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUexecAffinitySmCount_st"]
-        [::std::mem::size_of::<CUexecAffinitySmCount_st>() - 4usize];
+        [::core::mem::size_of::<CUexecAffinitySmCount_st>() - 4usize];
     ["Alignment of CUexecAffinitySmCount_st"]
-        [::std::mem::align_of::<CUexecAffinitySmCount_st>() - 4usize];
+        [::core::mem::align_of::<CUexecAffinitySmCount_st>() - 4usize];
     ["Offset of field: CUexecAffinitySmCount_st::val"]
-        [::std::mem::offset_of!(CUexecAffinitySmCount_st, val) - 0usize];
+        [::core::mem::offset_of!(CUexecAffinitySmCount_st, val) - 0usize];
 };
 pub type CUexecAffinitySmCount_v1 = CUexecAffinitySmCount_st;
 pub type CUexecAffinitySmCount = CUexecAffinitySmCount_v1;
@@ -2143,36 +2143,36 @@ pub union CUexecAffinityParam_st__bindgen_ty_1 {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUexecAffinityParam_st__bindgen_ty_1"]
-        [::std::mem::size_of::<CUexecAffinityParam_st__bindgen_ty_1>() - 4usize];
+        [::core::mem::size_of::<CUexecAffinityParam_st__bindgen_ty_1>() - 4usize];
     ["Alignment of CUexecAffinityParam_st__bindgen_ty_1"]
-        [::std::mem::align_of::<CUexecAffinityParam_st__bindgen_ty_1>() - 4usize];
+        [::core::mem::align_of::<CUexecAffinityParam_st__bindgen_ty_1>() - 4usize];
     ["Offset of field: CUexecAffinityParam_st__bindgen_ty_1::smCount"]
-        [::std::mem::offset_of!(CUexecAffinityParam_st__bindgen_ty_1, smCount) - 0usize];
+        [::core::mem::offset_of!(CUexecAffinityParam_st__bindgen_ty_1, smCount) - 0usize];
 };
 impl Default for CUexecAffinityParam_st__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUexecAffinityParam_st"][::std::mem::size_of::<CUexecAffinityParam_st>() - 8usize];
+    ["Size of CUexecAffinityParam_st"][::core::mem::size_of::<CUexecAffinityParam_st>() - 8usize];
     ["Alignment of CUexecAffinityParam_st"]
-        [::std::mem::align_of::<CUexecAffinityParam_st>() - 4usize];
+        [::core::mem::align_of::<CUexecAffinityParam_st>() - 4usize];
     ["Offset of field: CUexecAffinityParam_st::type_"]
-        [::std::mem::offset_of!(CUexecAffinityParam_st, type_) - 0usize];
+        [::core::mem::offset_of!(CUexecAffinityParam_st, type_) - 0usize];
     ["Offset of field: CUexecAffinityParam_st::param"]
-        [::std::mem::offset_of!(CUexecAffinityParam_st, param) - 4usize];
+        [::core::mem::offset_of!(CUexecAffinityParam_st, param) - 4usize];
 };
 impl Default for CUexecAffinityParam_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -2190,40 +2190,40 @@ pub use self::CUlibraryOption_enum as CUlibraryOption;
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUlibraryHostUniversalFunctionAndDataTable_st {
-    pub functionTable: *mut ::std::os::raw::c_void,
+    pub functionTable: *mut ::core::ffi::c_void,
     pub functionWindowSize: usize,
-    pub dataTable: *mut ::std::os::raw::c_void,
+    pub dataTable: *mut ::core::ffi::c_void,
     pub dataWindowSize: usize,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUlibraryHostUniversalFunctionAndDataTable_st"]
-        [::std::mem::size_of::<CUlibraryHostUniversalFunctionAndDataTable_st>() - 32usize];
+        [::core::mem::size_of::<CUlibraryHostUniversalFunctionAndDataTable_st>() - 32usize];
     ["Alignment of CUlibraryHostUniversalFunctionAndDataTable_st"]
-        [::std::mem::align_of::<CUlibraryHostUniversalFunctionAndDataTable_st>() - 8usize];
-    ["Offset of field: CUlibraryHostUniversalFunctionAndDataTable_st::functionTable"][::std::mem::offset_of!(
+        [::core::mem::align_of::<CUlibraryHostUniversalFunctionAndDataTable_st>() - 8usize];
+    ["Offset of field: CUlibraryHostUniversalFunctionAndDataTable_st::functionTable"][::core::mem::offset_of!(
         CUlibraryHostUniversalFunctionAndDataTable_st,
         functionTable
     ) - 0usize];
-    ["Offset of field: CUlibraryHostUniversalFunctionAndDataTable_st::functionWindowSize"][::std::mem::offset_of!(
+    ["Offset of field: CUlibraryHostUniversalFunctionAndDataTable_st::functionWindowSize"][::core::mem::offset_of!(
         CUlibraryHostUniversalFunctionAndDataTable_st,
         functionWindowSize
     )
         - 8usize];
-    ["Offset of field: CUlibraryHostUniversalFunctionAndDataTable_st::dataTable"][::std::mem::offset_of!(
+    ["Offset of field: CUlibraryHostUniversalFunctionAndDataTable_st::dataTable"][::core::mem::offset_of!(
         CUlibraryHostUniversalFunctionAndDataTable_st,
         dataTable
     ) - 16usize];
-    ["Offset of field: CUlibraryHostUniversalFunctionAndDataTable_st::dataWindowSize"][::std::mem::offset_of!(
+    ["Offset of field: CUlibraryHostUniversalFunctionAndDataTable_st::dataWindowSize"][::core::mem::offset_of!(
         CUlibraryHostUniversalFunctionAndDataTable_st,
         dataWindowSize
     ) - 24usize];
 };
 impl Default for CUlibraryHostUniversalFunctionAndDataTable_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -2344,29 +2344,29 @@ pub enum CUdevice_P2PAttribute_enum {
     CU_DEVICE_P2P_ATTRIBUTE_ACCESS_ACCESS_SUPPORTED = 4,
 }
 pub use self::CUdevice_P2PAttribute_enum as CUdevice_P2PAttribute;
-pub type CUstreamCallback = ::std::option::Option<
+pub type CUstreamCallback = ::core::option::Option<
     unsafe extern "C" fn(
         hStream: CUstream,
         status: CUresult,
-        userData: *mut ::std::os::raw::c_void,
+        userData: *mut ::core::ffi::c_void,
     ),
 >;
 pub type CUoccupancyB2DSize =
-    ::std::option::Option<unsafe extern "C" fn(blockSize: ::std::os::raw::c_int) -> usize>;
+    ::core::option::Option<unsafe extern "C" fn(blockSize: ::core::ffi::c_int) -> usize>;
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_MEMCPY2D_st {
     pub srcXInBytes: usize,
     pub srcY: usize,
     pub srcMemoryType: CUmemorytype,
-    pub srcHost: *const ::std::os::raw::c_void,
+    pub srcHost: *const ::core::ffi::c_void,
     pub srcDevice: CUdeviceptr,
     pub srcArray: CUarray,
     pub srcPitch: usize,
     pub dstXInBytes: usize,
     pub dstY: usize,
     pub dstMemoryType: CUmemorytype,
-    pub dstHost: *mut ::std::os::raw::c_void,
+    pub dstHost: *mut ::core::ffi::c_void,
     pub dstDevice: CUdeviceptr,
     pub dstArray: CUarray,
     pub dstPitch: usize,
@@ -2375,46 +2375,46 @@ pub struct CUDA_MEMCPY2D_st {
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUDA_MEMCPY2D_st"][::std::mem::size_of::<CUDA_MEMCPY2D_st>() - 128usize];
-    ["Alignment of CUDA_MEMCPY2D_st"][::std::mem::align_of::<CUDA_MEMCPY2D_st>() - 8usize];
+    ["Size of CUDA_MEMCPY2D_st"][::core::mem::size_of::<CUDA_MEMCPY2D_st>() - 128usize];
+    ["Alignment of CUDA_MEMCPY2D_st"][::core::mem::align_of::<CUDA_MEMCPY2D_st>() - 8usize];
     ["Offset of field: CUDA_MEMCPY2D_st::srcXInBytes"]
-        [::std::mem::offset_of!(CUDA_MEMCPY2D_st, srcXInBytes) - 0usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY2D_st, srcXInBytes) - 0usize];
     ["Offset of field: CUDA_MEMCPY2D_st::srcY"]
-        [::std::mem::offset_of!(CUDA_MEMCPY2D_st, srcY) - 8usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY2D_st, srcY) - 8usize];
     ["Offset of field: CUDA_MEMCPY2D_st::srcMemoryType"]
-        [::std::mem::offset_of!(CUDA_MEMCPY2D_st, srcMemoryType) - 16usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY2D_st, srcMemoryType) - 16usize];
     ["Offset of field: CUDA_MEMCPY2D_st::srcHost"]
-        [::std::mem::offset_of!(CUDA_MEMCPY2D_st, srcHost) - 24usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY2D_st, srcHost) - 24usize];
     ["Offset of field: CUDA_MEMCPY2D_st::srcDevice"]
-        [::std::mem::offset_of!(CUDA_MEMCPY2D_st, srcDevice) - 32usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY2D_st, srcDevice) - 32usize];
     ["Offset of field: CUDA_MEMCPY2D_st::srcArray"]
-        [::std::mem::offset_of!(CUDA_MEMCPY2D_st, srcArray) - 40usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY2D_st, srcArray) - 40usize];
     ["Offset of field: CUDA_MEMCPY2D_st::srcPitch"]
-        [::std::mem::offset_of!(CUDA_MEMCPY2D_st, srcPitch) - 48usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY2D_st, srcPitch) - 48usize];
     ["Offset of field: CUDA_MEMCPY2D_st::dstXInBytes"]
-        [::std::mem::offset_of!(CUDA_MEMCPY2D_st, dstXInBytes) - 56usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY2D_st, dstXInBytes) - 56usize];
     ["Offset of field: CUDA_MEMCPY2D_st::dstY"]
-        [::std::mem::offset_of!(CUDA_MEMCPY2D_st, dstY) - 64usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY2D_st, dstY) - 64usize];
     ["Offset of field: CUDA_MEMCPY2D_st::dstMemoryType"]
-        [::std::mem::offset_of!(CUDA_MEMCPY2D_st, dstMemoryType) - 72usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY2D_st, dstMemoryType) - 72usize];
     ["Offset of field: CUDA_MEMCPY2D_st::dstHost"]
-        [::std::mem::offset_of!(CUDA_MEMCPY2D_st, dstHost) - 80usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY2D_st, dstHost) - 80usize];
     ["Offset of field: CUDA_MEMCPY2D_st::dstDevice"]
-        [::std::mem::offset_of!(CUDA_MEMCPY2D_st, dstDevice) - 88usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY2D_st, dstDevice) - 88usize];
     ["Offset of field: CUDA_MEMCPY2D_st::dstArray"]
-        [::std::mem::offset_of!(CUDA_MEMCPY2D_st, dstArray) - 96usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY2D_st, dstArray) - 96usize];
     ["Offset of field: CUDA_MEMCPY2D_st::dstPitch"]
-        [::std::mem::offset_of!(CUDA_MEMCPY2D_st, dstPitch) - 104usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY2D_st, dstPitch) - 104usize];
     ["Offset of field: CUDA_MEMCPY2D_st::WidthInBytes"]
-        [::std::mem::offset_of!(CUDA_MEMCPY2D_st, WidthInBytes) - 112usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY2D_st, WidthInBytes) - 112usize];
     ["Offset of field: CUDA_MEMCPY2D_st::Height"]
-        [::std::mem::offset_of!(CUDA_MEMCPY2D_st, Height) - 120usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY2D_st, Height) - 120usize];
 };
 impl Default for CUDA_MEMCPY2D_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -2429,10 +2429,10 @@ pub struct CUDA_MEMCPY3D_st {
     pub srcZ: usize,
     pub srcLOD: usize,
     pub srcMemoryType: CUmemorytype,
-    pub srcHost: *const ::std::os::raw::c_void,
+    pub srcHost: *const ::core::ffi::c_void,
     pub srcDevice: CUdeviceptr,
     pub srcArray: CUarray,
-    pub reserved0: *mut ::std::os::raw::c_void,
+    pub reserved0: *mut ::core::ffi::c_void,
     pub srcPitch: usize,
     pub srcHeight: usize,
     pub dstXInBytes: usize,
@@ -2440,10 +2440,10 @@ pub struct CUDA_MEMCPY3D_st {
     pub dstZ: usize,
     pub dstLOD: usize,
     pub dstMemoryType: CUmemorytype,
-    pub dstHost: *mut ::std::os::raw::c_void,
+    pub dstHost: *mut ::core::ffi::c_void,
     pub dstDevice: CUdeviceptr,
     pub dstArray: CUarray,
-    pub reserved1: *mut ::std::os::raw::c_void,
+    pub reserved1: *mut ::core::ffi::c_void,
     pub dstPitch: usize,
     pub dstHeight: usize,
     pub WidthInBytes: usize,
@@ -2452,64 +2452,64 @@ pub struct CUDA_MEMCPY3D_st {
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUDA_MEMCPY3D_st"][::std::mem::size_of::<CUDA_MEMCPY3D_st>() - 200usize];
-    ["Alignment of CUDA_MEMCPY3D_st"][::std::mem::align_of::<CUDA_MEMCPY3D_st>() - 8usize];
+    ["Size of CUDA_MEMCPY3D_st"][::core::mem::size_of::<CUDA_MEMCPY3D_st>() - 200usize];
+    ["Alignment of CUDA_MEMCPY3D_st"][::core::mem::align_of::<CUDA_MEMCPY3D_st>() - 8usize];
     ["Offset of field: CUDA_MEMCPY3D_st::srcXInBytes"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcXInBytes) - 0usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, srcXInBytes) - 0usize];
     ["Offset of field: CUDA_MEMCPY3D_st::srcY"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcY) - 8usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, srcY) - 8usize];
     ["Offset of field: CUDA_MEMCPY3D_st::srcZ"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcZ) - 16usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, srcZ) - 16usize];
     ["Offset of field: CUDA_MEMCPY3D_st::srcLOD"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcLOD) - 24usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, srcLOD) - 24usize];
     ["Offset of field: CUDA_MEMCPY3D_st::srcMemoryType"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcMemoryType) - 32usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, srcMemoryType) - 32usize];
     ["Offset of field: CUDA_MEMCPY3D_st::srcHost"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcHost) - 40usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, srcHost) - 40usize];
     ["Offset of field: CUDA_MEMCPY3D_st::srcDevice"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcDevice) - 48usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, srcDevice) - 48usize];
     ["Offset of field: CUDA_MEMCPY3D_st::srcArray"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcArray) - 56usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, srcArray) - 56usize];
     ["Offset of field: CUDA_MEMCPY3D_st::reserved0"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, reserved0) - 64usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, reserved0) - 64usize];
     ["Offset of field: CUDA_MEMCPY3D_st::srcPitch"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcPitch) - 72usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, srcPitch) - 72usize];
     ["Offset of field: CUDA_MEMCPY3D_st::srcHeight"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, srcHeight) - 80usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, srcHeight) - 80usize];
     ["Offset of field: CUDA_MEMCPY3D_st::dstXInBytes"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstXInBytes) - 88usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, dstXInBytes) - 88usize];
     ["Offset of field: CUDA_MEMCPY3D_st::dstY"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstY) - 96usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, dstY) - 96usize];
     ["Offset of field: CUDA_MEMCPY3D_st::dstZ"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstZ) - 104usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, dstZ) - 104usize];
     ["Offset of field: CUDA_MEMCPY3D_st::dstLOD"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstLOD) - 112usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, dstLOD) - 112usize];
     ["Offset of field: CUDA_MEMCPY3D_st::dstMemoryType"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstMemoryType) - 120usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, dstMemoryType) - 120usize];
     ["Offset of field: CUDA_MEMCPY3D_st::dstHost"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstHost) - 128usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, dstHost) - 128usize];
     ["Offset of field: CUDA_MEMCPY3D_st::dstDevice"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstDevice) - 136usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, dstDevice) - 136usize];
     ["Offset of field: CUDA_MEMCPY3D_st::dstArray"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstArray) - 144usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, dstArray) - 144usize];
     ["Offset of field: CUDA_MEMCPY3D_st::reserved1"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, reserved1) - 152usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, reserved1) - 152usize];
     ["Offset of field: CUDA_MEMCPY3D_st::dstPitch"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstPitch) - 160usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, dstPitch) - 160usize];
     ["Offset of field: CUDA_MEMCPY3D_st::dstHeight"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, dstHeight) - 168usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, dstHeight) - 168usize];
     ["Offset of field: CUDA_MEMCPY3D_st::WidthInBytes"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, WidthInBytes) - 176usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, WidthInBytes) - 176usize];
     ["Offset of field: CUDA_MEMCPY3D_st::Height"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, Height) - 184usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, Height) - 184usize];
     ["Offset of field: CUDA_MEMCPY3D_st::Depth"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_st, Depth) - 192usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_st, Depth) - 192usize];
 };
 impl Default for CUDA_MEMCPY3D_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -2524,7 +2524,7 @@ pub struct CUDA_MEMCPY3D_PEER_st {
     pub srcZ: usize,
     pub srcLOD: usize,
     pub srcMemoryType: CUmemorytype,
-    pub srcHost: *const ::std::os::raw::c_void,
+    pub srcHost: *const ::core::ffi::c_void,
     pub srcDevice: CUdeviceptr,
     pub srcArray: CUarray,
     pub srcContext: CUcontext,
@@ -2535,7 +2535,7 @@ pub struct CUDA_MEMCPY3D_PEER_st {
     pub dstZ: usize,
     pub dstLOD: usize,
     pub dstMemoryType: CUmemorytype,
-    pub dstHost: *mut ::std::os::raw::c_void,
+    pub dstHost: *mut ::core::ffi::c_void,
     pub dstDevice: CUdeviceptr,
     pub dstArray: CUarray,
     pub dstContext: CUcontext,
@@ -2547,65 +2547,65 @@ pub struct CUDA_MEMCPY3D_PEER_st {
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUDA_MEMCPY3D_PEER_st"][::std::mem::size_of::<CUDA_MEMCPY3D_PEER_st>() - 200usize];
+    ["Size of CUDA_MEMCPY3D_PEER_st"][::core::mem::size_of::<CUDA_MEMCPY3D_PEER_st>() - 200usize];
     ["Alignment of CUDA_MEMCPY3D_PEER_st"]
-        [::std::mem::align_of::<CUDA_MEMCPY3D_PEER_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_MEMCPY3D_PEER_st>() - 8usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::srcXInBytes"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcXInBytes) - 0usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcXInBytes) - 0usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::srcY"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcY) - 8usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcY) - 8usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::srcZ"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcZ) - 16usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcZ) - 16usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::srcLOD"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcLOD) - 24usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcLOD) - 24usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::srcMemoryType"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcMemoryType) - 32usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcMemoryType) - 32usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::srcHost"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcHost) - 40usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcHost) - 40usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::srcDevice"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcDevice) - 48usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcDevice) - 48usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::srcArray"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcArray) - 56usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcArray) - 56usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::srcContext"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcContext) - 64usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcContext) - 64usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::srcPitch"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcPitch) - 72usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcPitch) - 72usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::srcHeight"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcHeight) - 80usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, srcHeight) - 80usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::dstXInBytes"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstXInBytes) - 88usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstXInBytes) - 88usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::dstY"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstY) - 96usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstY) - 96usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::dstZ"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstZ) - 104usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstZ) - 104usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::dstLOD"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstLOD) - 112usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstLOD) - 112usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::dstMemoryType"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstMemoryType) - 120usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstMemoryType) - 120usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::dstHost"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstHost) - 128usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstHost) - 128usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::dstDevice"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstDevice) - 136usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstDevice) - 136usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::dstArray"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstArray) - 144usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstArray) - 144usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::dstContext"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstContext) - 152usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstContext) - 152usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::dstPitch"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstPitch) - 160usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstPitch) - 160usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::dstHeight"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstHeight) - 168usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, dstHeight) - 168usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::WidthInBytes"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, WidthInBytes) - 176usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, WidthInBytes) - 176usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::Height"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, Height) - 184usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, Height) - 184usize];
     ["Offset of field: CUDA_MEMCPY3D_PEER_st::Depth"]
-        [::std::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, Depth) - 192usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY3D_PEER_st, Depth) - 192usize];
 };
 impl Default for CUDA_MEMCPY3D_PEER_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -2615,31 +2615,31 @@ pub type CUDA_MEMCPY3D_PEER = CUDA_MEMCPY3D_PEER_v1;
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_MEMCPY_NODE_PARAMS_st {
-    pub flags: ::std::os::raw::c_int,
-    pub reserved: ::std::os::raw::c_int,
+    pub flags: ::core::ffi::c_int,
+    pub reserved: ::core::ffi::c_int,
     pub copyCtx: CUcontext,
     pub copyParams: CUDA_MEMCPY3D,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_MEMCPY_NODE_PARAMS_st"]
-        [::std::mem::size_of::<CUDA_MEMCPY_NODE_PARAMS_st>() - 216usize];
+        [::core::mem::size_of::<CUDA_MEMCPY_NODE_PARAMS_st>() - 216usize];
     ["Alignment of CUDA_MEMCPY_NODE_PARAMS_st"]
-        [::std::mem::align_of::<CUDA_MEMCPY_NODE_PARAMS_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_MEMCPY_NODE_PARAMS_st>() - 8usize];
     ["Offset of field: CUDA_MEMCPY_NODE_PARAMS_st::flags"]
-        [::std::mem::offset_of!(CUDA_MEMCPY_NODE_PARAMS_st, flags) - 0usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY_NODE_PARAMS_st, flags) - 0usize];
     ["Offset of field: CUDA_MEMCPY_NODE_PARAMS_st::reserved"]
-        [::std::mem::offset_of!(CUDA_MEMCPY_NODE_PARAMS_st, reserved) - 4usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY_NODE_PARAMS_st, reserved) - 4usize];
     ["Offset of field: CUDA_MEMCPY_NODE_PARAMS_st::copyCtx"]
-        [::std::mem::offset_of!(CUDA_MEMCPY_NODE_PARAMS_st, copyCtx) - 8usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY_NODE_PARAMS_st, copyCtx) - 8usize];
     ["Offset of field: CUDA_MEMCPY_NODE_PARAMS_st::copyParams"]
-        [::std::mem::offset_of!(CUDA_MEMCPY_NODE_PARAMS_st, copyParams) - 16usize];
+        [::core::mem::offset_of!(CUDA_MEMCPY_NODE_PARAMS_st, copyParams) - 16usize];
 };
 impl Default for CUDA_MEMCPY_NODE_PARAMS_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -2651,28 +2651,28 @@ pub struct CUDA_ARRAY_DESCRIPTOR_st {
     pub Width: usize,
     pub Height: usize,
     pub Format: CUarray_format,
-    pub NumChannels: ::std::os::raw::c_uint,
+    pub NumChannels: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_ARRAY_DESCRIPTOR_st"]
-        [::std::mem::size_of::<CUDA_ARRAY_DESCRIPTOR_st>() - 24usize];
+        [::core::mem::size_of::<CUDA_ARRAY_DESCRIPTOR_st>() - 24usize];
     ["Alignment of CUDA_ARRAY_DESCRIPTOR_st"]
-        [::std::mem::align_of::<CUDA_ARRAY_DESCRIPTOR_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_ARRAY_DESCRIPTOR_st>() - 8usize];
     ["Offset of field: CUDA_ARRAY_DESCRIPTOR_st::Width"]
-        [::std::mem::offset_of!(CUDA_ARRAY_DESCRIPTOR_st, Width) - 0usize];
+        [::core::mem::offset_of!(CUDA_ARRAY_DESCRIPTOR_st, Width) - 0usize];
     ["Offset of field: CUDA_ARRAY_DESCRIPTOR_st::Height"]
-        [::std::mem::offset_of!(CUDA_ARRAY_DESCRIPTOR_st, Height) - 8usize];
+        [::core::mem::offset_of!(CUDA_ARRAY_DESCRIPTOR_st, Height) - 8usize];
     ["Offset of field: CUDA_ARRAY_DESCRIPTOR_st::Format"]
-        [::std::mem::offset_of!(CUDA_ARRAY_DESCRIPTOR_st, Format) - 16usize];
+        [::core::mem::offset_of!(CUDA_ARRAY_DESCRIPTOR_st, Format) - 16usize];
     ["Offset of field: CUDA_ARRAY_DESCRIPTOR_st::NumChannels"]
-        [::std::mem::offset_of!(CUDA_ARRAY_DESCRIPTOR_st, NumChannels) - 20usize];
+        [::core::mem::offset_of!(CUDA_ARRAY_DESCRIPTOR_st, NumChannels) - 20usize];
 };
 impl Default for CUDA_ARRAY_DESCRIPTOR_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -2686,33 +2686,33 @@ pub struct CUDA_ARRAY3D_DESCRIPTOR_st {
     pub Height: usize,
     pub Depth: usize,
     pub Format: CUarray_format,
-    pub NumChannels: ::std::os::raw::c_uint,
-    pub Flags: ::std::os::raw::c_uint,
+    pub NumChannels: ::core::ffi::c_uint,
+    pub Flags: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_ARRAY3D_DESCRIPTOR_st"]
-        [::std::mem::size_of::<CUDA_ARRAY3D_DESCRIPTOR_st>() - 40usize];
+        [::core::mem::size_of::<CUDA_ARRAY3D_DESCRIPTOR_st>() - 40usize];
     ["Alignment of CUDA_ARRAY3D_DESCRIPTOR_st"]
-        [::std::mem::align_of::<CUDA_ARRAY3D_DESCRIPTOR_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_ARRAY3D_DESCRIPTOR_st>() - 8usize];
     ["Offset of field: CUDA_ARRAY3D_DESCRIPTOR_st::Width"]
-        [::std::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, Width) - 0usize];
+        [::core::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, Width) - 0usize];
     ["Offset of field: CUDA_ARRAY3D_DESCRIPTOR_st::Height"]
-        [::std::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, Height) - 8usize];
+        [::core::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, Height) - 8usize];
     ["Offset of field: CUDA_ARRAY3D_DESCRIPTOR_st::Depth"]
-        [::std::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, Depth) - 16usize];
+        [::core::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, Depth) - 16usize];
     ["Offset of field: CUDA_ARRAY3D_DESCRIPTOR_st::Format"]
-        [::std::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, Format) - 24usize];
+        [::core::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, Format) - 24usize];
     ["Offset of field: CUDA_ARRAY3D_DESCRIPTOR_st::NumChannels"]
-        [::std::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, NumChannels) - 28usize];
+        [::core::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, NumChannels) - 28usize];
     ["Offset of field: CUDA_ARRAY3D_DESCRIPTOR_st::Flags"]
-        [::std::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, Flags) - 32usize];
+        [::core::mem::offset_of!(CUDA_ARRAY3D_DESCRIPTOR_st, Flags) - 32usize];
 };
 impl Default for CUDA_ARRAY3D_DESCRIPTOR_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -2723,47 +2723,47 @@ pub type CUDA_ARRAY3D_DESCRIPTOR = CUDA_ARRAY3D_DESCRIPTOR_v2;
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_ARRAY_SPARSE_PROPERTIES_st {
     pub tileExtent: CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1,
-    pub miptailFirstLevel: ::std::os::raw::c_uint,
-    pub miptailSize: ::std::os::raw::c_ulonglong,
-    pub flags: ::std::os::raw::c_uint,
-    pub reserved: [::std::os::raw::c_uint; 4usize],
+    pub miptailFirstLevel: ::core::ffi::c_uint,
+    pub miptailSize: ::core::ffi::c_ulonglong,
+    pub flags: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 4usize],
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1 {
-    pub width: ::std::os::raw::c_uint,
-    pub height: ::std::os::raw::c_uint,
-    pub depth: ::std::os::raw::c_uint,
+    pub width: ::core::ffi::c_uint,
+    pub height: ::core::ffi::c_uint,
+    pub depth: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1"]
-        [::std::mem::size_of::<CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1>() - 12usize];
+        [::core::mem::size_of::<CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1>() - 12usize];
     ["Alignment of CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1"]
-        [::std::mem::align_of::<CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1>() - 4usize];
+        [::core::mem::align_of::<CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1>() - 4usize];
     ["Offset of field: CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1::width"]
-        [::std::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1, width) - 0usize];
+        [::core::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1, width) - 0usize];
     ["Offset of field: CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1::height"]
-        [::std::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1, height) - 4usize];
+        [::core::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1, height) - 4usize];
     ["Offset of field: CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1::depth"]
-        [::std::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1, depth) - 8usize];
+        [::core::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st__bindgen_ty_1, depth) - 8usize];
 };
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_ARRAY_SPARSE_PROPERTIES_st"]
-        [::std::mem::size_of::<CUDA_ARRAY_SPARSE_PROPERTIES_st>() - 48usize];
+        [::core::mem::size_of::<CUDA_ARRAY_SPARSE_PROPERTIES_st>() - 48usize];
     ["Alignment of CUDA_ARRAY_SPARSE_PROPERTIES_st"]
-        [::std::mem::align_of::<CUDA_ARRAY_SPARSE_PROPERTIES_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_ARRAY_SPARSE_PROPERTIES_st>() - 8usize];
     ["Offset of field: CUDA_ARRAY_SPARSE_PROPERTIES_st::tileExtent"]
-        [::std::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st, tileExtent) - 0usize];
+        [::core::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st, tileExtent) - 0usize];
     ["Offset of field: CUDA_ARRAY_SPARSE_PROPERTIES_st::miptailFirstLevel"]
-        [::std::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st, miptailFirstLevel) - 12usize];
+        [::core::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st, miptailFirstLevel) - 12usize];
     ["Offset of field: CUDA_ARRAY_SPARSE_PROPERTIES_st::miptailSize"]
-        [::std::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st, miptailSize) - 16usize];
+        [::core::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st, miptailSize) - 16usize];
     ["Offset of field: CUDA_ARRAY_SPARSE_PROPERTIES_st::flags"]
-        [::std::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st, flags) - 24usize];
+        [::core::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st, flags) - 24usize];
     ["Offset of field: CUDA_ARRAY_SPARSE_PROPERTIES_st::reserved"]
-        [::std::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st, reserved) - 28usize];
+        [::core::mem::offset_of!(CUDA_ARRAY_SPARSE_PROPERTIES_st, reserved) - 28usize];
 };
 pub type CUDA_ARRAY_SPARSE_PROPERTIES_v1 = CUDA_ARRAY_SPARSE_PROPERTIES_st;
 pub type CUDA_ARRAY_SPARSE_PROPERTIES = CUDA_ARRAY_SPARSE_PROPERTIES_v1;
@@ -2772,20 +2772,20 @@ pub type CUDA_ARRAY_SPARSE_PROPERTIES = CUDA_ARRAY_SPARSE_PROPERTIES_v1;
 pub struct CUDA_ARRAY_MEMORY_REQUIREMENTS_st {
     pub size: usize,
     pub alignment: usize,
-    pub reserved: [::std::os::raw::c_uint; 4usize],
+    pub reserved: [::core::ffi::c_uint; 4usize],
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_ARRAY_MEMORY_REQUIREMENTS_st"]
-        [::std::mem::size_of::<CUDA_ARRAY_MEMORY_REQUIREMENTS_st>() - 32usize];
+        [::core::mem::size_of::<CUDA_ARRAY_MEMORY_REQUIREMENTS_st>() - 32usize];
     ["Alignment of CUDA_ARRAY_MEMORY_REQUIREMENTS_st"]
-        [::std::mem::align_of::<CUDA_ARRAY_MEMORY_REQUIREMENTS_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_ARRAY_MEMORY_REQUIREMENTS_st>() - 8usize];
     ["Offset of field: CUDA_ARRAY_MEMORY_REQUIREMENTS_st::size"]
-        [::std::mem::offset_of!(CUDA_ARRAY_MEMORY_REQUIREMENTS_st, size) - 0usize];
+        [::core::mem::offset_of!(CUDA_ARRAY_MEMORY_REQUIREMENTS_st, size) - 0usize];
     ["Offset of field: CUDA_ARRAY_MEMORY_REQUIREMENTS_st::alignment"]
-        [::std::mem::offset_of!(CUDA_ARRAY_MEMORY_REQUIREMENTS_st, alignment) - 8usize];
+        [::core::mem::offset_of!(CUDA_ARRAY_MEMORY_REQUIREMENTS_st, alignment) - 8usize];
     ["Offset of field: CUDA_ARRAY_MEMORY_REQUIREMENTS_st::reserved"]
-        [::std::mem::offset_of!(CUDA_ARRAY_MEMORY_REQUIREMENTS_st, reserved) - 16usize];
+        [::core::mem::offset_of!(CUDA_ARRAY_MEMORY_REQUIREMENTS_st, reserved) - 16usize];
 };
 pub type CUDA_ARRAY_MEMORY_REQUIREMENTS_v1 = CUDA_ARRAY_MEMORY_REQUIREMENTS_st;
 pub type CUDA_ARRAY_MEMORY_REQUIREMENTS = CUDA_ARRAY_MEMORY_REQUIREMENTS_v1;
@@ -2794,7 +2794,7 @@ pub type CUDA_ARRAY_MEMORY_REQUIREMENTS = CUDA_ARRAY_MEMORY_REQUIREMENTS_v1;
 pub struct CUDA_RESOURCE_DESC_st {
     pub resType: CUresourcetype,
     pub res: CUDA_RESOURCE_DESC_st__bindgen_ty_1,
-    pub flags: ::std::os::raw::c_uint,
+    pub flags: ::core::ffi::c_uint,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -2813,19 +2813,19 @@ pub struct CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1 {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1"]
-        [::std::mem::size_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1>() - 8usize];
+        [::core::mem::size_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1>() - 8usize];
     ["Alignment of CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1"]
-        [::std::mem::align_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1>() - 8usize];
-    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1::hArray"][::std::mem::offset_of!(
+        [::core::mem::align_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1>() - 8usize];
+    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1::hArray"][::core::mem::offset_of!(
         CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1,
         hArray
     ) - 0usize];
 };
 impl Default for CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -2838,10 +2838,10 @@ pub struct CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2 {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2"]
-        [::std::mem::size_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2>() - 8usize];
+        [::core::mem::size_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2>() - 8usize];
     ["Alignment of CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2"]
-        [::std::mem::align_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2>() - 8usize];
-    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2::hMipmappedArray"][::std::mem::offset_of!(
+        [::core::mem::align_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2>() - 8usize];
+    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2::hMipmappedArray"][::core::mem::offset_of!(
         CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2,
         hMipmappedArray
     )
@@ -2849,9 +2849,9 @@ const _: () = {
 };
 impl Default for CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -2861,29 +2861,29 @@ impl Default for CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_2 {
 pub struct CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3 {
     pub devPtr: CUdeviceptr,
     pub format: CUarray_format,
-    pub numChannels: ::std::os::raw::c_uint,
+    pub numChannels: ::core::ffi::c_uint,
     pub sizeInBytes: usize,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3"]
-        [::std::mem::size_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3>() - 24usize];
+        [::core::mem::size_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3>() - 24usize];
     ["Alignment of CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3"]
-        [::std::mem::align_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3>() - 8usize];
-    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3::devPtr"][::std::mem::offset_of!(
+        [::core::mem::align_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3>() - 8usize];
+    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3::devPtr"][::core::mem::offset_of!(
         CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3,
         devPtr
     ) - 0usize];
-    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3::format"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3::format"][::core::mem::offset_of!(
         CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3,
         format
     ) - 8usize];
-    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3::numChannels"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3::numChannels"][::core::mem::offset_of!(
         CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3,
         numChannels
     )
         - 12usize];
-    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3::sizeInBytes"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3::sizeInBytes"][::core::mem::offset_of!(
         CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3,
         sizeInBytes
     )
@@ -2891,9 +2891,9 @@ const _: () = {
 };
 impl Default for CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -2903,7 +2903,7 @@ impl Default for CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_3 {
 pub struct CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4 {
     pub devPtr: CUdeviceptr,
     pub format: CUarray_format,
-    pub numChannels: ::std::os::raw::c_uint,
+    pub numChannels: ::core::ffi::c_uint,
     pub width: usize,
     pub height: usize,
     pub pitchInBytes: usize,
@@ -2911,31 +2911,31 @@ pub struct CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4 {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4"]
-        [::std::mem::size_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4>() - 40usize];
+        [::core::mem::size_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4>() - 40usize];
     ["Alignment of CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4"]
-        [::std::mem::align_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4>() - 8usize];
-    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4::devPtr"][::std::mem::offset_of!(
+        [::core::mem::align_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4>() - 8usize];
+    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4::devPtr"][::core::mem::offset_of!(
         CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4,
         devPtr
     ) - 0usize];
-    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4::format"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4::format"][::core::mem::offset_of!(
         CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4,
         format
     ) - 8usize];
-    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4::numChannels"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4::numChannels"][::core::mem::offset_of!(
         CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4,
         numChannels
     )
         - 12usize];
-    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4::width"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4::width"][::core::mem::offset_of!(
         CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4,
         width
     ) - 16usize];
-    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4::height"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4::height"][::core::mem::offset_of!(
         CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4,
         height
     ) - 24usize];
-    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4::pitchInBytes"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4::pitchInBytes"][::core::mem::offset_of!(
         CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4,
         pitchInBytes
     )
@@ -2943,9 +2943,9 @@ const _: () = {
 };
 impl Default for CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -2953,15 +2953,15 @@ impl Default for CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_4 {
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5 {
-    pub reserved: [::std::os::raw::c_int; 32usize],
+    pub reserved: [::core::ffi::c_int; 32usize],
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5"]
-        [::std::mem::size_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5>() - 128usize];
+        [::core::mem::size_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5>() - 128usize];
     ["Alignment of CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5"]
-        [::std::mem::align_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5>() - 4usize];
-    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5::reserved"][::std::mem::offset_of!(
+        [::core::mem::align_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5>() - 4usize];
+    ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5::reserved"][::core::mem::offset_of!(
         CUDA_RESOURCE_DESC_st__bindgen_ty_1__bindgen_ty_5,
         reserved
     ) - 0usize];
@@ -2969,46 +2969,46 @@ const _: () = {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_RESOURCE_DESC_st__bindgen_ty_1"]
-        [::std::mem::size_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1>() - 128usize];
+        [::core::mem::size_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1>() - 128usize];
     ["Alignment of CUDA_RESOURCE_DESC_st__bindgen_ty_1"]
-        [::std::mem::align_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1>() - 8usize];
+        [::core::mem::align_of::<CUDA_RESOURCE_DESC_st__bindgen_ty_1>() - 8usize];
     ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1::array"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1, array) - 0usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1, array) - 0usize];
     ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1::mipmap"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1, mipmap) - 0usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1, mipmap) - 0usize];
     ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1::linear"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1, linear) - 0usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1, linear) - 0usize];
     ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1::pitch2D"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1, pitch2D) - 0usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1, pitch2D) - 0usize];
     ["Offset of field: CUDA_RESOURCE_DESC_st__bindgen_ty_1::reserved"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1, reserved) - 0usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_DESC_st__bindgen_ty_1, reserved) - 0usize];
 };
 impl Default for CUDA_RESOURCE_DESC_st__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUDA_RESOURCE_DESC_st"][::std::mem::size_of::<CUDA_RESOURCE_DESC_st>() - 144usize];
+    ["Size of CUDA_RESOURCE_DESC_st"][::core::mem::size_of::<CUDA_RESOURCE_DESC_st>() - 144usize];
     ["Alignment of CUDA_RESOURCE_DESC_st"]
-        [::std::mem::align_of::<CUDA_RESOURCE_DESC_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_RESOURCE_DESC_st>() - 8usize];
     ["Offset of field: CUDA_RESOURCE_DESC_st::resType"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_DESC_st, resType) - 0usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_DESC_st, resType) - 0usize];
     ["Offset of field: CUDA_RESOURCE_DESC_st::res"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_DESC_st, res) - 8usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_DESC_st, res) - 8usize];
     ["Offset of field: CUDA_RESOURCE_DESC_st::flags"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_DESC_st, flags) - 136usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_DESC_st, flags) - 136usize];
 };
 impl Default for CUDA_RESOURCE_DESC_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3020,45 +3020,45 @@ pub type CUDA_RESOURCE_DESC = CUDA_RESOURCE_DESC_v1;
 pub struct CUDA_TEXTURE_DESC_st {
     pub addressMode: [CUaddress_mode; 3usize],
     pub filterMode: CUfilter_mode,
-    pub flags: ::std::os::raw::c_uint,
-    pub maxAnisotropy: ::std::os::raw::c_uint,
+    pub flags: ::core::ffi::c_uint,
+    pub maxAnisotropy: ::core::ffi::c_uint,
     pub mipmapFilterMode: CUfilter_mode,
     pub mipmapLevelBias: f32,
     pub minMipmapLevelClamp: f32,
     pub maxMipmapLevelClamp: f32,
     pub borderColor: [f32; 4usize],
-    pub reserved: [::std::os::raw::c_int; 12usize],
+    pub reserved: [::core::ffi::c_int; 12usize],
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUDA_TEXTURE_DESC_st"][::std::mem::size_of::<CUDA_TEXTURE_DESC_st>() - 104usize];
-    ["Alignment of CUDA_TEXTURE_DESC_st"][::std::mem::align_of::<CUDA_TEXTURE_DESC_st>() - 4usize];
+    ["Size of CUDA_TEXTURE_DESC_st"][::core::mem::size_of::<CUDA_TEXTURE_DESC_st>() - 104usize];
+    ["Alignment of CUDA_TEXTURE_DESC_st"][::core::mem::align_of::<CUDA_TEXTURE_DESC_st>() - 4usize];
     ["Offset of field: CUDA_TEXTURE_DESC_st::addressMode"]
-        [::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, addressMode) - 0usize];
+        [::core::mem::offset_of!(CUDA_TEXTURE_DESC_st, addressMode) - 0usize];
     ["Offset of field: CUDA_TEXTURE_DESC_st::filterMode"]
-        [::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, filterMode) - 12usize];
+        [::core::mem::offset_of!(CUDA_TEXTURE_DESC_st, filterMode) - 12usize];
     ["Offset of field: CUDA_TEXTURE_DESC_st::flags"]
-        [::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, flags) - 16usize];
+        [::core::mem::offset_of!(CUDA_TEXTURE_DESC_st, flags) - 16usize];
     ["Offset of field: CUDA_TEXTURE_DESC_st::maxAnisotropy"]
-        [::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, maxAnisotropy) - 20usize];
+        [::core::mem::offset_of!(CUDA_TEXTURE_DESC_st, maxAnisotropy) - 20usize];
     ["Offset of field: CUDA_TEXTURE_DESC_st::mipmapFilterMode"]
-        [::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, mipmapFilterMode) - 24usize];
+        [::core::mem::offset_of!(CUDA_TEXTURE_DESC_st, mipmapFilterMode) - 24usize];
     ["Offset of field: CUDA_TEXTURE_DESC_st::mipmapLevelBias"]
-        [::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, mipmapLevelBias) - 28usize];
+        [::core::mem::offset_of!(CUDA_TEXTURE_DESC_st, mipmapLevelBias) - 28usize];
     ["Offset of field: CUDA_TEXTURE_DESC_st::minMipmapLevelClamp"]
-        [::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, minMipmapLevelClamp) - 32usize];
+        [::core::mem::offset_of!(CUDA_TEXTURE_DESC_st, minMipmapLevelClamp) - 32usize];
     ["Offset of field: CUDA_TEXTURE_DESC_st::maxMipmapLevelClamp"]
-        [::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, maxMipmapLevelClamp) - 36usize];
+        [::core::mem::offset_of!(CUDA_TEXTURE_DESC_st, maxMipmapLevelClamp) - 36usize];
     ["Offset of field: CUDA_TEXTURE_DESC_st::borderColor"]
-        [::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, borderColor) - 40usize];
+        [::core::mem::offset_of!(CUDA_TEXTURE_DESC_st, borderColor) - 40usize];
     ["Offset of field: CUDA_TEXTURE_DESC_st::reserved"]
-        [::std::mem::offset_of!(CUDA_TEXTURE_DESC_st, reserved) - 56usize];
+        [::core::mem::offset_of!(CUDA_TEXTURE_DESC_st, reserved) - 56usize];
 };
 impl Default for CUDA_TEXTURE_DESC_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3112,42 +3112,42 @@ pub struct CUDA_RESOURCE_VIEW_DESC_st {
     pub width: usize,
     pub height: usize,
     pub depth: usize,
-    pub firstMipmapLevel: ::std::os::raw::c_uint,
-    pub lastMipmapLevel: ::std::os::raw::c_uint,
-    pub firstLayer: ::std::os::raw::c_uint,
-    pub lastLayer: ::std::os::raw::c_uint,
-    pub reserved: [::std::os::raw::c_uint; 16usize],
+    pub firstMipmapLevel: ::core::ffi::c_uint,
+    pub lastMipmapLevel: ::core::ffi::c_uint,
+    pub firstLayer: ::core::ffi::c_uint,
+    pub lastLayer: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 16usize],
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_RESOURCE_VIEW_DESC_st"]
-        [::std::mem::size_of::<CUDA_RESOURCE_VIEW_DESC_st>() - 112usize];
+        [::core::mem::size_of::<CUDA_RESOURCE_VIEW_DESC_st>() - 112usize];
     ["Alignment of CUDA_RESOURCE_VIEW_DESC_st"]
-        [::std::mem::align_of::<CUDA_RESOURCE_VIEW_DESC_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_RESOURCE_VIEW_DESC_st>() - 8usize];
     ["Offset of field: CUDA_RESOURCE_VIEW_DESC_st::format"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, format) - 0usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, format) - 0usize];
     ["Offset of field: CUDA_RESOURCE_VIEW_DESC_st::width"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, width) - 8usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, width) - 8usize];
     ["Offset of field: CUDA_RESOURCE_VIEW_DESC_st::height"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, height) - 16usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, height) - 16usize];
     ["Offset of field: CUDA_RESOURCE_VIEW_DESC_st::depth"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, depth) - 24usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, depth) - 24usize];
     ["Offset of field: CUDA_RESOURCE_VIEW_DESC_st::firstMipmapLevel"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, firstMipmapLevel) - 32usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, firstMipmapLevel) - 32usize];
     ["Offset of field: CUDA_RESOURCE_VIEW_DESC_st::lastMipmapLevel"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, lastMipmapLevel) - 36usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, lastMipmapLevel) - 36usize];
     ["Offset of field: CUDA_RESOURCE_VIEW_DESC_st::firstLayer"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, firstLayer) - 40usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, firstLayer) - 40usize];
     ["Offset of field: CUDA_RESOURCE_VIEW_DESC_st::lastLayer"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, lastLayer) - 44usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, lastLayer) - 44usize];
     ["Offset of field: CUDA_RESOURCE_VIEW_DESC_st::reserved"]
-        [::std::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, reserved) - 48usize];
+        [::core::mem::offset_of!(CUDA_RESOURCE_VIEW_DESC_st, reserved) - 48usize];
 };
 impl Default for CUDA_RESOURCE_VIEW_DESC_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3162,16 +3162,16 @@ pub struct CUtensorMap_st {
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUtensorMap_st"][::std::mem::size_of::<CUtensorMap_st>() - 128usize];
-    ["Alignment of CUtensorMap_st"][::std::mem::align_of::<CUtensorMap_st>() - 64usize];
+    ["Size of CUtensorMap_st"][::core::mem::size_of::<CUtensorMap_st>() - 128usize];
+    ["Alignment of CUtensorMap_st"][::core::mem::align_of::<CUtensorMap_st>() - 64usize];
     ["Offset of field: CUtensorMap_st::opaque"]
-        [::std::mem::offset_of!(CUtensorMap_st, opaque) - 0usize];
+        [::core::mem::offset_of!(CUtensorMap_st, opaque) - 0usize];
 };
 impl Default for CUtensorMap_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3231,19 +3231,19 @@ pub use self::CUtensorMapFloatOOBfill_enum as CUtensorMapFloatOOBfill;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st {
-    pub p2pToken: ::std::os::raw::c_ulonglong,
-    pub vaSpaceToken: ::std::os::raw::c_uint,
+    pub p2pToken: ::core::ffi::c_ulonglong,
+    pub vaSpaceToken: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st"]
-        [::std::mem::size_of::<CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st>() - 16usize];
+        [::core::mem::size_of::<CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st>() - 16usize];
     ["Alignment of CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st"]
-        [::std::mem::align_of::<CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st>() - 8usize];
     ["Offset of field: CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st::p2pToken"]
-        [::std::mem::offset_of!(CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st, p2pToken) - 0usize];
+        [::core::mem::offset_of!(CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st, p2pToken) - 0usize];
     ["Offset of field: CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st::vaSpaceToken"]
-        [::std::mem::offset_of!(CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st, vaSpaceToken) - 8usize];
+        [::core::mem::offset_of!(CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st, vaSpaceToken) - 8usize];
 };
 pub type CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_v1 = CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_st;
 pub type CUDA_POINTER_ATTRIBUTE_P2P_TOKENS = CUDA_POINTER_ATTRIBUTE_P2P_TOKENS_v1;
@@ -3259,47 +3259,47 @@ pub use self::CUDA_POINTER_ATTRIBUTE_ACCESS_FLAGS_enum as CUDA_POINTER_ATTRIBUTE
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_LAUNCH_PARAMS_st {
     pub function: CUfunction,
-    pub gridDimX: ::std::os::raw::c_uint,
-    pub gridDimY: ::std::os::raw::c_uint,
-    pub gridDimZ: ::std::os::raw::c_uint,
-    pub blockDimX: ::std::os::raw::c_uint,
-    pub blockDimY: ::std::os::raw::c_uint,
-    pub blockDimZ: ::std::os::raw::c_uint,
-    pub sharedMemBytes: ::std::os::raw::c_uint,
+    pub gridDimX: ::core::ffi::c_uint,
+    pub gridDimY: ::core::ffi::c_uint,
+    pub gridDimZ: ::core::ffi::c_uint,
+    pub blockDimX: ::core::ffi::c_uint,
+    pub blockDimY: ::core::ffi::c_uint,
+    pub blockDimZ: ::core::ffi::c_uint,
+    pub sharedMemBytes: ::core::ffi::c_uint,
     pub hStream: CUstream,
-    pub kernelParams: *mut *mut ::std::os::raw::c_void,
+    pub kernelParams: *mut *mut ::core::ffi::c_void,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUDA_LAUNCH_PARAMS_st"][::std::mem::size_of::<CUDA_LAUNCH_PARAMS_st>() - 56usize];
+    ["Size of CUDA_LAUNCH_PARAMS_st"][::core::mem::size_of::<CUDA_LAUNCH_PARAMS_st>() - 56usize];
     ["Alignment of CUDA_LAUNCH_PARAMS_st"]
-        [::std::mem::align_of::<CUDA_LAUNCH_PARAMS_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_LAUNCH_PARAMS_st>() - 8usize];
     ["Offset of field: CUDA_LAUNCH_PARAMS_st::function"]
-        [::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, function) - 0usize];
+        [::core::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, function) - 0usize];
     ["Offset of field: CUDA_LAUNCH_PARAMS_st::gridDimX"]
-        [::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, gridDimX) - 8usize];
+        [::core::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, gridDimX) - 8usize];
     ["Offset of field: CUDA_LAUNCH_PARAMS_st::gridDimY"]
-        [::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, gridDimY) - 12usize];
+        [::core::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, gridDimY) - 12usize];
     ["Offset of field: CUDA_LAUNCH_PARAMS_st::gridDimZ"]
-        [::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, gridDimZ) - 16usize];
+        [::core::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, gridDimZ) - 16usize];
     ["Offset of field: CUDA_LAUNCH_PARAMS_st::blockDimX"]
-        [::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, blockDimX) - 20usize];
+        [::core::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, blockDimX) - 20usize];
     ["Offset of field: CUDA_LAUNCH_PARAMS_st::blockDimY"]
-        [::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, blockDimY) - 24usize];
+        [::core::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, blockDimY) - 24usize];
     ["Offset of field: CUDA_LAUNCH_PARAMS_st::blockDimZ"]
-        [::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, blockDimZ) - 28usize];
+        [::core::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, blockDimZ) - 28usize];
     ["Offset of field: CUDA_LAUNCH_PARAMS_st::sharedMemBytes"]
-        [::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, sharedMemBytes) - 32usize];
+        [::core::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, sharedMemBytes) - 32usize];
     ["Offset of field: CUDA_LAUNCH_PARAMS_st::hStream"]
-        [::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, hStream) - 40usize];
+        [::core::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, hStream) - 40usize];
     ["Offset of field: CUDA_LAUNCH_PARAMS_st::kernelParams"]
-        [::std::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, kernelParams) - 48usize];
+        [::core::mem::offset_of!(CUDA_LAUNCH_PARAMS_st, kernelParams) - 48usize];
 };
 impl Default for CUDA_LAUNCH_PARAMS_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3324,37 +3324,37 @@ pub use self::CUexternalMemoryHandleType_enum as CUexternalMemoryHandleType;
 pub struct CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st {
     pub type_: CUexternalMemoryHandleType,
     pub handle: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1,
-    pub size: ::std::os::raw::c_ulonglong,
-    pub flags: ::std::os::raw::c_uint,
-    pub reserved: [::std::os::raw::c_uint; 16usize],
+    pub size: ::core::ffi::c_ulonglong,
+    pub flags: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 16usize],
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1 {
-    pub fd: ::std::os::raw::c_int,
+    pub fd: ::core::ffi::c_int,
     pub win32: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1,
-    pub nvSciBufObject: *const ::std::os::raw::c_void,
+    pub nvSciBufObject: *const ::core::ffi::c_void,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1 {
-    pub handle: *mut ::std::os::raw::c_void,
-    pub name: *const ::std::os::raw::c_void,
+    pub handle: *mut ::core::ffi::c_void,
+    pub name: *const ::core::ffi::c_void,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1"][::std::mem::size_of::<
+    ["Size of CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1"][::core::mem::size_of::<
         CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1,
     >() - 16usize];
     ["Alignment of CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1"]
-        [::std::mem::align_of::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1>()
+        [::core::mem::align_of::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1>()
             - 8usize];
-    ["Offset of field: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1::handle"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1::handle"][::core::mem::offset_of!(
         CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1,
         handle
     )
         - 0usize];
-    ["Offset of field: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1::name"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1::name"][::core::mem::offset_of!(
         CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1,
         name
     )
@@ -3362,9 +3362,9 @@ const _: () = {
 };
 impl Default for CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3372,14 +3372,14 @@ impl Default for CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1>() - 16usize];
+        [::core::mem::size_of::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1>() - 16usize];
     ["Alignment of CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1"]
-        [::std::mem::align_of::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1>() - 8usize];
+        [::core::mem::align_of::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1>() - 8usize];
     ["Offset of field: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1::fd"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1, fd) - 0usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1, fd) - 0usize];
     ["Offset of field: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1::win32"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1, win32) - 0usize];
-    ["Offset of field: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1::nvSciBufObject"][::std::mem::offset_of!(
+        [::core::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1, win32) - 0usize];
+    ["Offset of field: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1::nvSciBufObject"][::core::mem::offset_of!(
         CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1,
         nvSciBufObject
     )
@@ -3387,9 +3387,9 @@ const _: () = {
 };
 impl Default for CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3397,25 +3397,25 @@ impl Default for CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st__bindgen_ty_1 {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st>() - 104usize];
+        [::core::mem::size_of::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st>() - 104usize];
     ["Alignment of CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st"]
-        [::std::mem::align_of::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st>() - 8usize];
     ["Offset of field: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st::type_"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st, type_) - 0usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st, type_) - 0usize];
     ["Offset of field: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st::handle"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st, handle) - 8usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st, handle) - 8usize];
     ["Offset of field: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st::size"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st, size) - 24usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st, size) - 24usize];
     ["Offset of field: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st::flags"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st, flags) - 32usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st, flags) - 32usize];
     ["Offset of field: CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st::reserved"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st, reserved) - 36usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st, reserved) - 36usize];
 };
 impl Default for CUDA_EXTERNAL_MEMORY_HANDLE_DESC_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3425,56 +3425,56 @@ pub type CUDA_EXTERNAL_MEMORY_HANDLE_DESC = CUDA_EXTERNAL_MEMORY_HANDLE_DESC_v1;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st {
-    pub offset: ::std::os::raw::c_ulonglong,
-    pub size: ::std::os::raw::c_ulonglong,
-    pub flags: ::std::os::raw::c_uint,
-    pub reserved: [::std::os::raw::c_uint; 16usize],
+    pub offset: ::core::ffi::c_ulonglong,
+    pub size: ::core::ffi::c_ulonglong,
+    pub flags: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 16usize],
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st>() - 88usize];
+        [::core::mem::size_of::<CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st>() - 88usize];
     ["Alignment of CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st"]
-        [::std::mem::align_of::<CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st>() - 8usize];
     ["Offset of field: CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st::offset"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st, offset) - 0usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st, offset) - 0usize];
     ["Offset of field: CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st::size"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st, size) - 8usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st, size) - 8usize];
     ["Offset of field: CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st::flags"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st, flags) - 16usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st, flags) - 16usize];
     ["Offset of field: CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st::reserved"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st, reserved) - 20usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st, reserved) - 20usize];
 };
 pub type CUDA_EXTERNAL_MEMORY_BUFFER_DESC_v1 = CUDA_EXTERNAL_MEMORY_BUFFER_DESC_st;
 pub type CUDA_EXTERNAL_MEMORY_BUFFER_DESC = CUDA_EXTERNAL_MEMORY_BUFFER_DESC_v1;
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st {
-    pub offset: ::std::os::raw::c_ulonglong,
+    pub offset: ::core::ffi::c_ulonglong,
     pub arrayDesc: CUDA_ARRAY3D_DESCRIPTOR,
-    pub numLevels: ::std::os::raw::c_uint,
-    pub reserved: [::std::os::raw::c_uint; 16usize],
+    pub numLevels: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 16usize],
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st>() - 120usize];
+        [::core::mem::size_of::<CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st>() - 120usize];
     ["Alignment of CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st"]
-        [::std::mem::align_of::<CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st>() - 8usize];
     ["Offset of field: CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st::offset"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st, offset) - 0usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st, offset) - 0usize];
     ["Offset of field: CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st::arrayDesc"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st, arrayDesc) - 8usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st, arrayDesc) - 8usize];
     ["Offset of field: CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st::numLevels"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st, numLevels) - 48usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st, numLevels) - 48usize];
     ["Offset of field: CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st::reserved"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st, reserved) - 52usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st, reserved) - 52usize];
 };
 impl Default for CUDA_EXTERNAL_MEMORY_MIPMAPPED_ARRAY_DESC_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3502,32 +3502,32 @@ pub use self::CUexternalSemaphoreHandleType_enum as CUexternalSemaphoreHandleTyp
 pub struct CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st {
     pub type_: CUexternalSemaphoreHandleType,
     pub handle: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1,
-    pub flags: ::std::os::raw::c_uint,
-    pub reserved: [::std::os::raw::c_uint; 16usize],
+    pub flags: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 16usize],
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1 {
-    pub fd: ::std::os::raw::c_int,
+    pub fd: ::core::ffi::c_int,
     pub win32: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1,
-    pub nvSciSyncObj: *const ::std::os::raw::c_void,
+    pub nvSciSyncObj: *const ::core::ffi::c_void,
 }
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1 {
-    pub handle: *mut ::std::os::raw::c_void,
-    pub name: *const ::std::os::raw::c_void,
+    pub handle: *mut ::core::ffi::c_void,
+    pub name: *const ::core::ffi::c_void,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1>(
+        [::core::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1>(
         ) - 16usize];
     ["Alignment of CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1"]
-        [::std::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1>(
+        [::core::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1>(
         ) - 8usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1::handle"] [:: std :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1 , handle) - 0usize] ;
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1::name"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1::handle"] [:: core :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1 , handle) - 0usize] ;
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1::name"][::core::mem::offset_of!(
         CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1,
         name
     )
@@ -3535,9 +3535,9 @@ const _: () = {
 };
 impl Default for CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3545,16 +3545,16 @@ impl Default for CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1__bindgen_t
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1>() - 16usize];
+        [::core::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1>() - 16usize];
     ["Alignment of CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1"]
-        [::std::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1>() - 8usize];
+        [::core::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1>() - 8usize];
     ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1::fd"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1, fd) - 0usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1::win32"][::std::mem::offset_of!(
+        [::core::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1, fd) - 0usize];
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1::win32"][::core::mem::offset_of!(
         CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1,
         win32
     ) - 0usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1::nvSciSyncObj"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1::nvSciSyncObj"][::core::mem::offset_of!(
         CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1,
         nvSciSyncObj
     )
@@ -3562,9 +3562,9 @@ const _: () = {
 };
 impl Default for CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3572,23 +3572,23 @@ impl Default for CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st__bindgen_ty_1 {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st>() - 96usize];
+        [::core::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st>() - 96usize];
     ["Alignment of CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st"]
-        [::std::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st>() - 8usize];
     ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st::type_"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st, type_) - 0usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st, type_) - 0usize];
     ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st::handle"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st, handle) - 8usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st, handle) - 8usize];
     ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st::flags"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st, flags) - 24usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st, flags) - 24usize];
     ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st::reserved"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st, reserved) - 28usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st, reserved) - 28usize];
 };
 impl Default for CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3599,8 +3599,8 @@ pub type CUDA_EXTERNAL_SEMAPHORE_HANDLE_DESC = CUDA_EXTERNAL_SEMAPHORE_HANDLE_DE
 #[derive(Copy, Clone)]
 pub struct CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st {
     pub params: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1,
-    pub flags: ::std::os::raw::c_uint,
-    pub reserved: [::std::os::raw::c_uint; 16usize],
+    pub flags: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 16usize],
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -3608,47 +3608,47 @@ pub struct CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1 {
     pub fence: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1,
     pub nvSciSync: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2,
     pub keyedMutex: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3,
-    pub reserved: [::std::os::raw::c_uint; 12usize],
+    pub reserved: [::core::ffi::c_uint; 12usize],
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1 {
-    pub value: ::std::os::raw::c_ulonglong,
+    pub value: ::core::ffi::c_ulonglong,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1>(
+        [::core::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1>(
         ) - 8usize];
     ["Alignment of CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1"]
-        [::std::mem::align_of::<
+        [::core::mem::align_of::<
             CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1,
         >() - 8usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1::value"] [:: std :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1 , value) - 0usize] ;
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1::value"] [:: core :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_1 , value) - 0usize] ;
 };
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2 {
-    pub fence: *mut ::std::os::raw::c_void,
-    pub reserved: ::std::os::raw::c_ulonglong,
+    pub fence: *mut ::core::ffi::c_void,
+    pub reserved: ::core::ffi::c_ulonglong,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2>(
+        [::core::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2>(
         ) - 8usize];
     ["Alignment of CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2"]
-        [::std::mem::align_of::<
+        [::core::mem::align_of::<
             CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2,
         >() - 8usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2::fence"] [:: std :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2 , fence) - 0usize] ;
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2::reserved"] [:: std :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2 , reserved) - 0usize] ;
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2::fence"] [:: core :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2 , fence) - 0usize] ;
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2::reserved"] [:: core :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2 , reserved) - 0usize] ;
 };
 impl Default for CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3656,40 +3656,40 @@ impl Default for CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3 {
-    pub key: ::std::os::raw::c_ulonglong,
+    pub key: ::core::ffi::c_ulonglong,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3>(
+        [::core::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3>(
         ) - 8usize];
     ["Alignment of CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3"]
-        [::std::mem::align_of::<
+        [::core::mem::align_of::<
             CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3,
         >() - 8usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3::key"] [:: std :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3 , key) - 0usize] ;
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3::key"] [:: core :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1__bindgen_ty_3 , key) - 0usize] ;
 };
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1>() - 72usize];
+        [::core::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1>() - 72usize];
     ["Alignment of CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1"]
-        [::std::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1>() - 8usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1::fence"][::std::mem::offset_of!(
+        [::core::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1>() - 8usize];
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1::fence"][::core::mem::offset_of!(
         CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1,
         fence
     ) - 0usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1::nvSciSync"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1::nvSciSync"][::core::mem::offset_of!(
         CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1,
         nvSciSync
     )
         - 8usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1::keyedMutex"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1::keyedMutex"][::core::mem::offset_of!(
         CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1,
         keyedMutex
     )
         - 16usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1::reserved"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1::reserved"][::core::mem::offset_of!(
         CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1,
         reserved
     )
@@ -3697,9 +3697,9 @@ const _: () = {
 };
 impl Default for CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3707,21 +3707,21 @@ impl Default for CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st__bindgen_ty_1 {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st>() - 144usize];
+        [::core::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st>() - 144usize];
     ["Alignment of CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st"]
-        [::std::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st>() - 8usize];
     ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st::params"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st, params) - 0usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st, params) - 0usize];
     ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st::flags"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st, flags) - 72usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st, flags) - 72usize];
     ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st::reserved"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st, reserved) - 76usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st, reserved) - 76usize];
 };
 impl Default for CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3732,8 +3732,8 @@ pub type CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS = CUDA_EXTERNAL_SEMAPHORE_SIGNAL_
 #[derive(Copy, Clone)]
 pub struct CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st {
     pub params: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1,
-    pub flags: ::std::os::raw::c_uint,
-    pub reserved: [::std::os::raw::c_uint; 16usize],
+    pub flags: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 16usize],
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -3741,45 +3741,45 @@ pub struct CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1 {
     pub fence: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1,
     pub nvSciSync: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2,
     pub keyedMutex: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3,
-    pub reserved: [::std::os::raw::c_uint; 10usize],
+    pub reserved: [::core::ffi::c_uint; 10usize],
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1 {
-    pub value: ::std::os::raw::c_ulonglong,
+    pub value: ::core::ffi::c_ulonglong,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1>(
+        [::core::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1>(
         ) - 8usize];
     ["Alignment of CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1"]
-        [::std::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1>(
+        [::core::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1>(
         ) - 8usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1::value"] [:: std :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1 , value) - 0usize] ;
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1::value"] [:: core :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_1 , value) - 0usize] ;
 };
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2 {
-    pub fence: *mut ::std::os::raw::c_void,
-    pub reserved: ::std::os::raw::c_ulonglong,
+    pub fence: *mut ::core::ffi::c_void,
+    pub reserved: ::core::ffi::c_ulonglong,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2>(
+        [::core::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2>(
         ) - 8usize];
     ["Alignment of CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2"]
-        [::std::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2>(
+        [::core::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2>(
         ) - 8usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2::fence"] [:: std :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2 , fence) - 0usize] ;
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2::reserved"] [:: std :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2 , reserved) - 0usize] ;
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2::fence"] [:: core :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2 , fence) - 0usize] ;
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2::reserved"] [:: core :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2 , reserved) - 0usize] ;
 };
 impl Default for CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3787,45 +3787,45 @@ impl Default for CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_t
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3 {
-    pub key: ::std::os::raw::c_ulonglong,
-    pub timeoutMs: ::std::os::raw::c_uint,
+    pub key: ::core::ffi::c_ulonglong,
+    pub timeoutMs: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3>(
+        [::core::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3>(
         ) - 16usize];
     ["Alignment of CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3"]
-        [::std::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3>(
+        [::core::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3>(
         ) - 8usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3::key"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3::key"][::core::mem::offset_of!(
         CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3,
         key
     )
         - 0usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3::timeoutMs"] [:: std :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3 , timeoutMs) - 8usize] ;
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3::timeoutMs"] [:: core :: mem :: offset_of ! (CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1__bindgen_ty_3 , timeoutMs) - 8usize] ;
 };
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1>() - 72usize];
+        [::core::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1>() - 72usize];
     ["Alignment of CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1"]
-        [::std::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1>() - 8usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1::fence"][::std::mem::offset_of!(
+        [::core::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1>() - 8usize];
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1::fence"][::core::mem::offset_of!(
         CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1,
         fence
     ) - 0usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1::nvSciSync"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1::nvSciSync"][::core::mem::offset_of!(
         CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1,
         nvSciSync
     )
         - 8usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1::keyedMutex"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1::keyedMutex"][::core::mem::offset_of!(
         CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1,
         keyedMutex
     )
         - 16usize];
-    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1::reserved"][::std::mem::offset_of!(
+    ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1::reserved"][::core::mem::offset_of!(
         CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1,
         reserved
     )
@@ -3833,9 +3833,9 @@ const _: () = {
 };
 impl Default for CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3843,21 +3843,21 @@ impl Default for CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st__bindgen_ty_1 {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st"]
-        [::std::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st>() - 144usize];
+        [::core::mem::size_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st>() - 144usize];
     ["Alignment of CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st"]
-        [::std::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st>() - 8usize];
     ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st::params"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st, params) - 0usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st, params) - 0usize];
     ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st::flags"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st, flags) - 72usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st, flags) - 72usize];
     ["Offset of field: CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st::reserved"]
-        [::std::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st, reserved) - 76usize];
+        [::core::mem::offset_of!(CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st, reserved) - 76usize];
 };
 impl Default for CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3869,26 +3869,26 @@ pub type CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS = CUDA_EXTERNAL_SEMAPHORE_WAIT_PARA
 pub struct CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st {
     pub extSemArray: *mut CUexternalSemaphore,
     pub paramsArray: *const CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS,
-    pub numExtSems: ::std::os::raw::c_uint,
+    pub numExtSems: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st"]
-        [::std::mem::size_of::<CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st>() - 24usize];
+        [::core::mem::size_of::<CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st>() - 24usize];
     ["Alignment of CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st"]
-        [::std::mem::align_of::<CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st>() - 8usize];
     ["Offset of field: CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st::extSemArray"]
-        [::std::mem::offset_of!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st, extSemArray) - 0usize];
+        [::core::mem::offset_of!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st, extSemArray) - 0usize];
     ["Offset of field: CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st::paramsArray"]
-        [::std::mem::offset_of!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st, paramsArray) - 8usize];
+        [::core::mem::offset_of!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st, paramsArray) - 8usize];
     ["Offset of field: CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st::numExtSems"]
-        [::std::mem::offset_of!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st, numExtSems) - 16usize];
+        [::core::mem::offset_of!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st, numExtSems) - 16usize];
 };
 impl Default for CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3900,26 +3900,26 @@ pub type CUDA_EXT_SEM_SIGNAL_NODE_PARAMS = CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v1;
 pub struct CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st {
     pub extSemArray: *mut CUexternalSemaphore,
     pub paramsArray: *const CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS,
-    pub numExtSems: ::std::os::raw::c_uint,
+    pub numExtSems: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st"]
-        [::std::mem::size_of::<CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st>() - 24usize];
+        [::core::mem::size_of::<CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st>() - 24usize];
     ["Alignment of CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st"]
-        [::std::mem::align_of::<CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st>() - 8usize];
     ["Offset of field: CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st::extSemArray"]
-        [::std::mem::offset_of!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st, extSemArray) - 0usize];
+        [::core::mem::offset_of!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st, extSemArray) - 0usize];
     ["Offset of field: CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st::paramsArray"]
-        [::std::mem::offset_of!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st, paramsArray) - 8usize];
+        [::core::mem::offset_of!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st, paramsArray) - 8usize];
     ["Offset of field: CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st::numExtSems"]
-        [::std::mem::offset_of!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st, numExtSems) - 16usize];
+        [::core::mem::offset_of!(CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st, numExtSems) - 16usize];
 };
 impl Default for CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3930,26 +3930,26 @@ pub type CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2 = CUDA_EXT_SEM_SIGNAL_NODE_PARAMS_v2
 pub struct CUDA_EXT_SEM_WAIT_NODE_PARAMS_st {
     pub extSemArray: *mut CUexternalSemaphore,
     pub paramsArray: *const CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS,
-    pub numExtSems: ::std::os::raw::c_uint,
+    pub numExtSems: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXT_SEM_WAIT_NODE_PARAMS_st"]
-        [::std::mem::size_of::<CUDA_EXT_SEM_WAIT_NODE_PARAMS_st>() - 24usize];
+        [::core::mem::size_of::<CUDA_EXT_SEM_WAIT_NODE_PARAMS_st>() - 24usize];
     ["Alignment of CUDA_EXT_SEM_WAIT_NODE_PARAMS_st"]
-        [::std::mem::align_of::<CUDA_EXT_SEM_WAIT_NODE_PARAMS_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_EXT_SEM_WAIT_NODE_PARAMS_st>() - 8usize];
     ["Offset of field: CUDA_EXT_SEM_WAIT_NODE_PARAMS_st::extSemArray"]
-        [::std::mem::offset_of!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_st, extSemArray) - 0usize];
+        [::core::mem::offset_of!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_st, extSemArray) - 0usize];
     ["Offset of field: CUDA_EXT_SEM_WAIT_NODE_PARAMS_st::paramsArray"]
-        [::std::mem::offset_of!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_st, paramsArray) - 8usize];
+        [::core::mem::offset_of!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_st, paramsArray) - 8usize];
     ["Offset of field: CUDA_EXT_SEM_WAIT_NODE_PARAMS_st::numExtSems"]
-        [::std::mem::offset_of!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_st, numExtSems) - 16usize];
+        [::core::mem::offset_of!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_st, numExtSems) - 16usize];
 };
 impl Default for CUDA_EXT_SEM_WAIT_NODE_PARAMS_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -3961,32 +3961,32 @@ pub type CUDA_EXT_SEM_WAIT_NODE_PARAMS = CUDA_EXT_SEM_WAIT_NODE_PARAMS_v1;
 pub struct CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st {
     pub extSemArray: *mut CUexternalSemaphore,
     pub paramsArray: *const CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS,
-    pub numExtSems: ::std::os::raw::c_uint,
+    pub numExtSems: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st"]
-        [::std::mem::size_of::<CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st>() - 24usize];
+        [::core::mem::size_of::<CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st>() - 24usize];
     ["Alignment of CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st"]
-        [::std::mem::align_of::<CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st>() - 8usize];
     ["Offset of field: CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st::extSemArray"]
-        [::std::mem::offset_of!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st, extSemArray) - 0usize];
+        [::core::mem::offset_of!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st, extSemArray) - 0usize];
     ["Offset of field: CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st::paramsArray"]
-        [::std::mem::offset_of!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st, paramsArray) - 8usize];
+        [::core::mem::offset_of!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st, paramsArray) - 8usize];
     ["Offset of field: CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st::numExtSems"]
-        [::std::mem::offset_of!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st, numExtSems) - 16usize];
+        [::core::mem::offset_of!(CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st, numExtSems) - 16usize];
 };
 impl Default for CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 pub type CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2 = CUDA_EXT_SEM_WAIT_NODE_PARAMS_v2_st;
-pub type CUmemGenericAllocationHandle_v1 = ::std::os::raw::c_ulonglong;
+pub type CUmemGenericAllocationHandle_v1 = ::core::ffi::c_ulonglong;
 pub type CUmemGenericAllocationHandle = CUmemGenericAllocationHandle_v1;
 #[repr(i32)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
@@ -4071,10 +4071,10 @@ pub struct CUarrayMapInfo_st {
     pub memOperationType: CUmemOperationType,
     pub memHandleType: CUmemHandleType,
     pub memHandle: CUarrayMapInfo_st__bindgen_ty_3,
-    pub offset: ::std::os::raw::c_ulonglong,
-    pub deviceBitMask: ::std::os::raw::c_uint,
-    pub flags: ::std::os::raw::c_uint,
-    pub reserved: [::std::os::raw::c_uint; 2usize],
+    pub offset: ::core::ffi::c_ulonglong,
+    pub deviceBitMask: ::core::ffi::c_uint,
+    pub flags: ::core::ffi::c_uint,
+    pub reserved: [::core::ffi::c_uint; 2usize],
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -4085,19 +4085,19 @@ pub union CUarrayMapInfo_st__bindgen_ty_1 {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUarrayMapInfo_st__bindgen_ty_1"]
-        [::std::mem::size_of::<CUarrayMapInfo_st__bindgen_ty_1>() - 8usize];
+        [::core::mem::size_of::<CUarrayMapInfo_st__bindgen_ty_1>() - 8usize];
     ["Alignment of CUarrayMapInfo_st__bindgen_ty_1"]
-        [::std::mem::align_of::<CUarrayMapInfo_st__bindgen_ty_1>() - 8usize];
+        [::core::mem::align_of::<CUarrayMapInfo_st__bindgen_ty_1>() - 8usize];
     ["Offset of field: CUarrayMapInfo_st__bindgen_ty_1::mipmap"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_1, mipmap) - 0usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_1, mipmap) - 0usize];
     ["Offset of field: CUarrayMapInfo_st__bindgen_ty_1::array"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_1, array) - 0usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_1, array) - 0usize];
 };
 impl Default for CUarrayMapInfo_st__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4111,40 +4111,40 @@ pub union CUarrayMapInfo_st__bindgen_ty_2 {
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1 {
-    pub level: ::std::os::raw::c_uint,
-    pub layer: ::std::os::raw::c_uint,
-    pub offsetX: ::std::os::raw::c_uint,
-    pub offsetY: ::std::os::raw::c_uint,
-    pub offsetZ: ::std::os::raw::c_uint,
-    pub extentWidth: ::std::os::raw::c_uint,
-    pub extentHeight: ::std::os::raw::c_uint,
-    pub extentDepth: ::std::os::raw::c_uint,
+    pub level: ::core::ffi::c_uint,
+    pub layer: ::core::ffi::c_uint,
+    pub offsetX: ::core::ffi::c_uint,
+    pub offsetY: ::core::ffi::c_uint,
+    pub offsetZ: ::core::ffi::c_uint,
+    pub extentWidth: ::core::ffi::c_uint,
+    pub extentHeight: ::core::ffi::c_uint,
+    pub extentDepth: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1"]
-        [::std::mem::size_of::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1>() - 32usize];
+        [::core::mem::size_of::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1>() - 32usize];
     ["Alignment of CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1"]
-        [::std::mem::align_of::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1>() - 4usize];
+        [::core::mem::align_of::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1>() - 4usize];
     ["Offset of field: CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1::level"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, level) - 0usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, level) - 0usize];
     ["Offset of field: CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1::layer"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, layer) - 4usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, layer) - 4usize];
     ["Offset of field: CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1::offsetX"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, offsetX) - 8usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, offsetX) - 8usize];
     ["Offset of field: CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1::offsetY"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, offsetY) - 12usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, offsetY) - 12usize];
     ["Offset of field: CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1::offsetZ"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, offsetZ) - 16usize];
-    ["Offset of field: CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1::extentWidth"][::std::mem::offset_of!(
+        [::core::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1, offsetZ) - 16usize];
+    ["Offset of field: CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1::extentWidth"][::core::mem::offset_of!(
         CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1,
         extentWidth
     ) - 20usize];
-    ["Offset of field: CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1::extentHeight"][::std::mem::offset_of!(
+    ["Offset of field: CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1::extentHeight"][::core::mem::offset_of!(
         CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1,
         extentHeight
     ) - 24usize];
-    ["Offset of field: CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1::extentDepth"][::std::mem::offset_of!(
+    ["Offset of field: CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1::extentDepth"][::core::mem::offset_of!(
         CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_1,
         extentDepth
     ) - 28usize];
@@ -4152,39 +4152,39 @@ const _: () = {
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2 {
-    pub layer: ::std::os::raw::c_uint,
-    pub offset: ::std::os::raw::c_ulonglong,
-    pub size: ::std::os::raw::c_ulonglong,
+    pub layer: ::core::ffi::c_uint,
+    pub offset: ::core::ffi::c_ulonglong,
+    pub size: ::core::ffi::c_ulonglong,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2"]
-        [::std::mem::size_of::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2>() - 24usize];
+        [::core::mem::size_of::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2>() - 24usize];
     ["Alignment of CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2"]
-        [::std::mem::align_of::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2>() - 8usize];
+        [::core::mem::align_of::<CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2>() - 8usize];
     ["Offset of field: CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2::layer"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2, layer) - 0usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2, layer) - 0usize];
     ["Offset of field: CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2::offset"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2, offset) - 8usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2, offset) - 8usize];
     ["Offset of field: CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2::size"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2, size) - 16usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2__bindgen_ty_2, size) - 16usize];
 };
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUarrayMapInfo_st__bindgen_ty_2"]
-        [::std::mem::size_of::<CUarrayMapInfo_st__bindgen_ty_2>() - 32usize];
+        [::core::mem::size_of::<CUarrayMapInfo_st__bindgen_ty_2>() - 32usize];
     ["Alignment of CUarrayMapInfo_st__bindgen_ty_2"]
-        [::std::mem::align_of::<CUarrayMapInfo_st__bindgen_ty_2>() - 8usize];
+        [::core::mem::align_of::<CUarrayMapInfo_st__bindgen_ty_2>() - 8usize];
     ["Offset of field: CUarrayMapInfo_st__bindgen_ty_2::sparseLevel"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2, sparseLevel) - 0usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2, sparseLevel) - 0usize];
     ["Offset of field: CUarrayMapInfo_st__bindgen_ty_2::miptail"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2, miptail) - 0usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_2, miptail) - 0usize];
 };
 impl Default for CUarrayMapInfo_st__bindgen_ty_2 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4197,53 +4197,53 @@ pub union CUarrayMapInfo_st__bindgen_ty_3 {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUarrayMapInfo_st__bindgen_ty_3"]
-        [::std::mem::size_of::<CUarrayMapInfo_st__bindgen_ty_3>() - 8usize];
+        [::core::mem::size_of::<CUarrayMapInfo_st__bindgen_ty_3>() - 8usize];
     ["Alignment of CUarrayMapInfo_st__bindgen_ty_3"]
-        [::std::mem::align_of::<CUarrayMapInfo_st__bindgen_ty_3>() - 8usize];
+        [::core::mem::align_of::<CUarrayMapInfo_st__bindgen_ty_3>() - 8usize];
     ["Offset of field: CUarrayMapInfo_st__bindgen_ty_3::memHandle"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_3, memHandle) - 0usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st__bindgen_ty_3, memHandle) - 0usize];
 };
 impl Default for CUarrayMapInfo_st__bindgen_ty_3 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUarrayMapInfo_st"][::std::mem::size_of::<CUarrayMapInfo_st>() - 96usize];
-    ["Alignment of CUarrayMapInfo_st"][::std::mem::align_of::<CUarrayMapInfo_st>() - 8usize];
+    ["Size of CUarrayMapInfo_st"][::core::mem::size_of::<CUarrayMapInfo_st>() - 96usize];
+    ["Alignment of CUarrayMapInfo_st"][::core::mem::align_of::<CUarrayMapInfo_st>() - 8usize];
     ["Offset of field: CUarrayMapInfo_st::resourceType"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st, resourceType) - 0usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st, resourceType) - 0usize];
     ["Offset of field: CUarrayMapInfo_st::resource"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st, resource) - 8usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st, resource) - 8usize];
     ["Offset of field: CUarrayMapInfo_st::subresourceType"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st, subresourceType) - 16usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st, subresourceType) - 16usize];
     ["Offset of field: CUarrayMapInfo_st::subresource"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st, subresource) - 24usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st, subresource) - 24usize];
     ["Offset of field: CUarrayMapInfo_st::memOperationType"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st, memOperationType) - 56usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st, memOperationType) - 56usize];
     ["Offset of field: CUarrayMapInfo_st::memHandleType"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st, memHandleType) - 60usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st, memHandleType) - 60usize];
     ["Offset of field: CUarrayMapInfo_st::memHandle"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st, memHandle) - 64usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st, memHandle) - 64usize];
     ["Offset of field: CUarrayMapInfo_st::offset"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st, offset) - 72usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st, offset) - 72usize];
     ["Offset of field: CUarrayMapInfo_st::deviceBitMask"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st, deviceBitMask) - 80usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st, deviceBitMask) - 80usize];
     ["Offset of field: CUarrayMapInfo_st::flags"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st, flags) - 84usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st, flags) - 84usize];
     ["Offset of field: CUarrayMapInfo_st::reserved"]
-        [::std::mem::offset_of!(CUarrayMapInfo_st, reserved) - 88usize];
+        [::core::mem::offset_of!(CUarrayMapInfo_st, reserved) - 88usize];
 };
 impl Default for CUarrayMapInfo_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4254,22 +4254,22 @@ pub type CUarrayMapInfo = CUarrayMapInfo_v1;
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUmemLocation_st {
     pub type_: CUmemLocationType,
-    pub id: ::std::os::raw::c_int,
+    pub id: ::core::ffi::c_int,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUmemLocation_st"][::std::mem::size_of::<CUmemLocation_st>() - 8usize];
-    ["Alignment of CUmemLocation_st"][::std::mem::align_of::<CUmemLocation_st>() - 4usize];
+    ["Size of CUmemLocation_st"][::core::mem::size_of::<CUmemLocation_st>() - 8usize];
+    ["Alignment of CUmemLocation_st"][::core::mem::align_of::<CUmemLocation_st>() - 4usize];
     ["Offset of field: CUmemLocation_st::type_"]
-        [::std::mem::offset_of!(CUmemLocation_st, type_) - 0usize];
+        [::core::mem::offset_of!(CUmemLocation_st, type_) - 0usize];
     ["Offset of field: CUmemLocation_st::id"]
-        [::std::mem::offset_of!(CUmemLocation_st, id) - 4usize];
+        [::core::mem::offset_of!(CUmemLocation_st, id) - 4usize];
 };
 impl Default for CUmemLocation_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4289,55 +4289,55 @@ pub struct CUmemAllocationProp_st {
     pub type_: CUmemAllocationType,
     pub requestedHandleTypes: CUmemAllocationHandleType,
     pub location: CUmemLocation,
-    pub win32HandleMetaData: *mut ::std::os::raw::c_void,
+    pub win32HandleMetaData: *mut ::core::ffi::c_void,
     pub allocFlags: CUmemAllocationProp_st__bindgen_ty_1,
 }
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUmemAllocationProp_st__bindgen_ty_1 {
-    pub compressionType: ::std::os::raw::c_uchar,
-    pub gpuDirectRDMACapable: ::std::os::raw::c_uchar,
-    pub usage: ::std::os::raw::c_ushort,
-    pub reserved: [::std::os::raw::c_uchar; 4usize],
+    pub compressionType: ::core::ffi::c_uchar,
+    pub gpuDirectRDMACapable: ::core::ffi::c_uchar,
+    pub usage: ::core::ffi::c_ushort,
+    pub reserved: [::core::ffi::c_uchar; 4usize],
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUmemAllocationProp_st__bindgen_ty_1"]
-        [::std::mem::size_of::<CUmemAllocationProp_st__bindgen_ty_1>() - 8usize];
+        [::core::mem::size_of::<CUmemAllocationProp_st__bindgen_ty_1>() - 8usize];
     ["Alignment of CUmemAllocationProp_st__bindgen_ty_1"]
-        [::std::mem::align_of::<CUmemAllocationProp_st__bindgen_ty_1>() - 2usize];
+        [::core::mem::align_of::<CUmemAllocationProp_st__bindgen_ty_1>() - 2usize];
     ["Offset of field: CUmemAllocationProp_st__bindgen_ty_1::compressionType"]
-        [::std::mem::offset_of!(CUmemAllocationProp_st__bindgen_ty_1, compressionType) - 0usize];
-    ["Offset of field: CUmemAllocationProp_st__bindgen_ty_1::gpuDirectRDMACapable"][::std::mem::offset_of!(
+        [::core::mem::offset_of!(CUmemAllocationProp_st__bindgen_ty_1, compressionType) - 0usize];
+    ["Offset of field: CUmemAllocationProp_st__bindgen_ty_1::gpuDirectRDMACapable"][::core::mem::offset_of!(
         CUmemAllocationProp_st__bindgen_ty_1,
         gpuDirectRDMACapable
     ) - 1usize];
     ["Offset of field: CUmemAllocationProp_st__bindgen_ty_1::usage"]
-        [::std::mem::offset_of!(CUmemAllocationProp_st__bindgen_ty_1, usage) - 2usize];
+        [::core::mem::offset_of!(CUmemAllocationProp_st__bindgen_ty_1, usage) - 2usize];
     ["Offset of field: CUmemAllocationProp_st__bindgen_ty_1::reserved"]
-        [::std::mem::offset_of!(CUmemAllocationProp_st__bindgen_ty_1, reserved) - 4usize];
+        [::core::mem::offset_of!(CUmemAllocationProp_st__bindgen_ty_1, reserved) - 4usize];
 };
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUmemAllocationProp_st"][::std::mem::size_of::<CUmemAllocationProp_st>() - 32usize];
+    ["Size of CUmemAllocationProp_st"][::core::mem::size_of::<CUmemAllocationProp_st>() - 32usize];
     ["Alignment of CUmemAllocationProp_st"]
-        [::std::mem::align_of::<CUmemAllocationProp_st>() - 8usize];
+        [::core::mem::align_of::<CUmemAllocationProp_st>() - 8usize];
     ["Offset of field: CUmemAllocationProp_st::type_"]
-        [::std::mem::offset_of!(CUmemAllocationProp_st, type_) - 0usize];
+        [::core::mem::offset_of!(CUmemAllocationProp_st, type_) - 0usize];
     ["Offset of field: CUmemAllocationProp_st::requestedHandleTypes"]
-        [::std::mem::offset_of!(CUmemAllocationProp_st, requestedHandleTypes) - 4usize];
+        [::core::mem::offset_of!(CUmemAllocationProp_st, requestedHandleTypes) - 4usize];
     ["Offset of field: CUmemAllocationProp_st::location"]
-        [::std::mem::offset_of!(CUmemAllocationProp_st, location) - 8usize];
+        [::core::mem::offset_of!(CUmemAllocationProp_st, location) - 8usize];
     ["Offset of field: CUmemAllocationProp_st::win32HandleMetaData"]
-        [::std::mem::offset_of!(CUmemAllocationProp_st, win32HandleMetaData) - 16usize];
+        [::core::mem::offset_of!(CUmemAllocationProp_st, win32HandleMetaData) - 16usize];
     ["Offset of field: CUmemAllocationProp_st::allocFlags"]
-        [::std::mem::offset_of!(CUmemAllocationProp_st, allocFlags) - 24usize];
+        [::core::mem::offset_of!(CUmemAllocationProp_st, allocFlags) - 24usize];
 };
 impl Default for CUmemAllocationProp_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4354,25 +4354,25 @@ pub use self::CUmulticastGranularity_flags_enum as CUmulticastGranularity_flags;
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUmulticastObjectProp_st {
-    pub numDevices: ::std::os::raw::c_uint,
+    pub numDevices: ::core::ffi::c_uint,
     pub size: usize,
-    pub handleTypes: ::std::os::raw::c_ulonglong,
-    pub flags: ::std::os::raw::c_ulonglong,
+    pub handleTypes: ::core::ffi::c_ulonglong,
+    pub flags: ::core::ffi::c_ulonglong,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUmulticastObjectProp_st"]
-        [::std::mem::size_of::<CUmulticastObjectProp_st>() - 32usize];
+        [::core::mem::size_of::<CUmulticastObjectProp_st>() - 32usize];
     ["Alignment of CUmulticastObjectProp_st"]
-        [::std::mem::align_of::<CUmulticastObjectProp_st>() - 8usize];
+        [::core::mem::align_of::<CUmulticastObjectProp_st>() - 8usize];
     ["Offset of field: CUmulticastObjectProp_st::numDevices"]
-        [::std::mem::offset_of!(CUmulticastObjectProp_st, numDevices) - 0usize];
+        [::core::mem::offset_of!(CUmulticastObjectProp_st, numDevices) - 0usize];
     ["Offset of field: CUmulticastObjectProp_st::size"]
-        [::std::mem::offset_of!(CUmulticastObjectProp_st, size) - 8usize];
+        [::core::mem::offset_of!(CUmulticastObjectProp_st, size) - 8usize];
     ["Offset of field: CUmulticastObjectProp_st::handleTypes"]
-        [::std::mem::offset_of!(CUmulticastObjectProp_st, handleTypes) - 16usize];
+        [::core::mem::offset_of!(CUmulticastObjectProp_st, handleTypes) - 16usize];
     ["Offset of field: CUmulticastObjectProp_st::flags"]
-        [::std::mem::offset_of!(CUmulticastObjectProp_st, flags) - 24usize];
+        [::core::mem::offset_of!(CUmulticastObjectProp_st, flags) - 24usize];
 };
 pub type CUmulticastObjectProp_v1 = CUmulticastObjectProp_st;
 pub type CUmulticastObjectProp = CUmulticastObjectProp_v1;
@@ -4384,18 +4384,18 @@ pub struct CUmemAccessDesc_st {
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUmemAccessDesc_st"][::std::mem::size_of::<CUmemAccessDesc_st>() - 12usize];
-    ["Alignment of CUmemAccessDesc_st"][::std::mem::align_of::<CUmemAccessDesc_st>() - 4usize];
+    ["Size of CUmemAccessDesc_st"][::core::mem::size_of::<CUmemAccessDesc_st>() - 12usize];
+    ["Alignment of CUmemAccessDesc_st"][::core::mem::align_of::<CUmemAccessDesc_st>() - 4usize];
     ["Offset of field: CUmemAccessDesc_st::location"]
-        [::std::mem::offset_of!(CUmemAccessDesc_st, location) - 0usize];
+        [::core::mem::offset_of!(CUmemAccessDesc_st, location) - 0usize];
     ["Offset of field: CUmemAccessDesc_st::flags"]
-        [::std::mem::offset_of!(CUmemAccessDesc_st, flags) - 8usize];
+        [::core::mem::offset_of!(CUmemAccessDesc_st, flags) - 8usize];
 };
 impl Default for CUmemAccessDesc_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4426,21 +4426,21 @@ pub struct CUgraphExecUpdateResultInfo_st {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUgraphExecUpdateResultInfo_st"]
-        [::std::mem::size_of::<CUgraphExecUpdateResultInfo_st>() - 24usize];
+        [::core::mem::size_of::<CUgraphExecUpdateResultInfo_st>() - 24usize];
     ["Alignment of CUgraphExecUpdateResultInfo_st"]
-        [::std::mem::align_of::<CUgraphExecUpdateResultInfo_st>() - 8usize];
+        [::core::mem::align_of::<CUgraphExecUpdateResultInfo_st>() - 8usize];
     ["Offset of field: CUgraphExecUpdateResultInfo_st::result"]
-        [::std::mem::offset_of!(CUgraphExecUpdateResultInfo_st, result) - 0usize];
+        [::core::mem::offset_of!(CUgraphExecUpdateResultInfo_st, result) - 0usize];
     ["Offset of field: CUgraphExecUpdateResultInfo_st::errorNode"]
-        [::std::mem::offset_of!(CUgraphExecUpdateResultInfo_st, errorNode) - 8usize];
+        [::core::mem::offset_of!(CUgraphExecUpdateResultInfo_st, errorNode) - 8usize];
     ["Offset of field: CUgraphExecUpdateResultInfo_st::errorFromNode"]
-        [::std::mem::offset_of!(CUgraphExecUpdateResultInfo_st, errorFromNode) - 16usize];
+        [::core::mem::offset_of!(CUgraphExecUpdateResultInfo_st, errorFromNode) - 16usize];
 };
 impl Default for CUgraphExecUpdateResultInfo_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4466,32 +4466,32 @@ pub struct CUmemPoolProps_st {
     pub allocType: CUmemAllocationType,
     pub handleTypes: CUmemAllocationHandleType,
     pub location: CUmemLocation,
-    pub win32SecurityAttributes: *mut ::std::os::raw::c_void,
+    pub win32SecurityAttributes: *mut ::core::ffi::c_void,
     pub maxSize: usize,
-    pub reserved: [::std::os::raw::c_uchar; 56usize],
+    pub reserved: [::core::ffi::c_uchar; 56usize],
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUmemPoolProps_st"][::std::mem::size_of::<CUmemPoolProps_st>() - 88usize];
-    ["Alignment of CUmemPoolProps_st"][::std::mem::align_of::<CUmemPoolProps_st>() - 8usize];
+    ["Size of CUmemPoolProps_st"][::core::mem::size_of::<CUmemPoolProps_st>() - 88usize];
+    ["Alignment of CUmemPoolProps_st"][::core::mem::align_of::<CUmemPoolProps_st>() - 8usize];
     ["Offset of field: CUmemPoolProps_st::allocType"]
-        [::std::mem::offset_of!(CUmemPoolProps_st, allocType) - 0usize];
+        [::core::mem::offset_of!(CUmemPoolProps_st, allocType) - 0usize];
     ["Offset of field: CUmemPoolProps_st::handleTypes"]
-        [::std::mem::offset_of!(CUmemPoolProps_st, handleTypes) - 4usize];
+        [::core::mem::offset_of!(CUmemPoolProps_st, handleTypes) - 4usize];
     ["Offset of field: CUmemPoolProps_st::location"]
-        [::std::mem::offset_of!(CUmemPoolProps_st, location) - 8usize];
+        [::core::mem::offset_of!(CUmemPoolProps_st, location) - 8usize];
     ["Offset of field: CUmemPoolProps_st::win32SecurityAttributes"]
-        [::std::mem::offset_of!(CUmemPoolProps_st, win32SecurityAttributes) - 16usize];
+        [::core::mem::offset_of!(CUmemPoolProps_st, win32SecurityAttributes) - 16usize];
     ["Offset of field: CUmemPoolProps_st::maxSize"]
-        [::std::mem::offset_of!(CUmemPoolProps_st, maxSize) - 24usize];
+        [::core::mem::offset_of!(CUmemPoolProps_st, maxSize) - 24usize];
     ["Offset of field: CUmemPoolProps_st::reserved"]
-        [::std::mem::offset_of!(CUmemPoolProps_st, reserved) - 32usize];
+        [::core::mem::offset_of!(CUmemPoolProps_st, reserved) - 32usize];
 };
 impl Default for CUmemPoolProps_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4501,22 +4501,22 @@ pub type CUmemPoolProps = CUmemPoolProps_v1;
 #[repr(C)]
 #[derive(Debug, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUmemPoolPtrExportData_st {
-    pub reserved: [::std::os::raw::c_uchar; 64usize],
+    pub reserved: [::core::ffi::c_uchar; 64usize],
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUmemPoolPtrExportData_st"]
-        [::std::mem::size_of::<CUmemPoolPtrExportData_st>() - 64usize];
+        [::core::mem::size_of::<CUmemPoolPtrExportData_st>() - 64usize];
     ["Alignment of CUmemPoolPtrExportData_st"]
-        [::std::mem::align_of::<CUmemPoolPtrExportData_st>() - 1usize];
+        [::core::mem::align_of::<CUmemPoolPtrExportData_st>() - 1usize];
     ["Offset of field: CUmemPoolPtrExportData_st::reserved"]
-        [::std::mem::offset_of!(CUmemPoolPtrExportData_st, reserved) - 0usize];
+        [::core::mem::offset_of!(CUmemPoolPtrExportData_st, reserved) - 0usize];
 };
 impl Default for CUmemPoolPtrExportData_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4535,25 +4535,25 @@ pub struct CUDA_MEM_ALLOC_NODE_PARAMS_v1_st {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_MEM_ALLOC_NODE_PARAMS_v1_st"]
-        [::std::mem::size_of::<CUDA_MEM_ALLOC_NODE_PARAMS_v1_st>() - 120usize];
+        [::core::mem::size_of::<CUDA_MEM_ALLOC_NODE_PARAMS_v1_st>() - 120usize];
     ["Alignment of CUDA_MEM_ALLOC_NODE_PARAMS_v1_st"]
-        [::std::mem::align_of::<CUDA_MEM_ALLOC_NODE_PARAMS_v1_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_MEM_ALLOC_NODE_PARAMS_v1_st>() - 8usize];
     ["Offset of field: CUDA_MEM_ALLOC_NODE_PARAMS_v1_st::poolProps"]
-        [::std::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st, poolProps) - 0usize];
+        [::core::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st, poolProps) - 0usize];
     ["Offset of field: CUDA_MEM_ALLOC_NODE_PARAMS_v1_st::accessDescs"]
-        [::std::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st, accessDescs) - 88usize];
+        [::core::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st, accessDescs) - 88usize];
     ["Offset of field: CUDA_MEM_ALLOC_NODE_PARAMS_v1_st::accessDescCount"]
-        [::std::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st, accessDescCount) - 96usize];
+        [::core::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st, accessDescCount) - 96usize];
     ["Offset of field: CUDA_MEM_ALLOC_NODE_PARAMS_v1_st::bytesize"]
-        [::std::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st, bytesize) - 104usize];
+        [::core::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st, bytesize) - 104usize];
     ["Offset of field: CUDA_MEM_ALLOC_NODE_PARAMS_v1_st::dptr"]
-        [::std::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st, dptr) - 112usize];
+        [::core::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v1_st, dptr) - 112usize];
 };
 impl Default for CUDA_MEM_ALLOC_NODE_PARAMS_v1_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4572,25 +4572,25 @@ pub struct CUDA_MEM_ALLOC_NODE_PARAMS_v2_st {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_MEM_ALLOC_NODE_PARAMS_v2_st"]
-        [::std::mem::size_of::<CUDA_MEM_ALLOC_NODE_PARAMS_v2_st>() - 120usize];
+        [::core::mem::size_of::<CUDA_MEM_ALLOC_NODE_PARAMS_v2_st>() - 120usize];
     ["Alignment of CUDA_MEM_ALLOC_NODE_PARAMS_v2_st"]
-        [::std::mem::align_of::<CUDA_MEM_ALLOC_NODE_PARAMS_v2_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_MEM_ALLOC_NODE_PARAMS_v2_st>() - 8usize];
     ["Offset of field: CUDA_MEM_ALLOC_NODE_PARAMS_v2_st::poolProps"]
-        [::std::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st, poolProps) - 0usize];
+        [::core::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st, poolProps) - 0usize];
     ["Offset of field: CUDA_MEM_ALLOC_NODE_PARAMS_v2_st::accessDescs"]
-        [::std::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st, accessDescs) - 88usize];
+        [::core::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st, accessDescs) - 88usize];
     ["Offset of field: CUDA_MEM_ALLOC_NODE_PARAMS_v2_st::accessDescCount"]
-        [::std::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st, accessDescCount) - 96usize];
+        [::core::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st, accessDescCount) - 96usize];
     ["Offset of field: CUDA_MEM_ALLOC_NODE_PARAMS_v2_st::bytesize"]
-        [::std::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st, bytesize) - 104usize];
+        [::core::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st, bytesize) - 104usize];
     ["Offset of field: CUDA_MEM_ALLOC_NODE_PARAMS_v2_st::dptr"]
-        [::std::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st, dptr) - 112usize];
+        [::core::mem::offset_of!(CUDA_MEM_ALLOC_NODE_PARAMS_v2_st, dptr) - 112usize];
 };
 impl Default for CUDA_MEM_ALLOC_NODE_PARAMS_v2_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4604,11 +4604,11 @@ pub struct CUDA_MEM_FREE_NODE_PARAMS_st {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_MEM_FREE_NODE_PARAMS_st"]
-        [::std::mem::size_of::<CUDA_MEM_FREE_NODE_PARAMS_st>() - 8usize];
+        [::core::mem::size_of::<CUDA_MEM_FREE_NODE_PARAMS_st>() - 8usize];
     ["Alignment of CUDA_MEM_FREE_NODE_PARAMS_st"]
-        [::std::mem::align_of::<CUDA_MEM_FREE_NODE_PARAMS_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_MEM_FREE_NODE_PARAMS_st>() - 8usize];
     ["Offset of field: CUDA_MEM_FREE_NODE_PARAMS_st::dptr"]
-        [::std::mem::offset_of!(CUDA_MEM_FREE_NODE_PARAMS_st, dptr) - 0usize];
+        [::core::mem::offset_of!(CUDA_MEM_FREE_NODE_PARAMS_st, dptr) - 0usize];
 };
 pub type CUDA_MEM_FREE_NODE_PARAMS = CUDA_MEM_FREE_NODE_PARAMS_st;
 #[repr(i32)]
@@ -4628,17 +4628,17 @@ pub struct CUDA_CHILD_GRAPH_NODE_PARAMS_st {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_CHILD_GRAPH_NODE_PARAMS_st"]
-        [::std::mem::size_of::<CUDA_CHILD_GRAPH_NODE_PARAMS_st>() - 8usize];
+        [::core::mem::size_of::<CUDA_CHILD_GRAPH_NODE_PARAMS_st>() - 8usize];
     ["Alignment of CUDA_CHILD_GRAPH_NODE_PARAMS_st"]
-        [::std::mem::align_of::<CUDA_CHILD_GRAPH_NODE_PARAMS_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_CHILD_GRAPH_NODE_PARAMS_st>() - 8usize];
     ["Offset of field: CUDA_CHILD_GRAPH_NODE_PARAMS_st::graph"]
-        [::std::mem::offset_of!(CUDA_CHILD_GRAPH_NODE_PARAMS_st, graph) - 0usize];
+        [::core::mem::offset_of!(CUDA_CHILD_GRAPH_NODE_PARAMS_st, graph) - 0usize];
 };
 impl Default for CUDA_CHILD_GRAPH_NODE_PARAMS_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4652,17 +4652,17 @@ pub struct CUDA_EVENT_RECORD_NODE_PARAMS_st {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EVENT_RECORD_NODE_PARAMS_st"]
-        [::std::mem::size_of::<CUDA_EVENT_RECORD_NODE_PARAMS_st>() - 8usize];
+        [::core::mem::size_of::<CUDA_EVENT_RECORD_NODE_PARAMS_st>() - 8usize];
     ["Alignment of CUDA_EVENT_RECORD_NODE_PARAMS_st"]
-        [::std::mem::align_of::<CUDA_EVENT_RECORD_NODE_PARAMS_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_EVENT_RECORD_NODE_PARAMS_st>() - 8usize];
     ["Offset of field: CUDA_EVENT_RECORD_NODE_PARAMS_st::event"]
-        [::std::mem::offset_of!(CUDA_EVENT_RECORD_NODE_PARAMS_st, event) - 0usize];
+        [::core::mem::offset_of!(CUDA_EVENT_RECORD_NODE_PARAMS_st, event) - 0usize];
 };
 impl Default for CUDA_EVENT_RECORD_NODE_PARAMS_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4676,17 +4676,17 @@ pub struct CUDA_EVENT_WAIT_NODE_PARAMS_st {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUDA_EVENT_WAIT_NODE_PARAMS_st"]
-        [::std::mem::size_of::<CUDA_EVENT_WAIT_NODE_PARAMS_st>() - 8usize];
+        [::core::mem::size_of::<CUDA_EVENT_WAIT_NODE_PARAMS_st>() - 8usize];
     ["Alignment of CUDA_EVENT_WAIT_NODE_PARAMS_st"]
-        [::std::mem::align_of::<CUDA_EVENT_WAIT_NODE_PARAMS_st>() - 8usize];
+        [::core::mem::align_of::<CUDA_EVENT_WAIT_NODE_PARAMS_st>() - 8usize];
     ["Offset of field: CUDA_EVENT_WAIT_NODE_PARAMS_st::event"]
-        [::std::mem::offset_of!(CUDA_EVENT_WAIT_NODE_PARAMS_st, event) - 0usize];
+        [::core::mem::offset_of!(CUDA_EVENT_WAIT_NODE_PARAMS_st, event) - 0usize];
 };
 impl Default for CUDA_EVENT_WAIT_NODE_PARAMS_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4696,14 +4696,14 @@ pub type CUDA_EVENT_WAIT_NODE_PARAMS = CUDA_EVENT_WAIT_NODE_PARAMS_st;
 #[derive(Copy, Clone)]
 pub struct CUgraphNodeParams_st {
     pub type_: CUgraphNodeType,
-    pub reserved0: [::std::os::raw::c_int; 3usize],
+    pub reserved0: [::core::ffi::c_int; 3usize],
     pub __bindgen_anon_1: CUgraphNodeParams_st__bindgen_ty_1,
-    pub reserved2: ::std::os::raw::c_longlong,
+    pub reserved2: ::core::ffi::c_longlong,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union CUgraphNodeParams_st__bindgen_ty_1 {
-    pub reserved1: [::std::os::raw::c_longlong; 29usize],
+    pub reserved1: [::core::ffi::c_longlong; 29usize],
     pub kernel: CUDA_KERNEL_NODE_PARAMS_v3,
     pub memcpy: CUDA_MEMCPY_NODE_PARAMS,
     pub memset: CUDA_MEMSET_NODE_PARAMS_v2,
@@ -4721,63 +4721,63 @@ pub union CUgraphNodeParams_st__bindgen_ty_1 {
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUgraphNodeParams_st__bindgen_ty_1"]
-        [::std::mem::size_of::<CUgraphNodeParams_st__bindgen_ty_1>() - 232usize];
+        [::core::mem::size_of::<CUgraphNodeParams_st__bindgen_ty_1>() - 232usize];
     ["Alignment of CUgraphNodeParams_st__bindgen_ty_1"]
-        [::std::mem::align_of::<CUgraphNodeParams_st__bindgen_ty_1>() - 8usize];
+        [::core::mem::align_of::<CUgraphNodeParams_st__bindgen_ty_1>() - 8usize];
     ["Offset of field: CUgraphNodeParams_st__bindgen_ty_1::reserved1"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, reserved1) - 0usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, reserved1) - 0usize];
     ["Offset of field: CUgraphNodeParams_st__bindgen_ty_1::kernel"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, kernel) - 0usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, kernel) - 0usize];
     ["Offset of field: CUgraphNodeParams_st__bindgen_ty_1::memcpy"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, memcpy) - 0usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, memcpy) - 0usize];
     ["Offset of field: CUgraphNodeParams_st__bindgen_ty_1::memset"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, memset) - 0usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, memset) - 0usize];
     ["Offset of field: CUgraphNodeParams_st__bindgen_ty_1::host"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, host) - 0usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, host) - 0usize];
     ["Offset of field: CUgraphNodeParams_st__bindgen_ty_1::graph"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, graph) - 0usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, graph) - 0usize];
     ["Offset of field: CUgraphNodeParams_st__bindgen_ty_1::eventWait"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, eventWait) - 0usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, eventWait) - 0usize];
     ["Offset of field: CUgraphNodeParams_st__bindgen_ty_1::eventRecord"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, eventRecord) - 0usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, eventRecord) - 0usize];
     ["Offset of field: CUgraphNodeParams_st__bindgen_ty_1::extSemSignal"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, extSemSignal) - 0usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, extSemSignal) - 0usize];
     ["Offset of field: CUgraphNodeParams_st__bindgen_ty_1::extSemWait"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, extSemWait) - 0usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, extSemWait) - 0usize];
     ["Offset of field: CUgraphNodeParams_st__bindgen_ty_1::alloc"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, alloc) - 0usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, alloc) - 0usize];
     ["Offset of field: CUgraphNodeParams_st__bindgen_ty_1::free"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, free) - 0usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, free) - 0usize];
     ["Offset of field: CUgraphNodeParams_st__bindgen_ty_1::memOp"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, memOp) - 0usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, memOp) - 0usize];
     ["Offset of field: CUgraphNodeParams_st__bindgen_ty_1::conditional"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, conditional) - 0usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st__bindgen_ty_1, conditional) - 0usize];
 };
 impl Default for CUgraphNodeParams_st__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUgraphNodeParams_st"][::std::mem::size_of::<CUgraphNodeParams_st>() - 256usize];
-    ["Alignment of CUgraphNodeParams_st"][::std::mem::align_of::<CUgraphNodeParams_st>() - 8usize];
+    ["Size of CUgraphNodeParams_st"][::core::mem::size_of::<CUgraphNodeParams_st>() - 256usize];
+    ["Alignment of CUgraphNodeParams_st"][::core::mem::align_of::<CUgraphNodeParams_st>() - 8usize];
     ["Offset of field: CUgraphNodeParams_st::type_"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st, type_) - 0usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st, type_) - 0usize];
     ["Offset of field: CUgraphNodeParams_st::reserved0"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st, reserved0) - 4usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st, reserved0) - 4usize];
     ["Offset of field: CUgraphNodeParams_st::reserved2"]
-        [::std::mem::offset_of!(CUgraphNodeParams_st, reserved2) - 248usize];
+        [::core::mem::offset_of!(CUgraphNodeParams_st, reserved2) - 248usize];
 };
 impl Default for CUgraphNodeParams_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -4861,27 +4861,27 @@ pub enum CUdeviceNumaConfig_enum {
 }
 pub use self::CUdeviceNumaConfig_enum as CUdeviceNumaConfig;
 unsafe extern "C" {
-    pub fn cuGetErrorString(error: CUresult, pStr: *mut *const ::std::os::raw::c_char) -> CUresult;
+    pub fn cuGetErrorString(error: CUresult, pStr: *mut *const ::core::ffi::c_char) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuGetErrorName(error: CUresult, pStr: *mut *const ::std::os::raw::c_char) -> CUresult;
+    pub fn cuGetErrorName(error: CUresult, pStr: *mut *const ::core::ffi::c_char) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuInit(Flags: ::std::os::raw::c_uint) -> CUresult;
+    pub fn cuInit(Flags: ::core::ffi::c_uint) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuDriverGetVersion(driverVersion: *mut ::std::os::raw::c_int) -> CUresult;
+    pub fn cuDriverGetVersion(driverVersion: *mut ::core::ffi::c_int) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuDeviceGet(device: *mut CUdevice, ordinal: ::std::os::raw::c_int) -> CUresult;
+    pub fn cuDeviceGet(device: *mut CUdevice, ordinal: ::core::ffi::c_int) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuDeviceGetCount(count: *mut ::std::os::raw::c_int) -> CUresult;
+    pub fn cuDeviceGetCount(count: *mut ::core::ffi::c_int) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuDeviceGetName(
-        name: *mut ::std::os::raw::c_char,
-        len: ::std::os::raw::c_int,
+        name: *mut ::core::ffi::c_char,
+        len: ::core::ffi::c_int,
         dev: CUdevice,
     ) -> CUresult;
 }
@@ -4893,8 +4893,8 @@ unsafe extern "C" {
 }
 unsafe extern "C" {
     pub fn cuDeviceGetLuid(
-        luid: *mut ::std::os::raw::c_char,
-        deviceNodeMask: *mut ::std::os::raw::c_uint,
+        luid: *mut ::core::ffi::c_char,
+        deviceNodeMask: *mut ::core::ffi::c_uint,
         dev: CUdevice,
     ) -> CUresult;
 }
@@ -4905,22 +4905,22 @@ unsafe extern "C" {
     pub fn cuDeviceGetTexture1DLinearMaxWidth(
         maxWidthInElements: *mut usize,
         format: CUarray_format,
-        numChannels: ::std::os::raw::c_uint,
+        numChannels: ::core::ffi::c_uint,
         dev: CUdevice,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuDeviceGetAttribute(
-        pi: *mut ::std::os::raw::c_int,
+        pi: *mut ::core::ffi::c_int,
         attrib: CUdevice_attribute,
         dev: CUdevice,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuDeviceGetNvSciSyncAttributes(
-        nvSciSyncAttrList: *mut ::std::os::raw::c_void,
+        nvSciSyncAttrList: *mut ::core::ffi::c_void,
         dev: CUdevice,
-        flags: ::std::os::raw::c_int,
+        flags: ::core::ffi::c_int,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -4934,7 +4934,7 @@ unsafe extern "C" {
 }
 unsafe extern "C" {
     pub fn cuDeviceGetExecAffinitySupport(
-        pi: *mut ::std::os::raw::c_int,
+        pi: *mut ::core::ffi::c_int,
         type_: CUexecAffinityType,
         dev: CUdevice,
     ) -> CUresult;
@@ -4950,8 +4950,8 @@ unsafe extern "C" {
 }
 unsafe extern "C" {
     pub fn cuDeviceComputeCapability(
-        major: *mut ::std::os::raw::c_int,
-        minor: *mut ::std::os::raw::c_int,
+        major: *mut ::core::ffi::c_int,
+        minor: *mut ::core::ffi::c_int,
         dev: CUdevice,
     ) -> CUresult;
 }
@@ -4962,13 +4962,13 @@ unsafe extern "C" {
     pub fn cuDevicePrimaryCtxRelease_v2(dev: CUdevice) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuDevicePrimaryCtxSetFlags_v2(dev: CUdevice, flags: ::std::os::raw::c_uint) -> CUresult;
+    pub fn cuDevicePrimaryCtxSetFlags_v2(dev: CUdevice, flags: ::core::ffi::c_uint) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuDevicePrimaryCtxGetState(
         dev: CUdevice,
-        flags: *mut ::std::os::raw::c_uint,
-        active: *mut ::std::os::raw::c_int,
+        flags: *mut ::core::ffi::c_uint,
+        active: *mut ::core::ffi::c_int,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -4977,7 +4977,7 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuCtxCreate_v2(
         pctx: *mut CUcontext,
-        flags: ::std::os::raw::c_uint,
+        flags: ::core::ffi::c_uint,
         dev: CUdevice,
     ) -> CUresult;
 }
@@ -4985,8 +4985,8 @@ unsafe extern "C" {
     pub fn cuCtxCreate_v3(
         pctx: *mut CUcontext,
         paramsArray: *mut CUexecAffinityParam,
-        numParams: ::std::os::raw::c_int,
-        flags: ::std::os::raw::c_uint,
+        numParams: ::core::ffi::c_int,
+        flags: ::core::ffi::c_uint,
         dev: CUdevice,
     ) -> CUresult;
 }
@@ -5009,13 +5009,13 @@ unsafe extern "C" {
     pub fn cuCtxGetDevice(device: *mut CUdevice) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuCtxGetFlags(flags: *mut ::std::os::raw::c_uint) -> CUresult;
+    pub fn cuCtxGetFlags(flags: *mut ::core::ffi::c_uint) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuCtxSetFlags(flags: ::std::os::raw::c_uint) -> CUresult;
+    pub fn cuCtxSetFlags(flags: ::core::ffi::c_uint) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuCtxGetId(ctx: CUcontext, ctxId: *mut ::std::os::raw::c_ulonglong) -> CUresult;
+    pub fn cuCtxGetId(ctx: CUcontext, ctxId: *mut ::core::ffi::c_ulonglong) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuCtxSynchronize() -> CUresult;
@@ -5033,12 +5033,12 @@ unsafe extern "C" {
     pub fn cuCtxSetCacheConfig(config: CUfunc_cache) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuCtxGetApiVersion(ctx: CUcontext, version: *mut ::std::os::raw::c_uint) -> CUresult;
+    pub fn cuCtxGetApiVersion(ctx: CUcontext, version: *mut ::core::ffi::c_uint) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuCtxGetStreamPriorityRange(
-        leastPriority: *mut ::std::os::raw::c_int,
-        greatestPriority: *mut ::std::os::raw::c_int,
+        leastPriority: *mut ::core::ffi::c_int,
+        greatestPriority: *mut ::core::ffi::c_int,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -5051,7 +5051,7 @@ unsafe extern "C" {
     ) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuCtxAttach(pctx: *mut CUcontext, flags: ::std::os::raw::c_uint) -> CUresult;
+    pub fn cuCtxAttach(pctx: *mut CUcontext, flags: ::core::ffi::c_uint) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuCtxDetach(ctx: CUcontext) -> CUresult;
@@ -5063,27 +5063,27 @@ unsafe extern "C" {
     pub fn cuCtxSetSharedMemConfig(config: CUsharedconfig) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuModuleLoad(module: *mut CUmodule, fname: *const ::std::os::raw::c_char) -> CUresult;
+    pub fn cuModuleLoad(module: *mut CUmodule, fname: *const ::core::ffi::c_char) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuModuleLoadData(
         module: *mut CUmodule,
-        image: *const ::std::os::raw::c_void,
+        image: *const ::core::ffi::c_void,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuModuleLoadDataEx(
         module: *mut CUmodule,
-        image: *const ::std::os::raw::c_void,
-        numOptions: ::std::os::raw::c_uint,
+        image: *const ::core::ffi::c_void,
+        numOptions: ::core::ffi::c_uint,
         options: *mut CUjit_option,
-        optionValues: *mut *mut ::std::os::raw::c_void,
+        optionValues: *mut *mut ::core::ffi::c_void,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuModuleLoadFatBinary(
         module: *mut CUmodule,
-        fatCubin: *const ::std::os::raw::c_void,
+        fatCubin: *const ::core::ffi::c_void,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -5103,17 +5103,17 @@ unsafe extern "C" {
     pub fn cuModuleGetFunction(
         hfunc: *mut CUfunction,
         hmod: CUmodule,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
     ) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuModuleGetFunctionCount(count: *mut ::std::os::raw::c_uint, mod_: CUmodule)
+    pub fn cuModuleGetFunctionCount(count: *mut ::core::ffi::c_uint, mod_: CUmodule)
         -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuModuleEnumerateFunctions(
         functions: *mut CUfunction,
-        numFunctions: ::std::os::raw::c_uint,
+        numFunctions: ::core::ffi::c_uint,
         mod_: CUmodule,
     ) -> CUresult;
 }
@@ -5122,14 +5122,14 @@ unsafe extern "C" {
         dptr: *mut CUdeviceptr,
         bytes: *mut usize,
         hmod: CUmodule,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuLinkCreate_v2(
-        numOptions: ::std::os::raw::c_uint,
+        numOptions: ::core::ffi::c_uint,
         options: *mut CUjit_option,
-        optionValues: *mut *mut ::std::os::raw::c_void,
+        optionValues: *mut *mut ::core::ffi::c_void,
         stateOut: *mut CUlinkState,
     ) -> CUresult;
 }
@@ -5137,28 +5137,28 @@ unsafe extern "C" {
     pub fn cuLinkAddData_v2(
         state: CUlinkState,
         type_: CUjitInputType,
-        data: *mut ::std::os::raw::c_void,
+        data: *mut ::core::ffi::c_void,
         size: usize,
-        name: *const ::std::os::raw::c_char,
-        numOptions: ::std::os::raw::c_uint,
+        name: *const ::core::ffi::c_char,
+        numOptions: ::core::ffi::c_uint,
         options: *mut CUjit_option,
-        optionValues: *mut *mut ::std::os::raw::c_void,
+        optionValues: *mut *mut ::core::ffi::c_void,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuLinkAddFile_v2(
         state: CUlinkState,
         type_: CUjitInputType,
-        path: *const ::std::os::raw::c_char,
-        numOptions: ::std::os::raw::c_uint,
+        path: *const ::core::ffi::c_char,
+        numOptions: ::core::ffi::c_uint,
         options: *mut CUjit_option,
-        optionValues: *mut *mut ::std::os::raw::c_void,
+        optionValues: *mut *mut ::core::ffi::c_void,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuLinkComplete(
         state: CUlinkState,
-        cubinOut: *mut *mut ::std::os::raw::c_void,
+        cubinOut: *mut *mut ::core::ffi::c_void,
         sizeOut: *mut usize,
     ) -> CUresult;
 }
@@ -5169,38 +5169,38 @@ unsafe extern "C" {
     pub fn cuModuleGetTexRef(
         pTexRef: *mut CUtexref,
         hmod: CUmodule,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuModuleGetSurfRef(
         pSurfRef: *mut CUsurfref,
         hmod: CUmodule,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuLibraryLoadData(
         library: *mut CUlibrary,
-        code: *const ::std::os::raw::c_void,
+        code: *const ::core::ffi::c_void,
         jitOptions: *mut CUjit_option,
-        jitOptionsValues: *mut *mut ::std::os::raw::c_void,
-        numJitOptions: ::std::os::raw::c_uint,
+        jitOptionsValues: *mut *mut ::core::ffi::c_void,
+        numJitOptions: ::core::ffi::c_uint,
         libraryOptions: *mut CUlibraryOption,
-        libraryOptionValues: *mut *mut ::std::os::raw::c_void,
-        numLibraryOptions: ::std::os::raw::c_uint,
+        libraryOptionValues: *mut *mut ::core::ffi::c_void,
+        numLibraryOptions: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuLibraryLoadFromFile(
         library: *mut CUlibrary,
-        fileName: *const ::std::os::raw::c_char,
+        fileName: *const ::core::ffi::c_char,
         jitOptions: *mut CUjit_option,
-        jitOptionsValues: *mut *mut ::std::os::raw::c_void,
-        numJitOptions: ::std::os::raw::c_uint,
+        jitOptionsValues: *mut *mut ::core::ffi::c_void,
+        numJitOptions: ::core::ffi::c_uint,
         libraryOptions: *mut CUlibraryOption,
-        libraryOptionValues: *mut *mut ::std::os::raw::c_void,
-        numLibraryOptions: ::std::os::raw::c_uint,
+        libraryOptionValues: *mut *mut ::core::ffi::c_void,
+        numLibraryOptions: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -5210,16 +5210,16 @@ unsafe extern "C" {
     pub fn cuLibraryGetKernel(
         pKernel: *mut CUkernel,
         library: CUlibrary,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
     ) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuLibraryGetKernelCount(count: *mut ::std::os::raw::c_uint, lib: CUlibrary) -> CUresult;
+    pub fn cuLibraryGetKernelCount(count: *mut ::core::ffi::c_uint, lib: CUlibrary) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuLibraryEnumerateKernels(
         kernels: *mut CUkernel,
-        numKernels: ::std::os::raw::c_uint,
+        numKernels: ::core::ffi::c_uint,
         lib: CUlibrary,
     ) -> CUresult;
 }
@@ -5234,7 +5234,7 @@ unsafe extern "C" {
         dptr: *mut CUdeviceptr,
         bytes: *mut usize,
         library: CUlibrary,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -5242,19 +5242,19 @@ unsafe extern "C" {
         dptr: *mut CUdeviceptr,
         bytes: *mut usize,
         library: CUlibrary,
-        name: *const ::std::os::raw::c_char,
+        name: *const ::core::ffi::c_char,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuLibraryGetUnifiedFunction(
-        fptr: *mut *mut ::std::os::raw::c_void,
+        fptr: *mut *mut ::core::ffi::c_void,
         library: CUlibrary,
-        symbol: *const ::std::os::raw::c_char,
+        symbol: *const ::core::ffi::c_char,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuKernelGetAttribute(
-        pi: *mut ::std::os::raw::c_int,
+        pi: *mut ::core::ffi::c_int,
         attrib: CUfunction_attribute,
         kernel: CUkernel,
         dev: CUdevice,
@@ -5263,7 +5263,7 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuKernelSetAttribute(
         attrib: CUfunction_attribute,
-        val: ::std::os::raw::c_int,
+        val: ::core::ffi::c_int,
         kernel: CUkernel,
         dev: CUdevice,
     ) -> CUresult;
@@ -5276,7 +5276,7 @@ unsafe extern "C" {
     ) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuKernelGetName(name: *mut *const ::std::os::raw::c_char, hfunc: CUkernel) -> CUresult;
+    pub fn cuKernelGetName(name: *mut *const ::core::ffi::c_char, hfunc: CUkernel) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuKernelGetParamInfo(
@@ -5298,7 +5298,7 @@ unsafe extern "C" {
         pPitch: *mut usize,
         WidthInBytes: usize,
         Height: usize,
-        ElementSizeBytes: ::std::os::raw::c_uint,
+        ElementSizeBytes: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -5312,43 +5312,43 @@ unsafe extern "C" {
     ) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuMemAllocHost_v2(pp: *mut *mut ::std::os::raw::c_void, bytesize: usize) -> CUresult;
+    pub fn cuMemAllocHost_v2(pp: *mut *mut ::core::ffi::c_void, bytesize: usize) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuMemFreeHost(p: *mut ::std::os::raw::c_void) -> CUresult;
+    pub fn cuMemFreeHost(p: *mut ::core::ffi::c_void) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMemHostAlloc(
-        pp: *mut *mut ::std::os::raw::c_void,
+        pp: *mut *mut ::core::ffi::c_void,
         bytesize: usize,
-        Flags: ::std::os::raw::c_uint,
+        Flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMemHostGetDevicePointer_v2(
         pdptr: *mut CUdeviceptr,
-        p: *mut ::std::os::raw::c_void,
-        Flags: ::std::os::raw::c_uint,
+        p: *mut ::core::ffi::c_void,
+        Flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMemHostGetFlags(
-        pFlags: *mut ::std::os::raw::c_uint,
-        p: *mut ::std::os::raw::c_void,
+        pFlags: *mut ::core::ffi::c_uint,
+        p: *mut ::core::ffi::c_void,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMemAllocManaged(
         dptr: *mut CUdeviceptr,
         bytesize: usize,
-        flags: ::std::os::raw::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuDeviceRegisterAsyncNotification(
         device: CUdevice,
         callbackFunc: CUasyncCallback,
-        userData: *mut ::std::os::raw::c_void,
+        userData: *mut ::core::ffi::c_void,
         callback: *mut CUasyncCallbackHandle,
     ) -> CUresult;
 }
@@ -5361,13 +5361,13 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuDeviceGetByPCIBusId(
         dev: *mut CUdevice,
-        pciBusId: *const ::std::os::raw::c_char,
+        pciBusId: *const ::core::ffi::c_char,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuDeviceGetPCIBusId(
-        pciBusId: *mut ::std::os::raw::c_char,
-        len: ::std::os::raw::c_int,
+        pciBusId: *mut ::core::ffi::c_char,
+        len: ::core::ffi::c_int,
         dev: CUdevice,
     ) -> CUresult;
 }
@@ -5384,7 +5384,7 @@ unsafe extern "C" {
     pub fn cuIpcOpenMemHandle_v2(
         pdptr: *mut CUdeviceptr,
         handle: CUipcMemHandle,
-        Flags: ::std::os::raw::c_uint,
+        Flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -5392,13 +5392,13 @@ unsafe extern "C" {
 }
 unsafe extern "C" {
     pub fn cuMemHostRegister_v2(
-        p: *mut ::std::os::raw::c_void,
+        p: *mut ::core::ffi::c_void,
         bytesize: usize,
-        Flags: ::std::os::raw::c_uint,
+        Flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuMemHostUnregister(p: *mut ::std::os::raw::c_void) -> CUresult;
+    pub fn cuMemHostUnregister(p: *mut ::core::ffi::c_void) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMemcpy(dst: CUdeviceptr, src: CUdeviceptr, ByteCount: usize) -> CUresult;
@@ -5415,13 +5415,13 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuMemcpyHtoD_v2(
         dstDevice: CUdeviceptr,
-        srcHost: *const ::std::os::raw::c_void,
+        srcHost: *const ::core::ffi::c_void,
         ByteCount: usize,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMemcpyDtoH_v2(
-        dstHost: *mut ::std::os::raw::c_void,
+        dstHost: *mut ::core::ffi::c_void,
         srcDevice: CUdeviceptr,
         ByteCount: usize,
     ) -> CUresult;
@@ -5453,13 +5453,13 @@ unsafe extern "C" {
     pub fn cuMemcpyHtoA_v2(
         dstArray: CUarray,
         dstOffset: usize,
-        srcHost: *const ::std::os::raw::c_void,
+        srcHost: *const ::core::ffi::c_void,
         ByteCount: usize,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMemcpyAtoH_v2(
-        dstHost: *mut ::std::os::raw::c_void,
+        dstHost: *mut ::core::ffi::c_void,
         srcArray: CUarray,
         srcOffset: usize,
         ByteCount: usize,
@@ -5507,14 +5507,14 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuMemcpyHtoDAsync_v2(
         dstDevice: CUdeviceptr,
-        srcHost: *const ::std::os::raw::c_void,
+        srcHost: *const ::core::ffi::c_void,
         ByteCount: usize,
         hStream: CUstream,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMemcpyDtoHAsync_v2(
-        dstHost: *mut ::std::os::raw::c_void,
+        dstHost: *mut ::core::ffi::c_void,
         srcDevice: CUdeviceptr,
         ByteCount: usize,
         hStream: CUstream,
@@ -5532,14 +5532,14 @@ unsafe extern "C" {
     pub fn cuMemcpyHtoAAsync_v2(
         dstArray: CUarray,
         dstOffset: usize,
-        srcHost: *const ::std::os::raw::c_void,
+        srcHost: *const ::core::ffi::c_void,
         ByteCount: usize,
         hStream: CUstream,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMemcpyAtoHAsync_v2(
-        dstHost: *mut ::std::os::raw::c_void,
+        dstHost: *mut ::core::ffi::c_void,
         srcArray: CUarray,
         srcOffset: usize,
         ByteCount: usize,
@@ -5556,25 +5556,25 @@ unsafe extern "C" {
     pub fn cuMemcpy3DPeerAsync(pCopy: *const CUDA_MEMCPY3D_PEER, hStream: CUstream) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuMemsetD8_v2(dstDevice: CUdeviceptr, uc: ::std::os::raw::c_uchar, N: usize)
+    pub fn cuMemsetD8_v2(dstDevice: CUdeviceptr, uc: ::core::ffi::c_uchar, N: usize)
         -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMemsetD16_v2(
         dstDevice: CUdeviceptr,
-        us: ::std::os::raw::c_ushort,
+        us: ::core::ffi::c_ushort,
         N: usize,
     ) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuMemsetD32_v2(dstDevice: CUdeviceptr, ui: ::std::os::raw::c_uint, N: usize)
+    pub fn cuMemsetD32_v2(dstDevice: CUdeviceptr, ui: ::core::ffi::c_uint, N: usize)
         -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMemsetD2D8_v2(
         dstDevice: CUdeviceptr,
         dstPitch: usize,
-        uc: ::std::os::raw::c_uchar,
+        uc: ::core::ffi::c_uchar,
         Width: usize,
         Height: usize,
     ) -> CUresult;
@@ -5583,7 +5583,7 @@ unsafe extern "C" {
     pub fn cuMemsetD2D16_v2(
         dstDevice: CUdeviceptr,
         dstPitch: usize,
-        us: ::std::os::raw::c_ushort,
+        us: ::core::ffi::c_ushort,
         Width: usize,
         Height: usize,
     ) -> CUresult;
@@ -5592,7 +5592,7 @@ unsafe extern "C" {
     pub fn cuMemsetD2D32_v2(
         dstDevice: CUdeviceptr,
         dstPitch: usize,
-        ui: ::std::os::raw::c_uint,
+        ui: ::core::ffi::c_uint,
         Width: usize,
         Height: usize,
     ) -> CUresult;
@@ -5600,7 +5600,7 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuMemsetD8Async(
         dstDevice: CUdeviceptr,
-        uc: ::std::os::raw::c_uchar,
+        uc: ::core::ffi::c_uchar,
         N: usize,
         hStream: CUstream,
     ) -> CUresult;
@@ -5608,7 +5608,7 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuMemsetD16Async(
         dstDevice: CUdeviceptr,
-        us: ::std::os::raw::c_ushort,
+        us: ::core::ffi::c_ushort,
         N: usize,
         hStream: CUstream,
     ) -> CUresult;
@@ -5616,7 +5616,7 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuMemsetD32Async(
         dstDevice: CUdeviceptr,
-        ui: ::std::os::raw::c_uint,
+        ui: ::core::ffi::c_uint,
         N: usize,
         hStream: CUstream,
     ) -> CUresult;
@@ -5625,7 +5625,7 @@ unsafe extern "C" {
     pub fn cuMemsetD2D8Async(
         dstDevice: CUdeviceptr,
         dstPitch: usize,
-        uc: ::std::os::raw::c_uchar,
+        uc: ::core::ffi::c_uchar,
         Width: usize,
         Height: usize,
         hStream: CUstream,
@@ -5635,7 +5635,7 @@ unsafe extern "C" {
     pub fn cuMemsetD2D16Async(
         dstDevice: CUdeviceptr,
         dstPitch: usize,
-        us: ::std::os::raw::c_ushort,
+        us: ::core::ffi::c_ushort,
         Width: usize,
         Height: usize,
         hStream: CUstream,
@@ -5645,7 +5645,7 @@ unsafe extern "C" {
     pub fn cuMemsetD2D32Async(
         dstDevice: CUdeviceptr,
         dstPitch: usize,
-        ui: ::std::os::raw::c_uint,
+        ui: ::core::ffi::c_uint,
         Width: usize,
         Height: usize,
         hStream: CUstream,
@@ -5693,7 +5693,7 @@ unsafe extern "C" {
     pub fn cuArrayGetPlane(
         pPlaneArray: *mut CUarray,
         hArray: CUarray,
-        planeIdx: ::std::os::raw::c_uint,
+        planeIdx: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -5715,14 +5715,14 @@ unsafe extern "C" {
     pub fn cuMipmappedArrayCreate(
         pHandle: *mut CUmipmappedArray,
         pMipmappedArrayDesc: *const CUDA_ARRAY3D_DESCRIPTOR,
-        numMipmapLevels: ::std::os::raw::c_uint,
+        numMipmapLevels: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMipmappedArrayGetLevel(
         pLevelArray: *mut CUarray,
         hMipmappedArray: CUmipmappedArray,
-        level: ::std::os::raw::c_uint,
+        level: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -5730,11 +5730,11 @@ unsafe extern "C" {
 }
 unsafe extern "C" {
     pub fn cuMemGetHandleForAddressRange(
-        handle: *mut ::std::os::raw::c_void,
+        handle: *mut ::core::ffi::c_void,
         dptr: CUdeviceptr,
         size: usize,
         handleType: CUmemRangeHandleType,
-        flags: ::std::os::raw::c_ulonglong,
+        flags: ::core::ffi::c_ulonglong,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -5743,7 +5743,7 @@ unsafe extern "C" {
         size: usize,
         alignment: usize,
         addr: CUdeviceptr,
-        flags: ::std::os::raw::c_ulonglong,
+        flags: ::core::ffi::c_ulonglong,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -5754,7 +5754,7 @@ unsafe extern "C" {
         handle: *mut CUmemGenericAllocationHandle,
         size: usize,
         prop: *const CUmemAllocationProp,
-        flags: ::std::os::raw::c_ulonglong,
+        flags: ::core::ffi::c_ulonglong,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -5766,13 +5766,13 @@ unsafe extern "C" {
         size: usize,
         offset: usize,
         handle: CUmemGenericAllocationHandle,
-        flags: ::std::os::raw::c_ulonglong,
+        flags: ::core::ffi::c_ulonglong,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMemMapArrayAsync(
         mapInfoList: *mut CUarrayMapInfo,
-        count: ::std::os::raw::c_uint,
+        count: ::core::ffi::c_uint,
         hStream: CUstream,
     ) -> CUresult;
 }
@@ -5789,23 +5789,23 @@ unsafe extern "C" {
 }
 unsafe extern "C" {
     pub fn cuMemGetAccess(
-        flags: *mut ::std::os::raw::c_ulonglong,
+        flags: *mut ::core::ffi::c_ulonglong,
         location: *const CUmemLocation,
         ptr: CUdeviceptr,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMemExportToShareableHandle(
-        shareableHandle: *mut ::std::os::raw::c_void,
+        shareableHandle: *mut ::core::ffi::c_void,
         handle: CUmemGenericAllocationHandle,
         handleType: CUmemAllocationHandleType,
-        flags: ::std::os::raw::c_ulonglong,
+        flags: ::core::ffi::c_ulonglong,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMemImportFromShareableHandle(
         handle: *mut CUmemGenericAllocationHandle,
-        osHandle: *mut ::std::os::raw::c_void,
+        osHandle: *mut ::core::ffi::c_void,
         shHandleType: CUmemAllocationHandleType,
     ) -> CUresult;
 }
@@ -5825,7 +5825,7 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuMemRetainAllocationHandle(
         handle: *mut CUmemGenericAllocationHandle,
-        addr: *mut ::std::os::raw::c_void,
+        addr: *mut ::core::ffi::c_void,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -5841,14 +5841,14 @@ unsafe extern "C" {
     pub fn cuMemPoolSetAttribute(
         pool: CUmemoryPool,
         attr: CUmemPool_attribute,
-        value: *mut ::std::os::raw::c_void,
+        value: *mut ::core::ffi::c_void,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMemPoolGetAttribute(
         pool: CUmemoryPool,
         attr: CUmemPool_attribute,
-        value: *mut ::std::os::raw::c_void,
+        value: *mut ::core::ffi::c_void,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -5881,18 +5881,18 @@ unsafe extern "C" {
 }
 unsafe extern "C" {
     pub fn cuMemPoolExportToShareableHandle(
-        handle_out: *mut ::std::os::raw::c_void,
+        handle_out: *mut ::core::ffi::c_void,
         pool: CUmemoryPool,
         handleType: CUmemAllocationHandleType,
-        flags: ::std::os::raw::c_ulonglong,
+        flags: ::core::ffi::c_ulonglong,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuMemPoolImportFromShareableHandle(
         pool_out: *mut CUmemoryPool,
-        handle: *mut ::std::os::raw::c_void,
+        handle: *mut ::core::ffi::c_void,
         handleType: CUmemAllocationHandleType,
-        flags: ::std::os::raw::c_ulonglong,
+        flags: ::core::ffi::c_ulonglong,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -5924,7 +5924,7 @@ unsafe extern "C" {
         memHandle: CUmemGenericAllocationHandle,
         memOffset: usize,
         size: usize,
-        flags: ::std::os::raw::c_ulonglong,
+        flags: ::core::ffi::c_ulonglong,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -5933,7 +5933,7 @@ unsafe extern "C" {
         mcOffset: usize,
         memptr: CUdeviceptr,
         size: usize,
-        flags: ::std::os::raw::c_ulonglong,
+        flags: ::core::ffi::c_ulonglong,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -5953,7 +5953,7 @@ unsafe extern "C" {
 }
 unsafe extern "C" {
     pub fn cuPointerGetAttribute(
-        data: *mut ::std::os::raw::c_void,
+        data: *mut ::core::ffi::c_void,
         attribute: CUpointer_attribute,
         ptr: CUdeviceptr,
     ) -> CUresult;
@@ -5971,7 +5971,7 @@ unsafe extern "C" {
         devPtr: CUdeviceptr,
         count: usize,
         location: CUmemLocation,
-        flags: ::std::os::raw::c_uint,
+        flags: ::core::ffi::c_uint,
         hStream: CUstream,
     ) -> CUresult;
 }
@@ -5993,7 +5993,7 @@ unsafe extern "C" {
 }
 unsafe extern "C" {
     pub fn cuMemRangeGetAttribute(
-        data: *mut ::std::os::raw::c_void,
+        data: *mut ::core::ffi::c_void,
         dataSize: usize,
         attribute: CUmem_range_attribute,
         devPtr: CUdeviceptr,
@@ -6002,7 +6002,7 @@ unsafe extern "C" {
 }
 unsafe extern "C" {
     pub fn cuMemRangeGetAttributes(
-        data: *mut *mut ::std::os::raw::c_void,
+        data: *mut *mut ::core::ffi::c_void,
         dataSizes: *mut usize,
         attributes: *mut CUmem_range_attribute,
         numAttributes: usize,
@@ -6012,38 +6012,38 @@ unsafe extern "C" {
 }
 unsafe extern "C" {
     pub fn cuPointerSetAttribute(
-        value: *const ::std::os::raw::c_void,
+        value: *const ::core::ffi::c_void,
         attribute: CUpointer_attribute,
         ptr: CUdeviceptr,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuPointerGetAttributes(
-        numAttributes: ::std::os::raw::c_uint,
+        numAttributes: ::core::ffi::c_uint,
         attributes: *mut CUpointer_attribute,
-        data: *mut *mut ::std::os::raw::c_void,
+        data: *mut *mut ::core::ffi::c_void,
         ptr: CUdeviceptr,
     ) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuStreamCreate(phStream: *mut CUstream, Flags: ::std::os::raw::c_uint) -> CUresult;
+    pub fn cuStreamCreate(phStream: *mut CUstream, Flags: ::core::ffi::c_uint) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuStreamCreateWithPriority(
         phStream: *mut CUstream,
-        flags: ::std::os::raw::c_uint,
-        priority: ::std::os::raw::c_int,
+        flags: ::core::ffi::c_uint,
+        priority: ::core::ffi::c_int,
     ) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuStreamGetPriority(hStream: CUstream, priority: *mut ::std::os::raw::c_int)
+    pub fn cuStreamGetPriority(hStream: CUstream, priority: *mut ::core::ffi::c_int)
         -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuStreamGetFlags(hStream: CUstream, flags: *mut ::std::os::raw::c_uint) -> CUresult;
+    pub fn cuStreamGetFlags(hStream: CUstream, flags: *mut ::core::ffi::c_uint) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuStreamGetId(hStream: CUstream, streamId: *mut ::std::os::raw::c_ulonglong)
+    pub fn cuStreamGetId(hStream: CUstream, streamId: *mut ::core::ffi::c_ulonglong)
         -> CUresult;
 }
 unsafe extern "C" {
@@ -6053,15 +6053,15 @@ unsafe extern "C" {
     pub fn cuStreamWaitEvent(
         hStream: CUstream,
         hEvent: CUevent,
-        Flags: ::std::os::raw::c_uint,
+        Flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuStreamAddCallback(
         hStream: CUstream,
         callback: CUstreamCallback,
-        userData: *mut ::std::os::raw::c_void,
-        flags: ::std::os::raw::c_uint,
+        userData: *mut ::core::ffi::c_void,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -6115,7 +6115,7 @@ unsafe extern "C" {
         hStream: CUstream,
         dependencies: *mut CUgraphNode,
         numDependencies: usize,
-        flags: ::std::os::raw::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -6124,7 +6124,7 @@ unsafe extern "C" {
         dependencies: *mut CUgraphNode,
         dependencyData: *const CUgraphEdgeData,
         numDependencies: usize,
-        flags: ::std::os::raw::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -6132,7 +6132,7 @@ unsafe extern "C" {
         hStream: CUstream,
         dptr: CUdeviceptr,
         length: usize,
-        flags: ::std::os::raw::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -6162,7 +6162,7 @@ unsafe extern "C" {
     ) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuEventCreate(phEvent: *mut CUevent, Flags: ::std::os::raw::c_uint) -> CUresult;
+    pub fn cuEventCreate(phEvent: *mut CUevent, Flags: ::core::ffi::c_uint) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuEventRecord(hEvent: CUevent, hStream: CUstream) -> CUresult;
@@ -6171,7 +6171,7 @@ unsafe extern "C" {
     pub fn cuEventRecordWithFlags(
         hEvent: CUevent,
         hStream: CUstream,
-        flags: ::std::os::raw::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -6219,7 +6219,7 @@ unsafe extern "C" {
     pub fn cuSignalExternalSemaphoresAsync(
         extSemArray: *const CUexternalSemaphore,
         paramsArray: *const CUDA_EXTERNAL_SEMAPHORE_SIGNAL_PARAMS,
-        numExtSems: ::std::os::raw::c_uint,
+        numExtSems: ::core::ffi::c_uint,
         stream: CUstream,
     ) -> CUresult;
 }
@@ -6227,7 +6227,7 @@ unsafe extern "C" {
     pub fn cuWaitExternalSemaphoresAsync(
         extSemArray: *const CUexternalSemaphore,
         paramsArray: *const CUDA_EXTERNAL_SEMAPHORE_WAIT_PARAMS,
-        numExtSems: ::std::os::raw::c_uint,
+        numExtSems: ::core::ffi::c_uint,
         stream: CUstream,
     ) -> CUresult;
 }
@@ -6239,7 +6239,7 @@ unsafe extern "C" {
         stream: CUstream,
         addr: CUdeviceptr,
         value: cuuint32_t,
-        flags: ::std::os::raw::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -6247,7 +6247,7 @@ unsafe extern "C" {
         stream: CUstream,
         addr: CUdeviceptr,
         value: cuuint64_t,
-        flags: ::std::os::raw::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -6255,7 +6255,7 @@ unsafe extern "C" {
         stream: CUstream,
         addr: CUdeviceptr,
         value: cuuint32_t,
-        flags: ::std::os::raw::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -6263,20 +6263,20 @@ unsafe extern "C" {
         stream: CUstream,
         addr: CUdeviceptr,
         value: cuuint64_t,
-        flags: ::std::os::raw::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuStreamBatchMemOp_v2(
         stream: CUstream,
-        count: ::std::os::raw::c_uint,
+        count: ::core::ffi::c_uint,
         paramArray: *mut CUstreamBatchMemOpParams,
-        flags: ::std::os::raw::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuFuncGetAttribute(
-        pi: *mut ::std::os::raw::c_int,
+        pi: *mut ::core::ffi::c_int,
         attrib: CUfunction_attribute,
         hfunc: CUfunction,
     ) -> CUresult;
@@ -6285,7 +6285,7 @@ unsafe extern "C" {
     pub fn cuFuncSetAttribute(
         hfunc: CUfunction,
         attrib: CUfunction_attribute,
-        value: ::std::os::raw::c_int,
+        value: ::core::ffi::c_int,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -6295,7 +6295,7 @@ unsafe extern "C" {
     pub fn cuFuncGetModule(hmod: *mut CUmodule, hfunc: CUfunction) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuFuncGetName(name: *mut *const ::std::os::raw::c_char, hfunc: CUfunction) -> CUresult;
+    pub fn cuFuncGetName(name: *mut *const ::core::ffi::c_char, hfunc: CUfunction) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuFuncGetParamInfo(
@@ -6322,84 +6322,84 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuLaunchKernel(
         f: CUfunction,
-        gridDimX: ::std::os::raw::c_uint,
-        gridDimY: ::std::os::raw::c_uint,
-        gridDimZ: ::std::os::raw::c_uint,
-        blockDimX: ::std::os::raw::c_uint,
-        blockDimY: ::std::os::raw::c_uint,
-        blockDimZ: ::std::os::raw::c_uint,
-        sharedMemBytes: ::std::os::raw::c_uint,
+        gridDimX: ::core::ffi::c_uint,
+        gridDimY: ::core::ffi::c_uint,
+        gridDimZ: ::core::ffi::c_uint,
+        blockDimX: ::core::ffi::c_uint,
+        blockDimY: ::core::ffi::c_uint,
+        blockDimZ: ::core::ffi::c_uint,
+        sharedMemBytes: ::core::ffi::c_uint,
         hStream: CUstream,
-        kernelParams: *mut *mut ::std::os::raw::c_void,
-        extra: *mut *mut ::std::os::raw::c_void,
+        kernelParams: *mut *mut ::core::ffi::c_void,
+        extra: *mut *mut ::core::ffi::c_void,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuLaunchKernelEx(
         config: *const CUlaunchConfig,
         f: CUfunction,
-        kernelParams: *mut *mut ::std::os::raw::c_void,
-        extra: *mut *mut ::std::os::raw::c_void,
+        kernelParams: *mut *mut ::core::ffi::c_void,
+        extra: *mut *mut ::core::ffi::c_void,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuLaunchCooperativeKernel(
         f: CUfunction,
-        gridDimX: ::std::os::raw::c_uint,
-        gridDimY: ::std::os::raw::c_uint,
-        gridDimZ: ::std::os::raw::c_uint,
-        blockDimX: ::std::os::raw::c_uint,
-        blockDimY: ::std::os::raw::c_uint,
-        blockDimZ: ::std::os::raw::c_uint,
-        sharedMemBytes: ::std::os::raw::c_uint,
+        gridDimX: ::core::ffi::c_uint,
+        gridDimY: ::core::ffi::c_uint,
+        gridDimZ: ::core::ffi::c_uint,
+        blockDimX: ::core::ffi::c_uint,
+        blockDimY: ::core::ffi::c_uint,
+        blockDimZ: ::core::ffi::c_uint,
+        sharedMemBytes: ::core::ffi::c_uint,
         hStream: CUstream,
-        kernelParams: *mut *mut ::std::os::raw::c_void,
+        kernelParams: *mut *mut ::core::ffi::c_void,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuLaunchCooperativeKernelMultiDevice(
         launchParamsList: *mut CUDA_LAUNCH_PARAMS,
-        numDevices: ::std::os::raw::c_uint,
-        flags: ::std::os::raw::c_uint,
+        numDevices: ::core::ffi::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuLaunchHostFunc(
         hStream: CUstream,
         fn_: CUhostFn,
-        userData: *mut ::std::os::raw::c_void,
+        userData: *mut ::core::ffi::c_void,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuFuncSetBlockShape(
         hfunc: CUfunction,
-        x: ::std::os::raw::c_int,
-        y: ::std::os::raw::c_int,
-        z: ::std::os::raw::c_int,
+        x: ::core::ffi::c_int,
+        y: ::core::ffi::c_int,
+        z: ::core::ffi::c_int,
     ) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuFuncSetSharedSize(hfunc: CUfunction, bytes: ::std::os::raw::c_uint) -> CUresult;
+    pub fn cuFuncSetSharedSize(hfunc: CUfunction, bytes: ::core::ffi::c_uint) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuParamSetSize(hfunc: CUfunction, numbytes: ::std::os::raw::c_uint) -> CUresult;
+    pub fn cuParamSetSize(hfunc: CUfunction, numbytes: ::core::ffi::c_uint) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuParamSeti(
         hfunc: CUfunction,
-        offset: ::std::os::raw::c_int,
-        value: ::std::os::raw::c_uint,
+        offset: ::core::ffi::c_int,
+        value: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuParamSetf(hfunc: CUfunction, offset: ::std::os::raw::c_int, value: f32) -> CUresult;
+    pub fn cuParamSetf(hfunc: CUfunction, offset: ::core::ffi::c_int, value: f32) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuParamSetv(
         hfunc: CUfunction,
-        offset: ::std::os::raw::c_int,
-        ptr: *mut ::std::os::raw::c_void,
-        numbytes: ::std::os::raw::c_uint,
+        offset: ::core::ffi::c_int,
+        ptr: *mut ::core::ffi::c_void,
+        numbytes: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -6408,22 +6408,22 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuLaunchGrid(
         f: CUfunction,
-        grid_width: ::std::os::raw::c_int,
-        grid_height: ::std::os::raw::c_int,
+        grid_width: ::core::ffi::c_int,
+        grid_height: ::core::ffi::c_int,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuLaunchGridAsync(
         f: CUfunction,
-        grid_width: ::std::os::raw::c_int,
-        grid_height: ::std::os::raw::c_int,
+        grid_width: ::core::ffi::c_int,
+        grid_height: ::core::ffi::c_int,
         hStream: CUstream,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuParamSetTexRef(
         hfunc: CUfunction,
-        texunit: ::std::os::raw::c_int,
+        texunit: ::core::ffi::c_int,
         hTexRef: CUtexref,
     ) -> CUresult;
 }
@@ -6431,7 +6431,7 @@ unsafe extern "C" {
     pub fn cuFuncSetSharedMemConfig(hfunc: CUfunction, config: CUsharedconfig) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuGraphCreate(phGraph: *mut CUgraph, flags: ::std::os::raw::c_uint) -> CUresult;
+    pub fn cuGraphCreate(phGraph: *mut CUgraph, flags: ::core::ffi::c_uint) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuGraphAddKernelNode_v2(
@@ -6673,14 +6673,14 @@ unsafe extern "C" {
     pub fn cuDeviceGetGraphMemAttribute(
         device: CUdevice,
         attr: CUgraphMem_attribute,
-        value: *mut ::std::os::raw::c_void,
+        value: *mut ::core::ffi::c_void,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuDeviceSetGraphMemAttribute(
         device: CUdevice,
         attr: CUgraphMem_attribute,
-        value: *mut ::std::os::raw::c_void,
+        value: *mut ::core::ffi::c_void,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -6798,7 +6798,7 @@ unsafe extern "C" {
     pub fn cuGraphInstantiateWithFlags(
         phGraphExec: *mut CUgraphExec,
         hGraph: CUgraph,
-        flags: ::std::os::raw::c_ulonglong,
+        flags: ::core::ffi::c_ulonglong,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -6880,14 +6880,14 @@ unsafe extern "C" {
     pub fn cuGraphNodeSetEnabled(
         hGraphExec: CUgraphExec,
         hNode: CUgraphNode,
-        isEnabled: ::std::os::raw::c_uint,
+        isEnabled: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuGraphNodeGetEnabled(
         hGraphExec: CUgraphExec,
         hNode: CUgraphNode,
-        isEnabled: *mut ::std::os::raw::c_uint,
+        isEnabled: *mut ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -6929,38 +6929,38 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuGraphDebugDotPrint(
         hGraph: CUgraph,
-        path: *const ::std::os::raw::c_char,
-        flags: ::std::os::raw::c_uint,
+        path: *const ::core::ffi::c_char,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuUserObjectCreate(
         object_out: *mut CUuserObject,
-        ptr: *mut ::std::os::raw::c_void,
+        ptr: *mut ::core::ffi::c_void,
         destroy: CUhostFn,
-        initialRefcount: ::std::os::raw::c_uint,
-        flags: ::std::os::raw::c_uint,
+        initialRefcount: ::core::ffi::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuUserObjectRetain(object: CUuserObject, count: ::std::os::raw::c_uint) -> CUresult;
+    pub fn cuUserObjectRetain(object: CUuserObject, count: ::core::ffi::c_uint) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuUserObjectRelease(object: CUuserObject, count: ::std::os::raw::c_uint) -> CUresult;
+    pub fn cuUserObjectRelease(object: CUuserObject, count: ::core::ffi::c_uint) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuGraphRetainUserObject(
         graph: CUgraph,
         object: CUuserObject,
-        count: ::std::os::raw::c_uint,
-        flags: ::std::os::raw::c_uint,
+        count: ::core::ffi::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuGraphReleaseUserObject(
         graph: CUgraph,
         object: CUuserObject,
-        count: ::std::os::raw::c_uint,
+        count: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -6998,66 +6998,66 @@ unsafe extern "C" {
         pHandle_out: *mut CUgraphConditionalHandle,
         hGraph: CUgraph,
         ctx: CUcontext,
-        defaultLaunchValue: ::std::os::raw::c_uint,
-        flags: ::std::os::raw::c_uint,
+        defaultLaunchValue: ::core::ffi::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuOccupancyMaxActiveBlocksPerMultiprocessor(
-        numBlocks: *mut ::std::os::raw::c_int,
+        numBlocks: *mut ::core::ffi::c_int,
         func: CUfunction,
-        blockSize: ::std::os::raw::c_int,
+        blockSize: ::core::ffi::c_int,
         dynamicSMemSize: usize,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuOccupancyMaxActiveBlocksPerMultiprocessorWithFlags(
-        numBlocks: *mut ::std::os::raw::c_int,
+        numBlocks: *mut ::core::ffi::c_int,
         func: CUfunction,
-        blockSize: ::std::os::raw::c_int,
+        blockSize: ::core::ffi::c_int,
         dynamicSMemSize: usize,
-        flags: ::std::os::raw::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuOccupancyMaxPotentialBlockSize(
-        minGridSize: *mut ::std::os::raw::c_int,
-        blockSize: *mut ::std::os::raw::c_int,
+        minGridSize: *mut ::core::ffi::c_int,
+        blockSize: *mut ::core::ffi::c_int,
         func: CUfunction,
         blockSizeToDynamicSMemSize: CUoccupancyB2DSize,
         dynamicSMemSize: usize,
-        blockSizeLimit: ::std::os::raw::c_int,
+        blockSizeLimit: ::core::ffi::c_int,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuOccupancyMaxPotentialBlockSizeWithFlags(
-        minGridSize: *mut ::std::os::raw::c_int,
-        blockSize: *mut ::std::os::raw::c_int,
+        minGridSize: *mut ::core::ffi::c_int,
+        blockSize: *mut ::core::ffi::c_int,
         func: CUfunction,
         blockSizeToDynamicSMemSize: CUoccupancyB2DSize,
         dynamicSMemSize: usize,
-        blockSizeLimit: ::std::os::raw::c_int,
-        flags: ::std::os::raw::c_uint,
+        blockSizeLimit: ::core::ffi::c_int,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuOccupancyAvailableDynamicSMemPerBlock(
         dynamicSmemSize: *mut usize,
         func: CUfunction,
-        numBlocks: ::std::os::raw::c_int,
-        blockSize: ::std::os::raw::c_int,
+        numBlocks: ::core::ffi::c_int,
+        blockSize: ::core::ffi::c_int,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuOccupancyMaxPotentialClusterSize(
-        clusterSize: *mut ::std::os::raw::c_int,
+        clusterSize: *mut ::core::ffi::c_int,
         func: CUfunction,
         config: *const CUlaunchConfig,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuOccupancyMaxActiveClusters(
-        numClusters: *mut ::std::os::raw::c_int,
+        numClusters: *mut ::core::ffi::c_int,
         func: CUfunction,
         config: *const CUlaunchConfig,
     ) -> CUresult;
@@ -7066,14 +7066,14 @@ unsafe extern "C" {
     pub fn cuTexRefSetArray(
         hTexRef: CUtexref,
         hArray: CUarray,
-        Flags: ::std::os::raw::c_uint,
+        Flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuTexRefSetMipmappedArray(
         hTexRef: CUtexref,
         hMipmappedArray: CUmipmappedArray,
-        Flags: ::std::os::raw::c_uint,
+        Flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -7096,13 +7096,13 @@ unsafe extern "C" {
     pub fn cuTexRefSetFormat(
         hTexRef: CUtexref,
         fmt: CUarray_format,
-        NumPackedComponents: ::std::os::raw::c_int,
+        NumPackedComponents: ::core::ffi::c_int,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuTexRefSetAddressMode(
         hTexRef: CUtexref,
-        dim: ::std::os::raw::c_int,
+        dim: ::core::ffi::c_int,
         am: CUaddress_mode,
     ) -> CUresult;
 }
@@ -7125,14 +7125,14 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuTexRefSetMaxAnisotropy(
         hTexRef: CUtexref,
-        maxAniso: ::std::os::raw::c_uint,
+        maxAniso: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuTexRefSetBorderColor(hTexRef: CUtexref, pBorderColor: *mut f32) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuTexRefSetFlags(hTexRef: CUtexref, Flags: ::std::os::raw::c_uint) -> CUresult;
+    pub fn cuTexRefSetFlags(hTexRef: CUtexref, Flags: ::core::ffi::c_uint) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuTexRefGetAddress_v2(pdptr: *mut CUdeviceptr, hTexRef: CUtexref) -> CUresult;
@@ -7150,7 +7150,7 @@ unsafe extern "C" {
     pub fn cuTexRefGetAddressMode(
         pam: *mut CUaddress_mode,
         hTexRef: CUtexref,
-        dim: ::std::os::raw::c_int,
+        dim: ::core::ffi::c_int,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -7159,7 +7159,7 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuTexRefGetFormat(
         pFormat: *mut CUarray_format,
-        pNumChannels: *mut ::std::os::raw::c_int,
+        pNumChannels: *mut ::core::ffi::c_int,
         hTexRef: CUtexref,
     ) -> CUresult;
 }
@@ -7178,7 +7178,7 @@ unsafe extern "C" {
 }
 unsafe extern "C" {
     pub fn cuTexRefGetMaxAnisotropy(
-        pmaxAniso: *mut ::std::os::raw::c_int,
+        pmaxAniso: *mut ::core::ffi::c_int,
         hTexRef: CUtexref,
     ) -> CUresult;
 }
@@ -7186,7 +7186,7 @@ unsafe extern "C" {
     pub fn cuTexRefGetBorderColor(pBorderColor: *mut f32, hTexRef: CUtexref) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuTexRefGetFlags(pFlags: *mut ::std::os::raw::c_uint, hTexRef: CUtexref) -> CUresult;
+    pub fn cuTexRefGetFlags(pFlags: *mut ::core::ffi::c_uint, hTexRef: CUtexref) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuTexRefCreate(pTexRef: *mut CUtexref) -> CUresult;
@@ -7198,7 +7198,7 @@ unsafe extern "C" {
     pub fn cuSurfRefSetArray(
         hSurfRef: CUsurfref,
         hArray: CUarray,
-        Flags: ::std::os::raw::c_uint,
+        Flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -7253,7 +7253,7 @@ unsafe extern "C" {
         tensorMap: *mut CUtensorMap,
         tensorDataType: CUtensorMapDataType,
         tensorRank: cuuint32_t,
-        globalAddress: *mut ::std::os::raw::c_void,
+        globalAddress: *mut ::core::ffi::c_void,
         globalDim: *const cuuint64_t,
         globalStrides: *const cuuint64_t,
         boxDim: *const cuuint32_t,
@@ -7269,11 +7269,11 @@ unsafe extern "C" {
         tensorMap: *mut CUtensorMap,
         tensorDataType: CUtensorMapDataType,
         tensorRank: cuuint32_t,
-        globalAddress: *mut ::std::os::raw::c_void,
+        globalAddress: *mut ::core::ffi::c_void,
         globalDim: *const cuuint64_t,
         globalStrides: *const cuuint64_t,
-        pixelBoxLowerCorner: *const ::std::os::raw::c_int,
-        pixelBoxUpperCorner: *const ::std::os::raw::c_int,
+        pixelBoxLowerCorner: *const ::core::ffi::c_int,
+        pixelBoxUpperCorner: *const ::core::ffi::c_int,
         channelsPerPixel: cuuint32_t,
         pixelsPerColumn: cuuint32_t,
         elementStrides: *const cuuint32_t,
@@ -7286,18 +7286,18 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuTensorMapReplaceAddress(
         tensorMap: *mut CUtensorMap,
-        globalAddress: *mut ::std::os::raw::c_void,
+        globalAddress: *mut ::core::ffi::c_void,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuDeviceCanAccessPeer(
-        canAccessPeer: *mut ::std::os::raw::c_int,
+        canAccessPeer: *mut ::core::ffi::c_int,
         dev: CUdevice,
         peerDev: CUdevice,
     ) -> CUresult;
 }
 unsafe extern "C" {
-    pub fn cuCtxEnablePeerAccess(peerContext: CUcontext, Flags: ::std::os::raw::c_uint)
+    pub fn cuCtxEnablePeerAccess(peerContext: CUcontext, Flags: ::core::ffi::c_uint)
         -> CUresult;
 }
 unsafe extern "C" {
@@ -7305,7 +7305,7 @@ unsafe extern "C" {
 }
 unsafe extern "C" {
     pub fn cuDeviceGetP2PAttribute(
-        value: *mut ::std::os::raw::c_int,
+        value: *mut ::core::ffi::c_int,
         attrib: CUdevice_P2PAttribute,
         srcDevice: CUdevice,
         dstDevice: CUdevice,
@@ -7318,8 +7318,8 @@ unsafe extern "C" {
     pub fn cuGraphicsSubResourceGetMappedArray(
         pArray: *mut CUarray,
         resource: CUgraphicsResource,
-        arrayIndex: ::std::os::raw::c_uint,
-        mipLevel: ::std::os::raw::c_uint,
+        arrayIndex: ::core::ffi::c_uint,
+        mipLevel: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -7338,28 +7338,28 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuGraphicsResourceSetMapFlags_v2(
         resource: CUgraphicsResource,
-        flags: ::std::os::raw::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuGraphicsMapResources(
-        count: ::std::os::raw::c_uint,
+        count: ::core::ffi::c_uint,
         resources: *mut CUgraphicsResource,
         hStream: CUstream,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuGraphicsUnmapResources(
-        count: ::std::os::raw::c_uint,
+        count: ::core::ffi::c_uint,
         resources: *mut CUgraphicsResource,
         hStream: CUstream,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuGetProcAddress_v2(
-        symbol: *const ::std::os::raw::c_char,
-        pfn: *mut *mut ::std::os::raw::c_void,
-        cudaVersion: ::std::os::raw::c_int,
+        symbol: *const ::core::ffi::c_char,
+        pfn: *mut *mut ::core::ffi::c_void,
+        cudaVersion: ::core::ffi::c_int,
         flags: cuuint64_t,
         symbolStatus: *mut CUdriverProcAddressQueryResult,
     ) -> CUresult;
@@ -7379,34 +7379,34 @@ pub use self::CUcoredumpSettings_enum as CUcoredumpSettings;
 unsafe extern "C" {
     pub fn cuCoredumpGetAttribute(
         attrib: CUcoredumpSettings,
-        value: *mut ::std::os::raw::c_void,
+        value: *mut ::core::ffi::c_void,
         size: *mut usize,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuCoredumpGetAttributeGlobal(
         attrib: CUcoredumpSettings,
-        value: *mut ::std::os::raw::c_void,
+        value: *mut ::core::ffi::c_void,
         size: *mut usize,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuCoredumpSetAttribute(
         attrib: CUcoredumpSettings,
-        value: *mut ::std::os::raw::c_void,
+        value: *mut ::core::ffi::c_void,
         size: *mut usize,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuCoredumpSetAttributeGlobal(
         attrib: CUcoredumpSettings,
-        value: *mut ::std::os::raw::c_void,
+        value: *mut ::core::ffi::c_void,
         size: *mut usize,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuGetExportTable(
-        ppExportTable: *mut *const ::std::os::raw::c_void,
+        ppExportTable: *mut *const ::core::ffi::c_void,
         pExportTableId: *const CUuuid,
     ) -> CUresult;
 }
@@ -7436,63 +7436,63 @@ pub enum CUdevResourceType {
 #[repr(C)]
 #[derive(Debug, Default, Copy, Clone, Hash, PartialOrd, Ord, PartialEq, Eq)]
 pub struct CUdevSmResource_st {
-    pub smCount: ::std::os::raw::c_uint,
+    pub smCount: ::core::ffi::c_uint,
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUdevSmResource_st"][::std::mem::size_of::<CUdevSmResource_st>() - 4usize];
-    ["Alignment of CUdevSmResource_st"][::std::mem::align_of::<CUdevSmResource_st>() - 4usize];
+    ["Size of CUdevSmResource_st"][::core::mem::size_of::<CUdevSmResource_st>() - 4usize];
+    ["Alignment of CUdevSmResource_st"][::core::mem::align_of::<CUdevSmResource_st>() - 4usize];
     ["Offset of field: CUdevSmResource_st::smCount"]
-        [::std::mem::offset_of!(CUdevSmResource_st, smCount) - 0usize];
+        [::core::mem::offset_of!(CUdevSmResource_st, smCount) - 0usize];
 };
 pub type CUdevSmResource = CUdevSmResource_st;
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct CUdevResource_st {
     pub type_: CUdevResourceType,
-    pub _internal_padding: [::std::os::raw::c_uchar; 92usize],
+    pub _internal_padding: [::core::ffi::c_uchar; 92usize],
     pub __bindgen_anon_1: CUdevResource_st__bindgen_ty_1,
 }
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union CUdevResource_st__bindgen_ty_1 {
     pub sm: CUdevSmResource,
-    pub _oversize: [::std::os::raw::c_uchar; 48usize],
+    pub _oversize: [::core::ffi::c_uchar; 48usize],
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CUdevResource_st__bindgen_ty_1"]
-        [::std::mem::size_of::<CUdevResource_st__bindgen_ty_1>() - 48usize];
+        [::core::mem::size_of::<CUdevResource_st__bindgen_ty_1>() - 48usize];
     ["Alignment of CUdevResource_st__bindgen_ty_1"]
-        [::std::mem::align_of::<CUdevResource_st__bindgen_ty_1>() - 4usize];
+        [::core::mem::align_of::<CUdevResource_st__bindgen_ty_1>() - 4usize];
     ["Offset of field: CUdevResource_st__bindgen_ty_1::sm"]
-        [::std::mem::offset_of!(CUdevResource_st__bindgen_ty_1, sm) - 0usize];
+        [::core::mem::offset_of!(CUdevResource_st__bindgen_ty_1, sm) - 0usize];
     ["Offset of field: CUdevResource_st__bindgen_ty_1::_oversize"]
-        [::std::mem::offset_of!(CUdevResource_st__bindgen_ty_1, _oversize) - 0usize];
+        [::core::mem::offset_of!(CUdevResource_st__bindgen_ty_1, _oversize) - 0usize];
 };
 impl Default for CUdevResource_st__bindgen_ty_1 {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
 }
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
-    ["Size of CUdevResource_st"][::std::mem::size_of::<CUdevResource_st>() - 144usize];
-    ["Alignment of CUdevResource_st"][::std::mem::align_of::<CUdevResource_st>() - 4usize];
+    ["Size of CUdevResource_st"][::core::mem::size_of::<CUdevResource_st>() - 144usize];
+    ["Alignment of CUdevResource_st"][::core::mem::align_of::<CUdevResource_st>() - 4usize];
     ["Offset of field: CUdevResource_st::type_"]
-        [::std::mem::offset_of!(CUdevResource_st, type_) - 0usize];
+        [::core::mem::offset_of!(CUdevResource_st, type_) - 0usize];
     ["Offset of field: CUdevResource_st::_internal_padding"]
-        [::std::mem::offset_of!(CUdevResource_st, _internal_padding) - 4usize];
+        [::core::mem::offset_of!(CUdevResource_st, _internal_padding) - 4usize];
 };
 impl Default for CUdevResource_st {
     fn default() -> Self {
-        let mut s = ::std::mem::MaybeUninit::<Self>::uninit();
+        let mut s = ::core::mem::MaybeUninit::<Self>::uninit();
         unsafe {
-            ::std::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
+            ::core::ptr::write_bytes(s.as_mut_ptr(), 0, 1);
             s.assume_init()
         }
     }
@@ -7504,7 +7504,7 @@ unsafe extern "C" {
         phCtx: *mut CUgreenCtx,
         desc: CUdevResourceDesc,
         dev: CUdevice,
-        flags: ::std::os::raw::c_uint,
+        flags: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
@@ -7537,18 +7537,18 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn cuDevSmResourceSplitByCount(
         result: *mut CUdevResource,
-        nbGroups: *mut ::std::os::raw::c_uint,
+        nbGroups: *mut ::core::ffi::c_uint,
         input: *const CUdevResource,
         remaining: *mut CUdevResource,
-        useFlags: ::std::os::raw::c_uint,
-        minCount: ::std::os::raw::c_uint,
+        useFlags: ::core::ffi::c_uint,
+        minCount: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
     pub fn cuDevResourceGenerateDesc(
         phDesc: *mut CUdevResourceDesc,
         resources: *mut CUdevResource,
-        nbResources: ::std::os::raw::c_uint,
+        nbResources: ::core::ffi::c_uint,
     ) -> CUresult;
 }
 unsafe extern "C" {
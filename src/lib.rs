@@ -1,2 +1,7 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
+
 pub mod cuda;
-pub mod nvrtc;
\ No newline at end of file
+pub mod nvrtc;
+#[cfg(feature = "std")]
+pub use cuda::borrow::{default_device, set_visible_devices};